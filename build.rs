@@ -1,116 +1,53 @@
 #[cfg(not(feature = "rebuild-reexports"))]
 fn main() {}
 
+/// Regenerates the forwarders, vtable slots and callback table for the
+/// target platform from `KONIGSBERG_BINDINGS` if it's set (either a bindings
+/// file or a `steamworks-sys` source directory), otherwise from the
+/// `steamworks-sys` this crate is built against. The trampolines for functions the bindings
+/// lack and the vtable slots follow `KONIGSBERG_REFERENCE_LIB`, or the Steamworks
+/// redistributable.
 #[cfg(feature = "rebuild-reexports")]
 fn main() {
-    use quote::ToTokens;
-    let bindings = reqwest::blocking::get({
-        #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
-        {
-            "https://raw.githubusercontent.com/Noxime/steamworks-rs/master/steamworks-sys/src/linux_bindings.rs"
-        }
-        #[cfg(target_os = "windows")]
-        {
-            "https://raw.githubusercontent.com/Noxime/steamworks-rs/master/steamworks-sys/src/windows_bindings.rs"
-        }
-        #[cfg(target_os = "macos")]
-        {
-            "https://raw.githubusercontent.com/Noxime/steamworks-rs/master/steamworks-sys/src/macos_bindings.rs"
-        }
-    }).and_then(|x| x.text()).expect("failed to fetch bindings from github");
-    let mut generated = String::new();
-    let code: syn::File = syn::parse_str(&bindings).expect("failed to parse bindings");
-    for item in code.items {
-        #[allow(clippy::single_match)]
-        match item {
-            syn::Item::ForeignMod(item) => {
-                assert_eq!(item.attrs, []);
-                assert_eq!(item.unsafety, None);
-                assert_eq!(
-                    item.abi.name.as_ref().map(|x| format!("{}", x.token())),
-                    Some(format!("{}", proc_macro2::Literal::string("C")))
-                );
-                for it in item.items {
-                    match it {
-                        syn::ForeignItem::Fn(it) => {
-                            let ident = format!("{}", it.sig.ident);
-                            if &ident == "SteamInternal_FindOrCreateUserInterface" {
-                                continue;
-                            }
-                            if !ident.contains("Steam") && !ident.starts_with('C') {
-                                continue;
-                            }
-                            let mut link_name = None;
-                            assert!(matches!(it.vis, syn::Visibility::Public(_)));
-                            for attr in it.attrs {
-                                match attr.meta {
-                                    syn::Meta::NameValue(meta) => {
-                                        let name = format!("{}", meta.path.get_ident().unwrap());
-                                        if name == "doc" {
-                                            continue;
-                                        }
-                                        assert_eq!(name, "link_name");
-                                        link_name = Some(match meta.value {
-                                            syn::Expr::Lit(syn::ExprLit {
-                                                lit: syn::Lit::Str(lit),
-                                                ..
-                                            }) => lit.token(),
-                                            _ => panic!(),
-                                        });
-                                    }
-                                    _ => panic!(),
-                                }
-                            }
-                            assert_eq!(it.sig.constness, None);
-                            assert_eq!(it.sig.asyncness, None);
-                            assert_eq!(it.sig.unsafety, None);
-                            assert_eq!(it.sig.abi, None);
-                            assert_eq!(it.sig.variadic, None);
-                            assert_eq!(it.sig.generics.params.len(), 0);
-                            let return_type = match it.sig.output {
-                                syn::ReturnType::Type(_, typ) => Some(typ),
-                                syn::ReturnType::Default => None,
-                            };
-                            let inputs = it.sig.inputs;
-                            generated.push_str("reexport!(");
-                            if let Some(link_name) = link_name {
-                                generated.push_str(&format!("{}", link_name));
-                                generated.push_str(", ");
-                            }
-                            generated.push_str("fn ");
-                            generated.push_str(&ident);
-                            generated.push('(');
-                            let mut first = true;
-                            for inp in inputs {
-                                if first {
-                                    first = false;
-                                } else {
-                                    generated.push_str(", ");
-                                }
-                                generated.push_str(&match inp {
-                                    syn::FnArg::Typed(arg) => {
-                                        assert!(arg.attrs.is_empty());
-                                        format!("{}", arg.into_token_stream())
-                                    }
-                                    _ => panic!(),
-                                });
-                            }
-                            generated.push(')');
-                            if let Some(ret) = return_type {
-                                generated.push_str(&format!(" -> {}", ret.into_token_stream()));
-                            }
-                            generated.push_str(");\n");
-                        }
-                        syn::ForeignItem::Static(..) => {}
-                        _ => panic!(),
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
+    use konigsberg_gen::Platform;
+    use std::path::PathBuf;
 
-    let mut out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
-    out_dir.push("reexports.rs");
-    std::fs::write(out_dir, generated).expect("failed to generate reexports");
+    let platform = Platform::from_target_os(&std::env::var("CARGO_CFG_TARGET_OS").unwrap());
+    let steamworks_sys = || {
+        konigsberg_gen::steamworks_sys_dir(
+            &std::env::var_os("CARGO").unwrap(),
+            &PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.toml"),
+        )
+    };
+    let bindings = match std::env::var_os("KONIGSBERG_BINDINGS").map(PathBuf::from) {
+        Some(path) if path.is_dir() => platform.bindings(&path),
+        Some(path) => path,
+        None => platform.bindings(&steamworks_sys()),
+    };
+    let reference = std::env::var_os("KONIGSBERG_REFERENCE_LIB")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| platform.reference_lib(&steamworks_sys()));
+    println!("cargo:rerun-if-env-changed=KONIGSBERG_BINDINGS");
+    println!("cargo:rerun-if-env-changed=KONIGSBERG_REFERENCE_LIB");
+    println!("cargo:rerun-if-changed={}", bindings.display());
+    println!("cargo:rerun-if-changed={}", reference.display());
+    let code = konigsberg_gen::parse(&bindings);
+    let reexports = konigsberg_gen::reexports(&code);
+    let dynamic = konigsberg_gen::dynamic_exports(&reference, &reexports);
+
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    let write = |name: &str, contents: &str| {
+        std::fs::write(out_dir.join(name), contents)
+            .unwrap_or_else(|err| panic!("failed to generate {name}: {err}"));
+    };
+    write("reexports.rs", &(reexports.join("\n") + "\n"));
+    write(
+        "vtable_slots.rs",
+        &konigsberg_gen::vtable_slots(&code, &[reference.clone()]),
+    );
+    write("callback_types.rs", &konigsberg_gen::callback_types(&code));
+    write(
+        "dynamic_exports.rs",
+        &konigsberg_gen::dynamic_exports_file(&[(platform, dynamic)]),
+    );
 }
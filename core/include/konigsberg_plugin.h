@@ -0,0 +1,57 @@
+/* Plugin API for konigsberg. See core/src/plugin.rs for how plugins are
+ * found and loaded. */
+
+#ifndef KONIGSBERG_PLUGIN_H
+#define KONIGSBERG_PLUGIN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define KONIGSBERG_API_VERSION 1
+
+typedef struct konigsberg_api {
+    /* KONIGSBERG_API_VERSION of the konigsberg that loaded the plugin.
+     * Fields are only ever added at the end. */
+    uint32_t version;
+    /* sizeof(konigsberg_api) in that konigsberg. */
+    uint32_t size;
+
+    /* Replace `slot` of the vtable of the interface class named `class_name`
+     * (like "ISteamApps"), for versions `min_version` to `max_version`
+     * inclusive, with `func`. `method` names it for call_original, like
+     * "ISteamApps::GetCurrentGameLanguage". Plugin hooks take precedence over
+     * konigsberg's own for the same slot. Returns false if an argument is
+     * null or the class is unknown. */
+    bool (*register_vtable_hook)(const char *class_name, uint8_t min_version,
+                                 uint8_t max_version, size_t slot,
+                                 const char *method, const void *func);
+    /* Make konigsberg's export `symbol` call `func`, which must have the same
+     * signature, instead of the original library's. Returns false if an
+     * argument is null. */
+    bool (*register_export_hook)(const char *symbol, const void *func);
+    /* The function a hook of `method` on `self` calls to call through to
+     * whatever was in the slot before, or null. */
+    const void *(*call_original)(void *self, const char *method);
+    /* The original library's `symbol`, for an export hook to call through
+     * to, or null if it's missing. The on_missing policy doesn't apply. */
+    const void *(*original_export)(const char *symbol);
+    /* Write `message` to konigsberg's log. */
+    void (*log)(const char *message);
+} konigsberg_api;
+
+/* Exported by every plugin. Registers its hooks through `api`, which stays
+ * valid for the life of the process, and returns whether it succeeded. It can
+ * call Steam API exports, but one it calls before registering an export hook
+ * for it keeps calling the original. */
+bool konigsberg_plugin_init(const konigsberg_api *api);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
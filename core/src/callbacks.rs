@@ -0,0 +1,543 @@
+//! Opt-in report of the callbacks a game registers for and is delivered,
+//! and the API call results it waits for, with payload sizes and how long
+//! its handlers take.
+//!
+//! Set `KONIGSBERG_CALLBACKS` or the `callbacks` config key to a file path
+//! to enable it. Both ways of receiving callbacks are observed:
+//!
+//! - `CCallbackBase` objects passed to `SteamAPI_RegisterCallback` and
+//!   `SteamAPI_RegisterCallResult` are pointed at a copy of their vtable
+//!   whose `Run` methods record each delivery before calling the game's.
+//! - With manual dispatch, each callback is recorded when
+//!   `SteamAPI_ManualDispatch_GetNextCallback` returns it, and handled until
+//!   `SteamAPI_ManualDispatch_FreeLastCallback`. Call results are recorded
+//!   when `SteamAPI_ManualDispatch_GetAPICallResult` returns them.
+//!
+//! Call results, including those `ISteamUtils::GetAPICallResult` returns, are
+//! passed on to [`crate::calls`] too.
+//!
+//! The report is rewritten by [`shutdown`] and [`write()`], which the
+//! konigsberg shim calls from `SteamAPI_Shutdown` and
+//! `konigsberg_write_callbacks`. It has one line per callback id, like
+//! `{"callback":703,"name":"SteamAPICallCompleted_t","registered":0,"game_server":false,"awaited":0,"delivered":2,"call_results":0,"io_failures":0,"size":16,"bytes":32,"handler_ns":5120,"max_handler_ns":4096}`,
+//! where `name` is there if the id has a registered [`CallbackType`],
+//! `registered` counts `CCallback` registrations, `game_server` is
+//! whether any of them was for the game server, `awaited` counts
+//! `CCallResult` registrations, and `size` is the last payload size seen.
+//!
+//! When tracing is on (see [`crate::trace`]), callbacks are observed even
+//! without the report, and every delivery is written to the trace with its
+//! payload decoded into fields by its [`CallbackType`], like
+//! `{"callback":304,"name":"PersonaStateChange_t","payload":{"m_ulSteamID":76561197960287930,"m_nChangeFlags":1},"thread":1234}`.
+//! [`callback_types!`](crate::callback_types) builds the types from a list of
+//! structs and fields that `konigsberg-gen` generates from the bindings.
+
+use crate::{hooks, log::log, report::Report, vtable_method};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    ffi::{c_int, c_void, OsStr},
+    fmt::Write as _,
+    time::{Duration, Instant},
+};
+use steamworks_sys::{
+    CCallbackBase, CCallbackBase_k_ECallbackFlagsGameServer, CallbackMsg_t, HSteamPipe,
+    SteamAPICall_t,
+};
+
+/// Slots of `CCallbackBase`'s virtual methods. MSVC puts overloads of a
+/// virtual method in the vtable in reverse order.
+#[cfg(not(windows))]
+const RUN: usize = 0;
+#[cfg(not(windows))]
+const RUN_CALL_RESULT: usize = 1;
+#[cfg(windows)]
+const RUN: usize = 1;
+#[cfg(windows)]
+const RUN_CALL_RESULT: usize = 0;
+const GET_CALLBACK_SIZE_BYTES: usize = 2;
+
+vtable_method! {
+    type RunFn = fn(*mut c_void);
+}
+vtable_method! {
+    type RunCallResultFn = fn(*mut c_void, bool, SteamAPICall_t);
+}
+vtable_method! {
+    type SizeFn = fn() -> c_int;
+}
+
+#[derive(Default)]
+struct Stats {
+    registered: u64,
+    game_server: bool,
+    awaited: u64,
+    delivered: u64,
+    call_results: u64,
+    io_failures: u64,
+    size: Option<c_int>,
+    bytes: u64,
+    handler: Duration,
+    max_handler: Duration,
+}
+
+impl Stats {
+    fn payload(&mut self, size: c_int, handler: Option<Duration>) {
+        self.size = Some(size);
+        self.bytes += u64::try_from(size).unwrap_or(0);
+        if let Some(handler) = handler {
+            self.handled(handler);
+        }
+    }
+
+    fn handled(&mut self, handler: Duration) {
+        self.handler += handler;
+        self.max_handler = self.max_handler.max(handler);
+    }
+}
+
+#[derive(Default)]
+struct State {
+    stats: BTreeMap<c_int, Stats>,
+    /// Original vtable of each copy objects are pointed at.
+    originals: HashMap<usize, usize>,
+    /// Copy of each original vtable.
+    copies: HashMap<usize, usize>,
+    /// Objects pointed at a copy.
+    objects: HashSet<usize>,
+    /// Vtables that couldn't be copied, so it's only logged once.
+    refused: HashSet<usize>,
+    /// Callback each pipe is handling under manual dispatch, and since when.
+    handling: HashMap<HSteamPipe, (c_int, Instant)>,
+    types: HashMap<c_int, &'static CallbackType>,
+}
+
+/// The name of one callback id's struct, and how to show its payload.
+pub struct CallbackType {
+    pub id: c_int,
+    pub name: &'static str,
+    /// Render a payload as a JSON object of its fields, or `None` if it's
+    /// too short to be one.
+    pub decode: fn(&[u8]) -> Option<String>,
+}
+
+static REPORT: Report<State> = Report::new(
+    "callback report",
+    |config| config.callbacks.as_deref(),
+    lines,
+);
+
+/// Whether the report is enabled in the config.
+pub fn enabled() -> bool {
+    REPORT.enabled()
+}
+
+/// Whether anything wants to know about callbacks.
+fn observing() -> bool {
+    enabled() || crate::trace::enabled() || crate::calls::enabled()
+}
+
+/// Name and decode the callback ids in `types` from now on, replacing any
+/// types registered for the same ids before. The SDK reuses some ids, the
+/// first type in `types` with an id is the one used.
+pub fn register_types(types: &'static [CallbackType]) {
+    let mut state = REPORT.state();
+    for ty in types.iter().rev() {
+        state.types.insert(ty.id, ty);
+    }
+}
+
+/// Name of the struct for callback `id`, if its type is registered.
+pub fn name(id: c_int) -> Option<&'static str> {
+    Some(REPORT.state().types.get(&id)?.name)
+}
+
+/// Render `payload` of callback `id` as a JSON object of its fields, if its
+/// type is registered and it's long enough.
+pub fn decode(id: c_int, payload: &[u8]) -> Option<String> {
+    let decode = REPORT.state().types.get(&id)?.decode;
+    decode(payload)
+}
+
+/// Write a delivery of callback `id` with `size` bytes at `data` to the
+/// trace, with the call it's the result of, if any.
+unsafe fn trace(id: c_int, data: *const c_void, size: c_int, call: Option<(SteamAPICall_t, bool)>) {
+    if !crate::trace::enabled() {
+        return;
+    }
+    let payload = match usize::try_from(size) {
+        Ok(size) if !data.is_null() => {
+            decode(id, std::slice::from_raw_parts(data as *const u8, size))
+        }
+        _ => None,
+    };
+    crate::trace::record_callback(id, name(id), payload, call);
+}
+
+/// Point `callback` at a copy of its vtable with the `Run` methods wrapped,
+/// unless it already is.
+unsafe fn wrap(callback: *mut CCallbackBase) {
+    const WORD: usize = std::mem::size_of::<usize>();
+    let object = callback as usize;
+    if !hooks::readable(object, std::mem::size_of::<CCallbackBase>()) {
+        return;
+    }
+    let vtable = *(object as *const usize);
+    let mut state = REPORT.state();
+    if state.originals.contains_key(&vtable) || state.refused.contains(&vtable) {
+        return;
+    }
+    let copy = match state.copies.get(&vtable) {
+        Some(&copy) => copy,
+        None => {
+            let len = GET_CALLBACK_SIZE_BYTES + 1;
+            let is_code = |i: usize| {
+                let func = *(vtable as *const usize).add(i);
+                region::query(func as *const u8).is_ok_and(|r| r.is_executable())
+            };
+            if !hooks::readable(vtable, len * WORD) || !(0..len).all(is_code) {
+                log!("not observing callbacks with the vtable at {vtable:#x}, it doesn't look like a CCallbackBase one");
+                state.refused.insert(vtable);
+                return;
+            }
+            let copy = hooks::clone_vtable(vtable as *const usize, len);
+            *copy.add(RUN) = run as RunFn as usize;
+            *copy.add(RUN_CALL_RESULT) = run_call_result as RunCallResultFn as usize;
+            state.copies.insert(vtable, copy as usize);
+            state.originals.insert(copy as usize, vtable);
+            copy as usize
+        }
+    };
+    *(object as *mut usize) = copy;
+    state.objects.insert(object);
+}
+
+/// Point `object` back at its original vtable.
+unsafe fn unwrap(state: &mut State, object: usize) {
+    state.objects.remove(&object);
+    if !hooks::readable(object, std::mem::size_of::<usize>()) {
+        return;
+    }
+    if let Some(&original) = state.originals.get(&*(object as *const usize)) {
+        *(object as *mut usize) = original;
+    }
+}
+
+/// The original vtable of `object`, which a `Run` wrapper was reached
+/// through a copy of. An unregistration or [`restore`] can have pointed the
+/// object back at the original since Steam looked the wrapper up, which
+/// mustn't drop the delivery.
+unsafe fn original(state: &State, object: usize) -> Option<usize> {
+    let vtable = *(object as *const usize);
+    match state.originals.get(&vtable) {
+        Some(&original) => Some(original),
+        None => state.copies.contains_key(&vtable).then_some(vtable),
+    }
+}
+
+unsafe fn slot<F: Copy>(vtable: usize, index: usize) -> F {
+    std::mem::transmute_copy(&*(vtable as *const usize).add(index))
+}
+
+vtable_method! {
+    unsafe fn run(this: *mut c_void, param: *mut c_void) {
+        let Some(vtable) = original(&REPORT.state(), this as usize) else {
+            return;
+        };
+        let id = (*(this as *const CCallbackBase)).m_iCallback;
+        let size = slot::<SizeFn>(vtable, GET_CALLBACK_SIZE_BYTES);
+        let size = vtable_method!(call size(this));
+        trace(id, param, size, None);
+        let func = slot::<RunFn>(vtable, RUN);
+        let start = Instant::now();
+        vtable_method!(call func(this, param));
+        let handler = start.elapsed();
+        let mut state = REPORT.state();
+        let stats = state.stats.entry(id).or_default();
+        stats.delivered += 1;
+        stats.payload(size, Some(handler));
+    }
+}
+
+vtable_method! {
+    unsafe fn run_call_result(
+        this: *mut c_void,
+        param: *mut c_void,
+        io_failure: bool,
+        call: SteamAPICall_t,
+    ) {
+        let vtable = {
+            let mut state = REPORT.state();
+            let vtable = original(&state, this as usize);
+            // call results are delivered once, and the object may be gone after
+            unwrap(&mut state, this as usize);
+            vtable
+        };
+        let Some(vtable) = vtable else {
+            return;
+        };
+        let id = (*(this as *const CCallbackBase)).m_iCallback;
+        let size = slot::<SizeFn>(vtable, GET_CALLBACK_SIZE_BYTES);
+        let size = vtable_method!(call size(this));
+        trace(id, param, size, Some((call, io_failure)));
+        crate::calls::completed(call, id, io_failure);
+        let func = slot::<RunCallResultFn>(vtable, RUN_CALL_RESULT);
+        let start = Instant::now();
+        vtable_method!(call func(this, param, io_failure, call));
+        let handler = start.elapsed();
+        let mut state = REPORT.state();
+        let stats = state.stats.entry(id).or_default();
+        stats.call_results += 1;
+        stats.io_failures += u64::from(io_failure);
+        stats.payload(size, Some(handler));
+    }
+}
+
+/// Record `callback` being registered for callback `id`, and start observing
+/// its deliveries. Call before forwarding the registration.
+///
+/// # Safety
+///
+/// `callback` must be null or point to a `CCallbackBase`.
+pub unsafe fn register_callback(callback: *mut CCallbackBase, id: c_int) {
+    if callback.is_null() || !observing() {
+        return;
+    }
+    {
+        let mut state = REPORT.state();
+        let stats = state.stats.entry(id).or_default();
+        stats.registered += 1;
+        stats.game_server |=
+            (*callback).m_nCallbackFlags & CCallbackBase_k_ECallbackFlagsGameServer as u8 != 0;
+    }
+    wrap(callback);
+}
+
+/// Stop observing `callback`. Call after forwarding the unregistration.
+///
+/// # Safety
+///
+/// `callback` must be null or point to a `CCallbackBase`.
+pub unsafe fn unregister_callback(callback: *mut CCallbackBase) {
+    if !callback.is_null() && observing() {
+        unwrap(&mut REPORT.state(), callback as usize);
+    }
+}
+
+/// Record `callback` waiting for the result of `call`, and start observing
+/// it. Call before forwarding the registration.
+///
+/// # Safety
+///
+/// `callback` must be null or point to a `CCallbackBase`.
+pub unsafe fn register_call_result(callback: *mut CCallbackBase, call: SteamAPICall_t) {
+    if callback.is_null() || !observing() {
+        return;
+    }
+    let id = (*callback).m_iCallback;
+    REPORT.state().stats.entry(id).or_default().awaited += 1;
+    crate::calls::awaited(call, id);
+    wrap(callback);
+}
+
+/// Stop observing `callback`. Call after forwarding the unregistration.
+///
+/// # Safety
+///
+/// `callback` must be null or point to a `CCallbackBase`.
+pub unsafe fn unregister_call_result(callback: *mut CCallbackBase, _call: SteamAPICall_t) {
+    unregister_callback(callback);
+}
+
+/// Record the callback `SteamAPI_ManualDispatch_GetNextCallback` returned in
+/// `msg` for `pipe`, which is being handled until [`free_last_callback`].
+///
+/// # Safety
+///
+/// `msg` must be null or point to a `CallbackMsg_t`.
+pub unsafe fn next_callback(pipe: HSteamPipe, msg: *const CallbackMsg_t) {
+    let Some(msg) = msg.as_ref().filter(|_| observing()) else {
+        return;
+    };
+    trace(
+        msg.m_iCallback,
+        msg.m_pubParam as *const c_void,
+        msg.m_cubParam,
+        None,
+    );
+    let mut state = REPORT.state();
+    let stats = state.stats.entry(msg.m_iCallback).or_default();
+    stats.delivered += 1;
+    stats.payload(msg.m_cubParam, None);
+    state
+        .handling
+        .insert(pipe, (msg.m_iCallback, Instant::now()));
+}
+
+/// Record the game being done with the last callback on `pipe`.
+pub fn free_last_callback(pipe: HSteamPipe) {
+    if !observing() {
+        return;
+    }
+    let mut state = REPORT.state();
+    if let Some((id, start)) = state.handling.remove(&pipe) {
+        state.stats.entry(id).or_default().handled(start.elapsed());
+    }
+}
+
+/// Record the result of `call` that `SteamAPI_ManualDispatch_GetAPICallResult`
+/// or `ISteamUtils::GetAPICallResult` returned: callback `id`, `size` bytes at
+/// `data`, and whether there was an IO failure.
+///
+/// # Safety
+///
+/// `data` must be null or point to `size` readable bytes.
+pub unsafe fn api_call_result(
+    call: SteamAPICall_t,
+    id: c_int,
+    data: *const c_void,
+    size: c_int,
+    io_failure: bool,
+) {
+    if !observing() {
+        return;
+    }
+    trace(id, data, size, Some((call, io_failure)));
+    crate::calls::completed(call, id, io_failure);
+    let mut state = REPORT.state();
+    let stats = state.stats.entry(id).or_default();
+    stats.call_results += 1;
+    stats.io_failures += u64::from(io_failure);
+    stats.payload(size, None);
+}
+
+/// Point every observed object back at its own vtable.
+///
+/// # Safety
+///
+/// Observed objects that weren't unregistered must still be alive, or at
+/// least their memory mapped.
+pub unsafe fn restore() {
+    let mut state = REPORT.state();
+    for object in state.objects.clone() {
+        unwrap(&mut state, object);
+    }
+}
+
+fn lines(state: &State) -> String {
+    let mut out = String::new();
+    for (id, stats) in &state.stats {
+        let _ = write!(out, "{{\"callback\":{id},");
+        if let Some(ty) = state.types.get(id) {
+            let _ = write!(out, "\"name\":\"{}\",", ty.name);
+        }
+        let _ = writeln!(
+            out,
+            "\"registered\":{},\"game_server\":{},\"awaited\":{},\
+             \"delivered\":{},\"call_results\":{},\"io_failures\":{},\"size\":{},\"bytes\":{},\
+             \"handler_ns\":{},\"max_handler_ns\":{}}}",
+            stats.registered,
+            stats.game_server,
+            stats.awaited,
+            stats.delivered,
+            stats.call_results,
+            stats.io_failures,
+            stats
+                .size
+                .map_or_else(|| "null".to_owned(), |size| size.to_string()),
+            stats.bytes,
+            stats.handler.as_nanos(),
+            stats.max_handler.as_nanos(),
+        );
+    }
+    out
+}
+
+/// Write the report to `path`, or to the configured path if it's `None`.
+/// Returns whether it was written. Callbacks are only recorded while the
+/// report is enabled in the config.
+pub fn write(path: Option<&OsStr>) -> bool {
+    REPORT.write(path)
+}
+
+/// Write the report to the configured path, if there is one.
+pub fn shutdown() {
+    REPORT.shutdown();
+}
+
+/// A `&'static [CallbackType]` from a list of callback structs, their ids
+/// and their fields, with the structs in scope. Fields that are structs
+/// themselves can list their own fields in braces to be shown field by
+/// field. Enum fields have to be read `as` their integer type and bool
+/// fields `as bool`, since a payload can hold any value in them:
+///
+/// ```ignore
+/// static TYPES: &[CallbackType] = callback_types! {
+///     PersonaStateChange_t = 304 {
+///         m_ulSteamID,
+///         m_nChangeFlags,
+///     }
+///     SteamNetConnectionStatusChangedCallback_t = 1221 {
+///         m_hConn,
+///         m_info: {
+///             m_eState as i32,
+///             m_szConnectionDescription,
+///         },
+///         m_eOldState as i32,
+///     }
+/// };
+/// ```
+///
+/// Other fields are shown the way the trace shows arguments. Each field is
+/// read from the payload on its own, the struct never is.
+#[macro_export]
+macro_rules! callback_types {
+    ($($name:ident = $id:literal { $($fields:tt)* })*) => {
+        &[$(
+            $crate::callbacks::CallbackType {
+                id: $id,
+                name: stringify!($name),
+                decode: |payload| {
+                    if payload.len() < std::mem::size_of::<$name>() {
+                        return None;
+                    }
+                    let value = payload.as_ptr() as *const $name;
+                    // SAFETY: the fields are in the payload, and are read as
+                    // types every bit pattern is valid for
+                    Some(unsafe { $crate::callback_fields!(value, { $($fields)* }) })
+                },
+            },
+        )*]
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! callback_fields {
+    (@value $field:ident, $nested:tt) => {
+        $crate::callback_fields!($field, $nested)
+    };
+    (@value $field:ident as bool) => {
+        $crate::trace_value!(($field as *const u8).read_unaligned() != 0)
+    };
+    (@value $field:ident as $int:ident) => {
+        $crate::trace_value!(($field as *const $int).read_unaligned())
+    };
+    (@value $field:ident) => {
+        $crate::trace_value!($field.read_unaligned())
+    };
+    ($value:ident, { $($field:ident $(: $nested:tt)? $(as $int:ident)?),* $(,)? }) => {{
+        #[allow(unused_mut)]
+        let mut fields = Vec::<String>::new();
+        $(
+            // not borrowed, fields of packed structs can be unaligned
+            #[allow(non_snake_case)]
+            let $field = std::ptr::addr_of!((*$value).$field);
+            fields.push(format!(
+                "\"{}\":{}",
+                stringify!($field),
+                $crate::callback_fields!(@value $field $(, $nested)? $(as $int)?),
+            ));
+        )*
+        format!("{{{}}}", fields.join(","))
+    }};
+}
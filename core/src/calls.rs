@@ -0,0 +1,179 @@
+//! Opt-in report of the asynchronous API calls a game makes: each
+//! `SteamAPICall_t` handle from when a forwarder returns it to when the game
+//! gets its result, by the function that issued it.
+//!
+//! Set `KONIGSBERG_CALLS` or the `calls` config key to a file path to enable
+//! it. A handle completes when its result is handed to the game, by
+//! `ISteamUtils::GetAPICallResult`, `SteamAPI_ManualDispatch_GetAPICallResult`
+//! or a `CCallResult` registered with `SteamAPI_RegisterCallResult` being run,
+//! see [`crate::callbacks`].
+//!
+//! Handles a game gets from interface methods rather than flat exports
+//! aren't seen being issued. They're tracked from their `CCallResult`
+//! registration instead if there is one, and counted by the id of the
+//! callback they complete with.
+//!
+//! The report is rewritten by [`shutdown`] and [`write()`], which the
+//! konigsberg shim calls from `SteamAPI_Shutdown` and `konigsberg_write_calls`.
+//! It has one line per function, like
+//! `{"fn":"SteamAPI_ISteamUserStats_FindLeaderboard","issued":2,"invalid":0,"completed":1,"failed":0,"mean_latency_ns":51200,"max_latency_ns":51200,"pending":[{"call":12,"age_ns":90210}]}`,
+//! where `invalid` counts `k_uAPICallInvalid` returns, `failed` counts
+//! results with the failure flag (`pbFailed`) set, and `pending` lists the
+//! handles that haven't completed, oldest first. Handles of unknown
+//! functions are counted on lines with `"fn":null` and the `callback` id and
+//! its `name` instead, where `issued` counts the handles seen registered.
+
+use crate::report::Report;
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    ffi::{c_int, OsStr},
+    fmt::Write as _,
+    time::{Duration, Instant},
+};
+use steamworks_sys::{k_uAPICallInvalid, SteamAPICall_t};
+
+/// Where a handle came from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Source {
+    /// Returned by this forwarder.
+    Function(&'static str),
+    /// First seen being waited for with a `CCallResult` for this callback,
+    /// or completing with it.
+    Callback(c_int),
+}
+
+#[derive(Default)]
+struct Stats {
+    issued: u64,
+    invalid: u64,
+    completed: u64,
+    failed: u64,
+    /// Completions whose handle was seen before, so have a latency.
+    timed: u64,
+    latency: Duration,
+    max_latency: Duration,
+}
+
+#[derive(Default)]
+struct State {
+    stats: BTreeMap<Source, Stats>,
+    pending: HashMap<SteamAPICall_t, (Source, Instant)>,
+}
+
+static REPORT: Report<State> =
+    Report::new("API call report", |config| config.calls.as_deref(), lines);
+
+/// Whether the report is enabled in the config.
+pub fn enabled() -> bool {
+    REPORT.enabled()
+}
+
+/// Record forwarder `function` returning `call`.
+pub fn issued(function: &'static str, call: SteamAPICall_t) {
+    if !enabled() {
+        return;
+    }
+    let mut state = REPORT.state();
+    let source = Source::Function(function);
+    let stats = state.stats.entry(source).or_default();
+    if call == k_uAPICallInvalid {
+        stats.invalid += 1;
+        return;
+    }
+    stats.issued += 1;
+    state.pending.insert(call, (source, Instant::now()));
+}
+
+/// Record a `CCallResult` for callback `id` waiting for `call`, which is
+/// tracked from now on if it wasn't already.
+pub fn awaited(call: SteamAPICall_t, id: c_int) {
+    if !enabled() || call == k_uAPICallInvalid {
+        return;
+    }
+    let state = &mut *REPORT.state();
+    if let Entry::Vacant(entry) = state.pending.entry(call) {
+        entry.insert((Source::Callback(id), Instant::now()));
+        state.stats.entry(Source::Callback(id)).or_default().issued += 1;
+    }
+}
+
+/// Record the game getting the result of `call`, callback `id`, and whether
+/// it failed.
+pub fn completed(call: SteamAPICall_t, id: c_int, failed: bool) {
+    if !enabled() {
+        return;
+    }
+    let mut state = REPORT.state();
+    let (source, latency) = match state.pending.remove(&call) {
+        Some((source, issued)) => (source, Some(issued.elapsed())),
+        None => (Source::Callback(id), None),
+    };
+    let stats = state.stats.entry(source).or_default();
+    stats.completed += 1;
+    stats.failed += u64::from(failed);
+    if let Some(latency) = latency {
+        stats.timed += 1;
+        stats.latency += latency;
+        stats.max_latency = stats.max_latency.max(latency);
+    }
+}
+
+fn lines(state: &State) -> String {
+    let now = Instant::now();
+    let mut out = String::new();
+    for (source, stats) in &state.stats {
+        match source {
+            Source::Function(function) => {
+                let _ = write!(out, "{{\"fn\":\"{function}\",");
+            }
+            Source::Callback(id) => {
+                let _ = write!(out, "{{\"fn\":null,\"callback\":{id},");
+                if let Some(name) = crate::callbacks::name(*id) {
+                    let _ = write!(out, "\"name\":\"{name}\",");
+                }
+            }
+        }
+        let mean = match stats.timed {
+            0 => "null".to_owned(),
+            timed => (stats.latency.as_nanos() / u128::from(timed)).to_string(),
+        };
+        let _ = write!(
+            out,
+            "\"issued\":{},\"invalid\":{},\"completed\":{},\"failed\":{},\
+             \"mean_latency_ns\":{mean},\"max_latency_ns\":{},\"pending\":[",
+            stats.issued,
+            stats.invalid,
+            stats.completed,
+            stats.failed,
+            stats.max_latency.as_nanos(),
+        );
+        let mut pending = state
+            .pending
+            .iter()
+            .filter(|(_, (from, _))| from == source)
+            .map(|(&call, &(_, issued))| (issued, call))
+            .collect::<Vec<_>>();
+        pending.sort();
+        for (i, (issued, call)) in pending.into_iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let age = now.duration_since(issued).as_nanos();
+            let _ = write!(out, "{{\"call\":{call},\"age_ns\":{age}}}");
+        }
+        out.push_str("]}\n");
+    }
+    out
+}
+
+/// Write the report to `path`, or to the configured path if it's `None`.
+/// Returns whether it was written. Calls are only recorded while the report
+/// is enabled in the config.
+pub fn write(path: Option<&OsStr>) -> bool {
+    REPORT.write(path)
+}
+
+/// Write the report to the configured path, if there is one.
+pub fn shutdown() {
+    REPORT.shutdown();
+}
@@ -0,0 +1,270 @@
+//! Settings from `konigsberg.cfg` and the environment.
+//!
+//! The config file is looked up next to the konigsberg library itself, or at
+//! `KONIGSBERG_CONFIG` if that's set. It has one `key = value` per line, `#`
+//! starts a comment, and relative paths are relative to the file's directory:
+//!
+//! ```text
+//! # tried in order, before the built-in names
+//! lib = ../steam/libsteam_api.so
+//! lib = /opt/steam/libsteam_api.so
+//! trace = konigsberg-trace.jsonl
+//! log = konigsberg.log
+//! inventory = konigsberg-interfaces.jsonl
+//! callbacks = konigsberg-callbacks.jsonl
+//! calls = konigsberg-calls.jsonl
+//! lint = konigsberg-lint.jsonl
+//! threads = konigsberg-threads.jsonl
+//! # how long the game can go without running callbacks, in milliseconds
+//! lint_callback_gap = 1000
+//! # what to do when the library or a symbol is missing: abort, default or fallback
+//! on_missing = abort
+//! on_missing.SteamAPI_SteamApps_v009 = default
+//! # how vtables are hooked: in_place or copy
+//! patch_mode = in_place
+//! # every plugin in this directory is loaded on first use
+//! plugins = konigsberg-plugins
+//! ```
+//!
+//! Environment variables take precedence over the file:
+//!
+//! - `KONIGSBERG_LIB`: original library candidates, separated like `PATH`
+//! - `KONIGSBERG_TRACE`: see [`crate::trace`]
+//! - `KONIGSBERG_LOG`: where diagnostics go instead of stderr
+//! - `KONIGSBERG_INVENTORY`: see [`crate::inventory`]
+//! - `KONIGSBERG_CALLBACKS`: see [`crate::callbacks`]
+//! - `KONIGSBERG_CALLS`: see [`crate::calls`]
+//! - `KONIGSBERG_LINT`, `KONIGSBERG_LINT_CALLBACK_GAP`: see [`crate::lint`]
+//! - `KONIGSBERG_THREADS`: see [`crate::threads`]
+//! - `KONIGSBERG_ON_MISSING`: the default `on_missing` policy
+//! - `KONIGSBERG_PATCH_MODE`: `patch_mode`
+//! - `KONIGSBERG_PLUGINS`: see [`crate::plugin`]
+
+use once_cell::sync::OnceCell;
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub(crate) const FILE_NAME: &str = "konigsberg.cfg";
+
+/// What a forwarder does when its symbol (or the whole library) is missing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) enum OnMissing {
+    /// Log and abort the process.
+    #[default]
+    Abort,
+    /// Log and return zero, null or false.
+    Default,
+    /// Call the function registered with `konigsberg_register_fallback`,
+    /// or act like `Default` if there is none.
+    Fallback,
+}
+
+impl std::str::FromStr for OnMissing {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "abort" => Ok(Self::Abort),
+            "default" => Ok(Self::Default),
+            "fallback" => Ok(Self::Fallback),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How hooks get into an interface's vtable.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) enum PatchMode {
+    /// Overwrite the slots in the original library's vtable, which affects
+    /// every object of the class.
+    #[default]
+    InPlace,
+    /// Point the object at a hooked copy of its vtable, leaving the original
+    /// untouched.
+    Copy,
+}
+
+impl std::str::FromStr for PatchMode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "in_place" => Ok(Self::InPlace),
+            "copy" => Ok(Self::Copy),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Config {
+    /// Original library candidates, tried before the built-in names.
+    pub lib: Vec<PathBuf>,
+    pub trace: Option<OsString>,
+    pub log: Option<OsString>,
+    pub inventory: Option<OsString>,
+    pub callbacks: Option<OsString>,
+    pub calls: Option<OsString>,
+    pub lint: Option<OsString>,
+    /// `None` for the default.
+    pub lint_callback_gap: Option<Duration>,
+    pub threads: Option<OsString>,
+    pub on_missing: OnMissing,
+    pub on_missing_for: HashMap<String, OnMissing>,
+    pub patch_mode: PatchMode,
+    pub plugins: Option<PathBuf>,
+    warnings: Vec<String>,
+}
+
+impl Config {
+    pub fn on_missing(&self, symbol: &str) -> OnMissing {
+        self.on_missing_for
+            .get(symbol)
+            .copied()
+            .unwrap_or(self.on_missing)
+    }
+}
+
+pub(crate) fn get() -> &'static Config {
+    static CELL: OnceCell<Config> = OnceCell::new();
+    let mut loaded = false;
+    let config = CELL.get_or_init(|| {
+        loaded = true;
+        load()
+    });
+    // logging reads the config, so this can only happen once it's in place
+    if loaded {
+        for warning in &config.warnings {
+            crate::log::log!("{warning}");
+        }
+    }
+    config
+}
+
+fn load() -> Config {
+    let mut config = Config::default();
+    let path = std::env::var_os("KONIGSBERG_CONFIG")
+        .map(PathBuf::from)
+        .or_else(|| Some(crate::os::module_path()?.parent()?.join(FILE_NAME)));
+    if let Some(path) = path {
+        if let Ok(text) = std::fs::read_to_string(&path) {
+            config.parse(&text, path.parent().unwrap_or(Path::new("")));
+        }
+    }
+    if let Some(lib) = std::env::var_os("KONIGSBERG_LIB") {
+        let mut paths = std::env::split_paths(&lib).collect::<Vec<_>>();
+        paths.append(&mut config.lib);
+        config.lib = paths;
+    }
+    if let Some(trace) = std::env::var_os("KONIGSBERG_TRACE") {
+        config.trace = Some(trace);
+    }
+    if let Some(log) = std::env::var_os("KONIGSBERG_LOG") {
+        config.log = Some(log);
+    }
+    if let Some(inventory) = std::env::var_os("KONIGSBERG_INVENTORY") {
+        config.inventory = Some(inventory);
+    }
+    if let Some(callbacks) = std::env::var_os("KONIGSBERG_CALLBACKS") {
+        config.callbacks = Some(callbacks);
+    }
+    if let Some(calls) = std::env::var_os("KONIGSBERG_CALLS") {
+        config.calls = Some(calls);
+    }
+    if let Some(lint) = std::env::var_os("KONIGSBERG_LINT") {
+        config.lint = Some(lint);
+    }
+    if let Ok(gap) = std::env::var("KONIGSBERG_LINT_CALLBACK_GAP") {
+        match gap.parse() {
+            Ok(gap) => config.lint_callback_gap = Some(Duration::from_millis(gap)),
+            Err(_) => config.warnings.push(format!(
+                "KONIGSBERG_LINT_CALLBACK_GAP: expected milliseconds, got `{gap}`"
+            )),
+        }
+    }
+    if let Some(threads) = std::env::var_os("KONIGSBERG_THREADS") {
+        config.threads = Some(threads);
+    }
+    if let Some(plugins) = std::env::var_os("KONIGSBERG_PLUGINS") {
+        config.plugins = Some(plugins.into());
+    }
+    if let Ok(on_missing) = std::env::var("KONIGSBERG_ON_MISSING") {
+        match on_missing.parse() {
+            Ok(on_missing) => config.on_missing = on_missing,
+            Err(()) => config.warnings.push(format!(
+                "KONIGSBERG_ON_MISSING: unknown policy `{on_missing}`"
+            )),
+        }
+    }
+    if let Ok(patch_mode) = std::env::var("KONIGSBERG_PATCH_MODE") {
+        match patch_mode.parse() {
+            Ok(patch_mode) => config.patch_mode = patch_mode,
+            Err(()) => config.warnings.push(format!(
+                "KONIGSBERG_PATCH_MODE: unknown mode `{patch_mode}`"
+            )),
+        }
+    }
+    config
+}
+
+impl Config {
+    fn parse(&mut self, text: &str, dir: &Path) {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                self.warnings
+                    .push(format!("{FILE_NAME}:{}: expected `key = value`", i + 1));
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "lib" => self.lib.push(dir.join(value)),
+                "trace" if value == "-" => self.trace = Some(value.into()),
+                "trace" => self.trace = Some(dir.join(value).into()),
+                "log" => self.log = Some(dir.join(value).into()),
+                "inventory" => self.inventory = Some(dir.join(value).into()),
+                "callbacks" => self.callbacks = Some(dir.join(value).into()),
+                "calls" => self.calls = Some(dir.join(value).into()),
+                "lint" => self.lint = Some(dir.join(value).into()),
+                "lint_callback_gap" => match value.parse() {
+                    Ok(gap) => self.lint_callback_gap = Some(Duration::from_millis(gap)),
+                    Err(_) => self.warnings.push(format!(
+                        "{FILE_NAME}:{}: expected milliseconds, got `{value}`",
+                        i + 1
+                    )),
+                },
+                "threads" => self.threads = Some(dir.join(value).into()),
+                "plugins" => self.plugins = Some(dir.join(value)),
+                "on_missing" => match value.parse() {
+                    Ok(on_missing) => self.on_missing = on_missing,
+                    Err(()) => self
+                        .warnings
+                        .push(format!("{FILE_NAME}:{}: unknown policy `{value}`", i + 1)),
+                },
+                "patch_mode" => match value.parse() {
+                    Ok(patch_mode) => self.patch_mode = patch_mode,
+                    Err(()) => self
+                        .warnings
+                        .push(format!("{FILE_NAME}:{}: unknown mode `{value}`", i + 1)),
+                },
+                key if key.starts_with("on_missing.") => match value.parse() {
+                    Ok(on_missing) => {
+                        self.on_missing_for
+                            .insert(key["on_missing.".len()..].to_owned(), on_missing);
+                    }
+                    Err(()) => self
+                        .warnings
+                        .push(format!("{FILE_NAME}:{}: unknown policy `{value}`", i + 1)),
+                },
+                key => self
+                    .warnings
+                    .push(format!("{FILE_NAME}:{}: unknown key `{key}`", i + 1)),
+            }
+        }
+    }
+}
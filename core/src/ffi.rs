@@ -0,0 +1,439 @@
+//! Loading the original library, and forwarding calls to it.
+//!
+//! [`reexport!`](crate::reexport) declares an exported function that
+//! forwards to the same symbol in the original library, tracing the call if
+//! tracing is on. [`import!`](crate::import) declares one that isn't
+//! exported, for calling the original from hooks. Either resolves its symbol
+//! on the first call, with [`resolve_export`], so it calls the export hook
+//! registered for it if there is one. Every call is checked by
+//! [`lint`](crate::lint) first, and recorded by [`threads`](crate::threads)
+//! for as long as it runs.
+
+use crate::{config::OnMissing, log::log};
+use once_cell::sync::OnceCell;
+use std::{
+    collections::HashMap,
+    ffi::{c_void, OsStr},
+    sync::RwLock,
+};
+use steamworks_sys::*;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! forward {
+    ($sym:expr, $name:ident($( $(#[$fmt:ident $(($len:ident))?])? $arg:ident ),*) $(-> $(#[$rfmt:ident])? $ret:ty)?) => {{
+        $crate::lint::call(stringify!($name));
+        let _call = $crate::threads::enter(stringify!($name));
+        // before resolving, so a plugin calling this from its init doesn't
+        // re-enter the symbol's cell
+        $crate::plugin::load();
+        let Some(sym) = *$sym else {
+            return $crate::missing_ret!($($ret)?);
+        };
+        if !$crate::trace::enabled() {
+            let ret = sym($( $arg ),*);
+            $crate::track_call!($($(#[$rfmt])?)? $name, ret);
+            return ret;
+        }
+        let args: &mut [(&str, String)] =
+            &mut [$( (stringify!($arg), $crate::trace_arg!($(#[$fmt $(($len))?])? $arg)) ),*];
+        let start = std::time::Instant::now();
+        let ret = sym($( $arg ),*);
+        let duration = start.elapsed();
+        $crate::track_call!($($(#[$rfmt])?)? $name, ret);
+        let written: &[Option<String>] = &[$( $crate::trace_written!($(#[$fmt $(($len))?])? $arg) ),*];
+        for ((_, value), written) in args.iter_mut().zip(written) {
+            if let Some(written) = written {
+                value.clone_from(written);
+            }
+        }
+        $crate::trace::record(
+            stringify!($name),
+            args,
+            $crate::trace_arg!($($(#[$rfmt])?)? ret),
+            duration,
+        );
+        ret
+    }};
+}
+
+/// Pass an API call handle a forwarder returns, marked `#[call]`, to
+/// [`calls`](crate::calls).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! track_call {
+    (#[call] $name:ident, $ret:ident) => {
+        $crate::calls::issued(stringify!($name), $ret)
+    };
+    ($(#[$rfmt:ident])? $name:ident, $ret:ident) => {};
+}
+
+/// How a forwarder traces an argument or return value before the call,
+/// going by its marker, see [`reexport!`](crate::reexport). Out-parameters
+/// are traced as the pointer until [`trace_written!`](crate::trace_written)
+/// reads them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! trace_arg {
+    (#[str] $arg:ident) => {
+        $crate::trace::c_str($arg as *const std::ffi::c_char, usize::MAX)
+    };
+    (#[buf($len:ident)] $arg:ident) => {
+        $crate::trace::bytes($arg as *const u8, $crate::trace::len($len))
+    };
+    (#[deref] $arg:ident) => {
+        if $arg.is_null() {
+            "null".to_owned()
+        } else {
+            $crate::trace_value!(*$arg)
+        }
+    };
+    (#[steam_id] $arg:ident) => {
+        $crate::trace::steam_id($arg)
+    };
+    (#[ipv4] $arg:ident) => {
+        $crate::trace::ipv4($arg)
+    };
+    ($(#[$fmt:ident $(($len:ident))?])? $arg:ident) => {
+        $crate::trace_value!($arg)
+    };
+}
+
+/// What a forwarder's call wrote to an out-parameter, going by its marker.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! trace_written {
+    (#[out] $arg:ident) => {
+        Some($crate::trace_arg!(
+            #[deref]
+            $arg
+        ))
+    };
+    (#[out_str($len:ident)] $arg:ident) => {
+        Some($crate::trace::c_str(
+            $arg as *const std::ffi::c_char,
+            $crate::trace::len($len),
+        ))
+    };
+    (#[out_buf($len:ident)] $arg:ident) => {
+        Some($crate::trace_arg!(
+            #[buf($len)]
+            $arg
+        ))
+    };
+    (#[out_ipv4] $arg:ident) => {
+        Some(if $arg.is_null() {
+            "null".to_owned()
+        } else {
+            $crate::trace::ipv4(*$arg)
+        })
+    };
+    ($(#[$fmt:ident $(($len:ident))?])? $arg:ident) => {
+        None
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! missing_ret {
+    () => {
+        ()
+    };
+    ($ret:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::ffi::{ViaNoZero, ViaZeroed};
+        (&&$crate::ffi::Missing::<$ret>(std::marker::PhantomData)).value()
+    }};
+}
+
+/// Declare an exported `extern "C"` function that forwards to the function
+/// of the same name in the original library. The optional leading literal
+/// is the symbol to export it as, and look for first, for C++ functions.
+///
+/// Arguments and the return type can be marked with how to trace them
+/// rather than as their raw value: `#[str]` for C strings, `#[buf(len)]` for
+/// byte buffers `len` long, `#[deref]` for pointers to values, `#[steam_id]`
+/// and `#[ipv4]` for integers holding those. Out-parameters the call writes
+/// to are marked `#[out]`, `#[out_str(len)]`, `#[out_buf(len)]` or
+/// `#[out_ipv4]`, and are read after the call. A return type marked
+/// `#[call]` is an API call handle, followed by [`calls`](crate::calls). The
+/// generator picks these for `src/*reexports.rs`.
+///
+/// ```ignore
+/// reexport!(fn SteamAPI_IsSteamRunning() -> bool);
+/// reexport!("_ZNK8CSteamID7IsValidEv", fn CSteamID_IsValid(this: *const CSteamID) -> bool);
+/// reexport!(fn SteamAPI_ISteamFriends_GetFriendPersonaName(self_: *mut ISteamFriends, #[steam_id] steamIDFriend: uint64_steamid) -> #[str] *const c_char);
+/// ```
+#[macro_export]
+macro_rules! reexport {
+    (fn $name:ident($( $(#[$fmt:ident $(($len:ident))?])? $arg:ident : $type:ty ),*) $(-> $(#[$rfmt:ident])? $ret:ty)?) => {
+        #[no_mangle]
+        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
+            static CELL: $crate::OnceCell<Option<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = $crate::OnceCell::new();
+            if const { $crate::interface::is_accessor(stringify!($name)) } && $crate::inventory::enabled() {
+                $crate::inventory::record_accessor(stringify!($name));
+            }
+            $crate::forward!(
+                CELL.get_or_init(|| $crate::ffi::resolve_export(stringify!($name), &[stringify!($name)])),
+                $name($( $(#[$fmt $(($len))?])? $arg ),*) $(-> $(#[$rfmt])? $ret)?
+            )
+        }
+    };
+    ($link:literal, fn $name:ident($( $(#[$fmt:ident $(($len:ident))?])? $arg:ident : $type:ty ),*) $(-> $(#[$rfmt:ident])? $ret:ty)?) => {
+        #[export_name = $link]
+        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
+            static CELL: $crate::OnceCell<Option<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = $crate::OnceCell::new();
+            $crate::forward!(
+                CELL.get_or_init(|| $crate::ffi::resolve_export(
+                    stringify!($name),
+                    &[$link, &$link[1..], stringify!($name), concat!("\x01", $link)],
+                )),
+                $name($( $(#[$fmt $(($len))?])? $arg ),*) $(-> $(#[$rfmt])? $ret)?
+            )
+        }
+    };
+}
+
+/// Declare a crate-private function that forwards to the function of the
+/// same name in the original library, like [`reexport!`] but not exported.
+#[macro_export]
+macro_rules! import {
+    (fn $name:ident($( $(#[$fmt:ident $(($len:ident))?])? $arg:ident : $type:ty ),*) $(-> $(#[$rfmt:ident])? $ret:ty)?) => {
+        pub(crate) unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
+            static CELL: $crate::OnceCell<Option<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = $crate::OnceCell::new();
+            $crate::forward!(
+                CELL.get_or_init(|| $crate::ffi::resolve_export(stringify!($name), &[stringify!($name)])),
+                $name($( $(#[$fmt $(($len))?])? $arg ),*) $(-> $(#[$rfmt])? $ret)?
+            )
+        }
+    };
+}
+
+/// Return value of a forwarder whose symbol is missing. Zeroed, except for
+/// enums that have no zero variant, which use [`NoZero::DEFAULT`].
+#[doc(hidden)]
+pub struct Missing<T>(pub std::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait NoZero: Sized {
+    const DEFAULT: Self;
+}
+
+#[doc(hidden)]
+pub trait ViaNoZero<T> {
+    fn value(&self) -> T;
+}
+impl<T: NoZero> ViaNoZero<T> for &Missing<T> {
+    fn value(&self) -> T {
+        T::DEFAULT
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaZeroed<T> {
+    fn value(&self) -> T;
+}
+impl<T> ViaZeroed<T> for Missing<T> {
+    fn value(&self) -> T {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+macro_rules! no_zero {
+    ($($t:ident => $v:ident),* $(,)?) => {$(
+        impl NoZero for $t {
+            const DEFAULT: Self = $t::$v;
+        }
+    )*};
+}
+no_zero! {
+    ESteamNetworkingGetConfigValueResult => k_ESteamNetworkingGetConfigValue_BadValue,
+    ESteamNetworkingConfigScope => k_ESteamNetworkingConfig_Global,
+    ESteamNetworkingConfigDataType => k_ESteamNetworkingConfig_Int32,
+    EPlayerResult_t => k_EPlayerResultFailedToConnect,
+    EGameSearchErrorCode_t => k_EGameSearchErrorCode_Failed_Unknown_Error,
+    EControllerHapticLocation => k_EControllerHapticLocation_Both,
+}
+
+/// Names the original library is looked for under when none of the
+/// configured paths work.
+#[cfg(target_os = "macos")]
+const DEFAULT_LIBS: &[&str] = &[
+    "./libsteam_api.orig.dylib",
+    "./libsteam_api_orig.dylib",
+    "libsteam_api.orig.dylib",
+    "libsteam_api_orig.dylib",
+    "steam_api.orig.dylib",
+    "steam_api_orig.dylib",
+    "steam_api.orig",
+    "steam_api_orig",
+];
+#[cfg(all(target_os = "windows", not(target_pointer_width = "64")))]
+const DEFAULT_LIBS: &[&str] = &[
+    "./steam_api.orig.dll",
+    "./steam_api_orig.dll",
+    "steam_api.orig.dll",
+    "steam_api_orig.dll",
+    "steam_api.orig",
+    "steam_api_orig",
+];
+#[cfg(all(target_os = "windows", target_pointer_width = "64"))]
+const DEFAULT_LIBS: &[&str] = &[
+    "./steam_api64.orig.dll",
+    "./steam_api64_orig.dll",
+    "steam_api64.orig.dll",
+    "steam_api64_orig.dll",
+    "steam_api64.orig",
+    "steam_api64_orig",
+    "./steam_api.orig.dll",
+    "./steam_api_orig.dll",
+    "steam_api.orig.dll",
+    "steam_api_orig.dll",
+    "steam_api.orig",
+    "steam_api_orig",
+];
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+const DEFAULT_LIBS: &[&str] = &[
+    "./libsteam_api.orig.so",
+    "./libsteam_api_orig.so",
+    "libsteam_api.orig.so",
+    "libsteam_api_orig.so",
+    "steam_api.orig.so",
+    "steam_api_orig.so",
+    "steam_api.orig",
+    "steam_api_orig",
+];
+
+/// Every path the original library is looked for at, in order.
+pub fn lib_candidates() -> impl Iterator<Item = &'static OsStr> {
+    crate::config::get()
+        .lib
+        .iter()
+        .map(|x| x.as_os_str())
+        .chain(DEFAULT_LIBS.iter().map(OsStr::new))
+}
+
+struct Loaded {
+    lib: libloading::Library,
+    path: &'static OsStr,
+}
+
+/// The original library, or a list of the paths tried if it couldn't be
+/// loaded.
+fn lib() -> Result<&'static Loaded, &'static str> {
+    static CELL: OnceCell<Result<Loaded, String>> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let mut errors = String::new();
+        let mut tried = Vec::new();
+        for path in lib_candidates() {
+            match unsafe { libloading::Library::new(path) } {
+                Ok(lib) => return Ok(Loaded { lib, path }),
+                Err(err) => errors.push_str(&format!("\n  {}: {err}", path.to_string_lossy())),
+            }
+            tried.push(path.to_string_lossy());
+        }
+        log!("failed to load the original steam api lib:{errors}");
+        Err(tried.join(", "))
+    })
+    .as_ref()
+    .map_err(String::as_str)
+}
+
+/// Look up one of `candidates` in the original library, falling back
+/// according to the `on_missing` policy for `name` if none are there.
+/// `F` must be a function pointer type.
+pub fn resolve<F: Copy>(name: &str, candidates: &[&str]) -> Option<F> {
+    assert_eq!(
+        std::mem::size_of::<F>(),
+        std::mem::size_of::<*const c_void>()
+    );
+    let problem = match lib() {
+        Ok(loaded) => {
+            for candidate in candidates {
+                if let Ok(sym) = unsafe { loaded.lib.get::<F>(candidate.as_bytes()) } {
+                    return Some(*sym);
+                }
+            }
+            format!(
+                "not found in {} (tried {candidates:?})",
+                loaded.path.to_string_lossy()
+            )
+        }
+        Err(tried) => format!("original library isn't loaded (tried {tried})"),
+    };
+    match crate::config::get().on_missing(name) {
+        OnMissing::Abort => {
+            log!("{name}: {problem}, aborting");
+            std::process::abort();
+        }
+        OnMissing::Default => {
+            log!("{name}: {problem}, returning a default value");
+            None
+        }
+        OnMissing::Fallback => match fallbacks().read().unwrap().get(name) {
+            Some(&func) => {
+                log!("{name}: {problem}, calling the registered fallback");
+                Some(unsafe { std::mem::transmute_copy::<usize, F>(&func) })
+            }
+            None => {
+                log!("{name}: {problem}, and no fallback is registered, returning a default value");
+                None
+            }
+        },
+    }
+}
+
+/// `symbol` in the original library, if it's loaded and has it. Unlike
+/// [`resolve`], a missing symbol isn't logged or handled by `on_missing`.
+pub(crate) fn original_symbol(symbol: &str) -> Option<*const c_void> {
+    let loaded = lib().ok()?;
+    let sym = unsafe { loaded.lib.get::<*const c_void>(symbol.as_bytes()) }.ok()?;
+    Some(*sym)
+}
+
+fn export_hooks() -> &'static RwLock<HashMap<String, usize>> {
+    static CELL: OnceCell<RwLock<HashMap<String, usize>>> = OnceCell::new();
+    CELL.get_or_init(Default::default)
+}
+
+/// Make forwarders for `symbol` call `func` instead of the original
+/// library's function, which `func` can still get with [`resolve`].
+///
+/// `func` must have the same signature as `symbol`, and must be registered
+/// before `symbol` is first called. A later hook for the same symbol
+/// replaces an earlier one.
+pub fn hook_export(symbol: &str, func: *const c_void) {
+    export_hooks()
+        .write()
+        .unwrap()
+        .insert(symbol.to_owned(), func as usize);
+}
+
+/// What a forwarder for `name` calls: the hook registered for it with
+/// [`hook_export`] if there is one, otherwise what [`resolve`] finds.
+/// Forwarders load plugins before calling this, so they get to register
+/// hooks.
+pub fn resolve_export<F: Copy>(name: &str, candidates: &[&str]) -> Option<F> {
+    if let Some(&func) = export_hooks().read().unwrap().get(name) {
+        assert_eq!(std::mem::size_of::<F>(), std::mem::size_of::<usize>());
+        return Some(unsafe { std::mem::transmute_copy::<usize, F>(&func) });
+    }
+    resolve(name, candidates)
+}
+
+fn fallbacks() -> &'static RwLock<HashMap<String, usize>> {
+    static CELL: OnceCell<RwLock<HashMap<String, usize>>> = OnceCell::new();
+    CELL.get_or_init(Default::default)
+}
+
+/// Register `func` to be called in place of `symbol` if it's missing from
+/// the original library and its `on_missing` policy is `fallback`.
+///
+/// `func` must have the same signature as `symbol`, and must be registered
+/// before `symbol` is first called.
+pub fn register_fallback(symbol: &str, func: *const c_void) {
+    fallbacks()
+        .write()
+        .unwrap()
+        .insert(symbol.to_owned(), func as usize);
+}
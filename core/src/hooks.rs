@@ -0,0 +1,492 @@
+//! Vtable slot patching driven by the [`VtableHook`]s passed to
+//! [`register`], either in the original library's vtables or in copies of
+//! them.
+//!
+//! Hooks are declared with [`vtable_method!`](crate::vtable_method), which
+//! also calls through to the [`original`] from inside one. Interfaces are
+//! hooked when they're passed to [`patch`], which a proxy does with every
+//! interface it hands out. Hooks registered after a vtable was patched don't
+//! apply to it until [`restore`] is called.
+
+use crate::{
+    config::PatchMode,
+    interface::{Interface, InterfaceKind},
+    log::log,
+};
+use once_cell::sync::OnceCell;
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::c_void,
+    ops::{Range, RangeInclusive},
+    sync::{Mutex, RwLock},
+};
+
+/// Replace `slot` of `interface`'s vtable with `func` for every version in
+/// `versions`. Whatever was in the slot before stays reachable through
+/// [`original`] under `method`.
+#[derive(Clone)]
+pub struct VtableHook {
+    pub interface: InterfaceKind,
+    pub versions: RangeInclusive<u8>,
+    pub slot: usize,
+    /// Name of the method, like `ISteamClient::GetISteamApps`. Hooks for the
+    /// same method on different versions share it.
+    pub method: &'static str,
+    pub func: HookFn,
+}
+
+/// A hook function, type-erased so it can be stored in a table.
+#[derive(Copy, Clone)]
+pub struct HookFn(pub *const c_void);
+
+unsafe impl Send for HookFn {}
+unsafe impl Sync for HookFn {}
+
+/// A vtable slot konigsberg replaced.
+#[derive(Copy, Clone)]
+struct Replaced {
+    slot: usize,
+    original: usize,
+    hook: usize,
+}
+
+/// Replaced slots by vtable address, then by method.
+#[allow(clippy::type_complexity)]
+static ORIGINALS: OnceCell<RwLock<HashMap<usize, HashMap<&'static str, Replaced>>>> =
+    OnceCell::new();
+
+/// The hooked vtable each object was found with or pointed at, for
+/// [`original`] to fall back to once something else points the object at a
+/// vtable of its own.
+static OBJECTS: OnceCell<RwLock<HashMap<usize, usize>>> = OnceCell::new();
+
+static PATCHED: OnceCell<Mutex<Patched>> = OnceCell::new();
+
+fn registry() -> &'static RwLock<Vec<VtableHook>> {
+    static CELL: OnceCell<RwLock<Vec<VtableHook>>> = OnceCell::new();
+    CELL.get_or_init(Default::default)
+}
+
+/// Add `hooks` to the ones [`patch`] applies. If several hooks replace the
+/// same slot, the one registered first is used, except that hooks from
+/// plugins come before everything else.
+pub fn register(hooks: impl IntoIterator<Item = VtableHook>) {
+    registry().write().unwrap().extend(hooks);
+}
+
+/// Register `hook` ahead of every hook registered so far.
+pub(crate) fn register_first(hook: VtableHook) {
+    registry().write().unwrap().insert(0, hook);
+}
+
+/// Apply every registered hook for `ver` to `object`, an interface of that
+/// version, as configured by `patch_mode`. Objects konigsberg can't verify
+/// are logged and left alone.
+///
+/// # Safety
+///
+/// `object` must be a live interface of version `ver`, and the registered
+/// hooks must match its methods' signatures.
+pub unsafe fn patch(ver: Interface, object: *mut c_void) {
+    crate::plugin::load();
+    apply(&registry().read().unwrap(), ver, object);
+}
+
+/// Vtables konigsberg has dealt with.
+#[derive(Default)]
+struct Patched {
+    /// Vtables konigsberg's hooks are in: originals patched in place, or
+    /// copies.
+    hooked: HashSet<usize>,
+    /// Hooked copy of each original vtable, with [`PatchMode::Copy`].
+    copies: HashMap<usize, usize>,
+    /// Original vtable of each object pointed at a copy.
+    objects: HashMap<usize, usize>,
+    /// Objects [`verify`] turned down, so it's only logged once.
+    refused: HashSet<usize>,
+    /// Hooked slots found replaced since, with what they held, so each
+    /// replacement is only logged once.
+    tampered: HashSet<(usize, &'static str, usize)>,
+}
+
+/// Patch every hook in `hooks` matching `ver` into `object`'s vtable, or into
+/// a copy of it that `object` is then pointed at, depending on the
+/// `patch_mode`. A vtable is only ever patched or copied once, and only if
+/// [`verify`] agrees. Patching in place either replaces every slot or none.
+/// An already hooked vtable is checked for slots replaced since instead.
+unsafe fn apply(hooks: &[VtableHook], ver: Interface, object: *mut c_void) {
+    let mut slots = HashSet::new();
+    let hooks = hooks
+        .iter()
+        .filter(|hook| hook.interface == ver.kind && hook.versions.contains(&ver.version))
+        .filter(|hook| slots.insert(hook.slot))
+        .collect::<Vec<_>>();
+    if hooks.is_empty() {
+        return;
+    }
+
+    let patched = PATCHED.get_or_init(Default::default);
+    let mut lock = patched.lock().unwrap();
+    if lock.refused.contains(&(object as usize)) {
+        return;
+    }
+    let vtable = match verify(object, &hooks, &lock.hooked) {
+        Ok(Some(vtable)) => vtable,
+        Ok(None) => {
+            let vtable = *(object as *const usize);
+            check_in(&mut lock, Some(vtable));
+            hooked_on(object, vtable);
+            return;
+        }
+        Err(reason) => {
+            log!("not hooking {ver} at {object:p}: {reason}");
+            lock.refused.insert(object as usize);
+            return;
+        }
+    };
+
+    match crate::config::get().patch_mode {
+        PatchMode::InPlace => {
+            record_originals(vtable, &hooks);
+            let mut written = Vec::new();
+            for hook in &hooks {
+                let slot = vtable.add(hook.slot);
+                match write_slot(slot, hook.func.0 as usize) {
+                    Ok(old) => written.push((slot, old)),
+                    Err(err) => {
+                        log!(
+                            "not hooking {ver} at {object:p}: writing {} failed, \
+                             restoring {} slot(s): {err}",
+                            hook.method,
+                            written.len()
+                        );
+                        for (slot, old) in written.into_iter().rev() {
+                            if let Err(err) = write_slot(slot, old) {
+                                log!("failed to restore slot {slot:p}: {err}");
+                            }
+                        }
+                        forget_originals(vtable);
+                        lock.refused.insert(object as usize);
+                        return;
+                    }
+                }
+            }
+            lock.hooked.insert(vtable as usize);
+            hooked_on(object, vtable as usize);
+        }
+        PatchMode::Copy => {
+            let copy = match lock.copies.get(&(vtable as usize)) {
+                Some(&copy) => copy as *mut usize,
+                None => {
+                    let len = hooks.iter().map(|hook| hook.slot + 1).max().unwrap_or(0);
+                    let copy = clone_vtable(vtable, len);
+                    record_originals(copy, &hooks);
+                    for hook in hooks {
+                        *copy.add(hook.slot) = hook.func.0 as usize;
+                    }
+                    lock.copies.insert(vtable as usize, copy as usize);
+                    lock.hooked.insert(copy as usize);
+                    copy
+                }
+            };
+            *(object as *mut *mut usize) = copy;
+            lock.objects.insert(object as usize, vtable as usize);
+            hooked_on(object, copy as usize);
+        }
+    }
+}
+
+/// Check that `object` and the slots `hooks` replace in its vtable can be
+/// read, and that each of those slots points to code, then return the
+/// vtable. `None` if the vtable is in `hooked` already.
+///
+/// Slots pointing outside the module the vtable is in, and vtables outside
+/// any module, are most likely another hooker's doing. They're logged and
+/// hooked anyway, and calls are chained through to them.
+unsafe fn verify(
+    object: *mut c_void,
+    hooks: &[&VtableHook],
+    hooked: &HashSet<usize>,
+) -> Result<Option<*mut usize>, String> {
+    const WORD: usize = std::mem::size_of::<usize>();
+    if !readable(object as usize, WORD) {
+        return Err("the object isn't readable".into());
+    }
+    let vtable = *(object as *const *mut usize);
+    if hooked.contains(&(vtable as usize)) {
+        return Ok(None);
+    }
+    let len = hooks.iter().map(|hook| hook.slot + 1).max().unwrap_or(0);
+    if !readable(vtable as usize, len * WORD) {
+        return Err(format!("its vtable at {vtable:p} isn't readable"));
+    }
+    let module = crate::os::module_base(vtable as *const c_void);
+    if module.is_none() {
+        log!("the vtable at {vtable:p} isn't in a loaded module, it's probably another hooker's copy");
+    }
+    for hook in hooks {
+        let func = *vtable.add(hook.slot);
+        if !region::query(func as *const u8).is_ok_and(|r| r.is_executable()) {
+            return Err(format!(
+                "{} (slot {}) points to {func:#x}, which isn't code",
+                hook.method, hook.slot
+            ));
+        }
+        if module.is_some() && crate::os::module_base(func as *const c_void) != module {
+            log!(
+                "{} (slot {}) in the vtable at {vtable:p} points to {func:#x}, outside the \
+                 module the vtable is in, calls will be chained to it",
+                hook.method,
+                hook.slot
+            );
+        }
+    }
+    Ok(Some(vtable))
+}
+
+/// Count the slots in hooked vtables, or just in `vtable`, that no longer
+/// point to konigsberg's hook, and log each newly found one.
+fn check_in(patched: &mut Patched, vtable: Option<usize>) -> usize {
+    let Some(originals) = ORIGINALS.get() else {
+        return 0;
+    };
+    let originals = originals.read().unwrap();
+    let mut count = 0;
+    for (&table, replaced) in originals.iter() {
+        if vtable.is_some_and(|vtable| vtable != table) || !patched.hooked.contains(&table) {
+            continue;
+        }
+        for (&method, replaced) in replaced {
+            let slot = (table as *const usize).wrapping_add(replaced.slot);
+            // SAFETY: hooked vtables were readable when they were patched,
+            // and are never freed
+            let value = unsafe { *slot };
+            if value == replaced.hook {
+                continue;
+            }
+            count += 1;
+            if patched.tampered.insert((table, method, value)) {
+                log!(
+                    "{method} in the vtable at {table:#x} was replaced with {value:#x} after \
+                     konigsberg hooked it"
+                );
+            }
+        }
+    }
+    count
+}
+
+/// Check every hooked vtable slot and return how many were replaced after
+/// konigsberg hooked them. Each replacement is logged the first time it's
+/// found.
+pub fn check() -> usize {
+    let Some(patched) = PATCHED.get() else {
+        return 0;
+    };
+    check_in(&mut patched.lock().unwrap(), None)
+}
+
+/// Whether `len` bytes at `addr` are mapped and readable.
+pub(crate) fn readable(addr: usize, len: usize) -> bool {
+    let Ok(regions) = region::query_range(addr as *const u8, len.max(1)) else {
+        return false;
+    };
+    let mut next = addr;
+    for region in regions {
+        match region {
+            Ok(region) if region.is_readable() && region.as_range().start <= next => {
+                next = region.as_range().end;
+            }
+            _ => return false,
+        }
+    }
+    next >= addr + len
+}
+
+/// Replace what's in `slot` with `value` and return the old value. The page
+/// is only made writable for the write, and never writable and executable
+/// at once.
+unsafe fn write_slot(slot: *mut usize, value: usize) -> region::Result<usize> {
+    let _handle = region::protect_with_handle(
+        slot,
+        std::mem::size_of::<usize>(),
+        region::Protection::READ_WRITE,
+    )?;
+    Ok(std::mem::replace(&mut *slot, value))
+}
+
+/// Remember what `hooks` are about to replace in `vtable`. Originals have to
+/// be in place before the first hooked slot can be called.
+unsafe fn record_originals(vtable: *mut usize, hooks: &[&VtableHook]) {
+    ORIGINALS
+        .get_or_init(Default::default)
+        .write()
+        .unwrap()
+        .insert(
+            vtable as usize,
+            hooks
+                .iter()
+                .map(|hook| {
+                    let replaced = Replaced {
+                        slot: hook.slot,
+                        original: *vtable.add(hook.slot),
+                        hook: hook.func.0 as usize,
+                    };
+                    (hook.method, replaced)
+                })
+                .collect(),
+        );
+}
+
+/// Remember that `object` was hooked through `vtable`.
+fn hooked_on(object: *mut c_void, vtable: usize) {
+    OBJECTS
+        .get_or_init(Default::default)
+        .write()
+        .unwrap()
+        .insert(object as usize, vtable);
+}
+
+fn forget_originals(vtable: *mut usize) {
+    if let Some(originals) = ORIGINALS.get() {
+        originals.write().unwrap().remove(&(vtable as usize));
+    }
+}
+
+/// Put back every slot and vtable pointer konigsberg replaced, and forget
+/// about them, so hooks are applied from scratch the next time an interface
+/// is handed out. Slots someone else has replaced since are left alone.
+///
+/// # Safety
+///
+/// Objects hooked in copy mode must still be alive, or at least their memory
+/// mapped.
+pub unsafe fn restore() {
+    const WORD: usize = std::mem::size_of::<usize>();
+    let Some(patched) = PATCHED.get() else {
+        return;
+    };
+    let mut lock = patched.lock().unwrap();
+    let patched = std::mem::take(&mut *lock);
+    let originals = ORIGINALS
+        .get()
+        .map(|originals| std::mem::take(&mut *originals.write().unwrap()))
+        .unwrap_or_default();
+    if let Some(objects) = OBJECTS.get() {
+        objects.write().unwrap().clear();
+    }
+
+    for (&object, &vtable) in &patched.objects {
+        let p_vtable = object as *mut usize;
+        if readable(object, WORD) && Some(&*p_vtable) == patched.copies.get(&vtable) {
+            *p_vtable = vtable;
+        }
+    }
+    // copies are leaked rather than freed, in case a call through one is
+    // still in flight
+    let copies = patched.copies.values().collect::<HashSet<_>>();
+    for vtable in patched
+        .hooked
+        .iter()
+        .filter(|vtable| !copies.contains(vtable))
+    {
+        let Some(replaced) = originals.get(vtable) else {
+            continue;
+        };
+        for (method, replaced) in replaced {
+            let slot = (*vtable as *mut usize).add(replaced.slot);
+            if *slot != replaced.hook {
+                log!("not restoring {method} in the vtable at {vtable:#x}, it was replaced since");
+                continue;
+            }
+            if let Err(err) = write_slot(slot, replaced.original) {
+                log!("failed to restore {method} in the vtable at {vtable:#x}: {err}");
+            }
+        }
+    }
+}
+
+/// Longest vtable [`clone_vtable`] looks at.
+const MAX_VTABLE_LEN: usize = 1024;
+
+/// Words before a vtable that belong to it: the offset to top and RTTI
+/// pointer in the Itanium ABI, or the RTTI locator in MSVC's.
+const VTABLE_PREFIX: usize = 2;
+
+/// Copy `vtable` into memory konigsberg owns and return the copy's address
+/// point. The length isn't known, so it's at least `min_len` and goes on
+/// for as long as the entries point to code.
+pub(crate) unsafe fn clone_vtable(vtable: *const usize, min_len: usize) -> *mut usize {
+    let readable =
+        |addr: usize| region::query(addr as *const u8).map(|r| (r.is_readable(), r.as_range()));
+    // where the vtable itself lives, and what code its entries were found in
+    let data = match readable(vtable as usize) {
+        Ok((true, range)) => range,
+        _ => vtable as usize..vtable as usize + min_len * std::mem::size_of::<usize>(),
+    };
+    let mut code = Vec::<Range<usize>>::new();
+    let mut is_code = |addr: usize| {
+        if code.iter().any(|range| range.contains(&addr)) {
+            return true;
+        }
+        match region::query(addr as *const u8) {
+            Ok(region) if region.is_executable() => {
+                code.push(region.as_range());
+                true
+            }
+            _ => false,
+        }
+    };
+    let mut len = 0;
+    while len < MAX_VTABLE_LEN
+        && data.contains(&(vtable.add(len) as usize))
+        && (len < min_len || is_code(*vtable.add(len)))
+    {
+        len += 1;
+    }
+    let len = len.max(min_len);
+
+    let mut copy = vec![0usize; VTABLE_PREFIX + len];
+    for i in 1..=VTABLE_PREFIX {
+        let addr = vtable.sub(i);
+        if data.contains(&(addr as usize)) || matches!(readable(addr as usize), Ok((true, _))) {
+            copy[VTABLE_PREFIX - i] = *addr;
+        }
+    }
+    std::ptr::copy_nonoverlapping(vtable, copy[VTABLE_PREFIX..].as_mut_ptr(), len);
+    // objects may point at it for as long as the process lives
+    Box::leak(copy.into_boxed_slice())
+        .as_mut_ptr()
+        .add(VTABLE_PREFIX)
+}
+
+/// What `method` pointed to in `this`'s vtable before it was hooked. Hooks
+/// looking it up are recorded as calls to it by [`threads`](crate::threads).
+/// If `this` has been pointed at a vtable konigsberg didn't hook since, like
+/// another hooker's copy, it's looked up in the one `this` was hooked
+/// through.
+///
+/// # Safety
+///
+/// `this` must point to an object, and `F` must be the method's function
+/// pointer type.
+pub unsafe fn original<F: Copy>(this: *mut c_void, method: &str) -> Option<F> {
+    assert_eq!(std::mem::size_of::<F>(), std::mem::size_of::<usize>());
+    let vtable = *(this as *const usize);
+    let lock = ORIGINALS.get()?.read().ok()?;
+    let replaced = match lock.get(&vtable) {
+        Some(replaced) => replaced,
+        None => {
+            let objects = OBJECTS.get()?.read().ok()?;
+            lock.get(objects.get(&(this as usize))?)?
+        }
+    };
+    let (&method, replaced) = replaced.get_key_value(method)?;
+    let func = replaced.original;
+    drop(lock);
+    crate::threads::method(method);
+    if func == 0 {
+        return None;
+    }
+    Some(std::mem::transmute_copy::<usize, F>(&func))
+}
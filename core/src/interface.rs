@@ -0,0 +1,178 @@
+//! Parsing of Steamworks interface version strings like `SteamUser021` or
+//! `STEAMAPPS_INTERFACE_VERSION008`.
+
+use std::{
+    ffi::{c_char, CStr},
+    fmt,
+};
+
+macro_rules! kinds {
+    ($($kind:ident => $class:ident, $prefix:literal,)*) => {
+        /// Every Steamworks interface konigsberg knows the version string of.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+        pub enum InterfaceKind {
+            $($kind,)*
+        }
+
+        impl InterfaceKind {
+            pub const ALL: &'static [InterfaceKind] = &[$(InterfaceKind::$kind,)*];
+
+            /// What comes before the digits in this interface's version
+            /// string.
+            pub const fn prefix(self) -> &'static str {
+                match self {
+                    $(InterfaceKind::$kind => $prefix,)*
+                }
+            }
+
+            /// Name of the interface's class, like `ISteamApps`.
+            pub const fn class(self) -> &'static str {
+                match self {
+                    $(InterfaceKind::$kind => stringify!($class),)*
+                }
+            }
+        }
+    };
+}
+
+kinds! {
+    Client => ISteamClient, "SteamClient",
+    User => ISteamUser, "SteamUser",
+    Friends => ISteamFriends, "SteamFriends",
+    Utils => ISteamUtils, "SteamUtils",
+    MatchMaking => ISteamMatchmaking, "SteamMatchMaking",
+    MatchMakingServers => ISteamMatchmakingServers, "SteamMatchMakingServers",
+    GameSearch => ISteamGameSearch, "SteamMatchGameSearch",
+    Parties => ISteamParties, "SteamParties",
+    RemoteStorage => ISteamRemoteStorage, "STEAMREMOTESTORAGE_INTERFACE_VERSION",
+    UserStats => ISteamUserStats, "STEAMUSERSTATS_INTERFACE_VERSION",
+    Apps => ISteamApps, "STEAMAPPS_INTERFACE_VERSION",
+    Networking => ISteamNetworking, "SteamNetworking",
+    Screenshots => ISteamScreenshots, "STEAMSCREENSHOTS_INTERFACE_VERSION",
+    Music => ISteamMusic, "STEAMMUSIC_INTERFACE_VERSION",
+    MusicRemote => ISteamMusicRemote, "STEAMMUSICREMOTE_INTERFACE_VERSION",
+    Http => ISteamHTTP, "STEAMHTTP_INTERFACE_VERSION",
+    Input => ISteamInput, "SteamInput",
+    Controller => ISteamController, "SteamController",
+    Ugc => ISteamUGC, "STEAMUGC_INTERFACE_VERSION",
+    AppList => ISteamAppList, "STEAMAPPLIST_INTERFACE_VERSION",
+    HtmlSurface => ISteamHTMLSurface, "STEAMHTMLSURFACE_INTERFACE_VERSION_",
+    Inventory => ISteamInventory, "STEAMINVENTORY_INTERFACE_V",
+    Video => ISteamVideo, "STEAMVIDEO_INTERFACE_V",
+    ParentalSettings => ISteamParentalSettings, "STEAMPARENTALSETTINGS_INTERFACE_VERSION",
+    RemotePlay => ISteamRemotePlay, "STEAMREMOTEPLAY_INTERFACE_VERSION",
+    NetworkingMessages => ISteamNetworkingMessages, "SteamNetworkingMessages",
+    NetworkingSockets => ISteamNetworkingSockets, "SteamNetworkingSockets",
+    NetworkingUtils => ISteamNetworkingUtils, "SteamNetworkingUtils",
+    GameServer => ISteamGameServer, "SteamGameServer",
+    GameServerStats => ISteamGameServerStats, "SteamGameServerStats",
+    Timeline => ISteamTimeline, "STEAMTIMELINE_INTERFACE_V",
+    // gone from current SDKs, but older games still ask for them
+    AppTicket => ISteamAppTicket, "STEAMAPPTICKET_INTERFACE_VERSION",
+    ContentServer => ISteamContentServer, "SteamContentServer",
+    GameCoordinator => ISteamGameCoordinator, "SteamGameCoordinator",
+    GameStats => ISteamGameStats, "SteamGameStats",
+    MasterServerUpdater => ISteamMasterServerUpdater, "SteamMasterServerUpdater",
+    Tv => ISteamTV, "STEAMTV_INTERFACE_V",
+    UnifiedMessages => ISteamUnifiedMessages, "STEAMUNIFIEDMESSAGES_INTERFACE_VERSION",
+}
+
+/// An interface version, like `SteamUser021`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Interface {
+    pub kind: InterfaceKind,
+    pub version: u8,
+}
+
+impl Interface {
+    pub const fn new(kind: InterfaceKind, version: u8) -> Self {
+        Self { kind, version }
+    }
+
+    /// Parse a version string. It has to be exactly a known prefix followed
+    /// by one or more decimal digits, and the version has to fit in a `u8`.
+    pub fn parse(name: &[u8]) -> Option<Self> {
+        let digits = name.iter().rev().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        let (prefix, digits) = name.split_at(name.len() - digits);
+        // leading zeros aside, anything longer than 3 digits overflows anyway
+        let digits = &digits[digits
+            .iter()
+            .position(|&c| c != b'0')
+            .unwrap_or(digits.len())..];
+        if digits.len() > 3 {
+            return None;
+        }
+        let version = digits
+            .iter()
+            .fold(0u16, |acc, c| acc * 10 + u16::from(c - b'0'));
+        let kind = InterfaceKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.prefix().as_bytes() == prefix)?;
+        Some(Self::new(kind, version.try_into().ok()?))
+    }
+
+    /// The interface a flat API accessor like `SteamAPI_SteamUser_v021` or
+    /// `SteamAPI_SteamGameServerUGC_v016` returns.
+    pub fn from_accessor(name: &str) -> Option<Self> {
+        let (name, version) = name.strip_prefix("SteamAPI_Steam")?.rsplit_once("_v")?;
+        if version.is_empty() || !version.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let name = name.strip_suffix("_SteamAPI").unwrap_or(name);
+        let class = |name: &str| {
+            InterfaceKind::ALL
+                .iter()
+                .copied()
+                .find(|kind| kind.class().strip_prefix("ISteam") == Some(name))
+        };
+        // game server accessors return the same interfaces as the client ones
+        let kind = class(name).or_else(|| class(name.strip_prefix("GameServer")?))?;
+        Some(Self::new(kind, version.parse().ok()?))
+    }
+
+    /// [`Interface::parse`] a C string, which may be null.
+    ///
+    /// # Safety
+    ///
+    /// `name` must be null or point to a nul-terminated string.
+    pub unsafe fn from_ptr(name: *const c_char) -> Option<Self> {
+        if name.is_null() {
+            return None;
+        }
+        Self::parse(CStr::from_ptr(name).to_bytes())
+    }
+}
+
+/// Whether `name` looks like a flat API accessor, so forwarders can tell at
+/// compile time.
+pub const fn is_accessor(name: &str) -> bool {
+    let name = name.as_bytes();
+    let prefix = b"SteamAPI_Steam";
+    if name.len() <= prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if name[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    let mut end = name.len();
+    while end > prefix.len() && name[end - 1].is_ascii_digit() {
+        end -= 1;
+    }
+    end < name.len() && end >= prefix.len() + 2 && name[end - 2] == b'_' && name[end - 1] == b'v'
+}
+
+/// The canonical version string, which [`Interface::parse`] turns back into
+/// the same interface.
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:03}", self.kind.prefix(), self.version)
+    }
+}
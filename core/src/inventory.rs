@@ -0,0 +1,136 @@
+//! Opt-in report of every versioned interface the game asks for, which tells
+//! what Steamworks SDK it was built against.
+//!
+//! Set `KONIGSBERG_INVENTORY` or the `inventory` config key to a file path to
+//! enable it. The report is rewritten by [`shutdown`] and [`write()`], which
+//! the konigsberg shim calls from `SteamAPI_Shutdown`,
+//! `SteamGameServer_Shutdown` and `konigsberg_write_inventory`. It has one
+//! line per interface, in the order they were first asked for, like
+//! `{"interface":"SteamUser021","kind":"User","version":21,"first_seen_ms":1690000000000,"calls":3,"via":["SteamInternal_FindOrCreateUserInterface"]}`.
+//! Version strings konigsberg doesn't recognise have a null `kind` and
+//! `version`.
+
+use crate::{interface::Interface, report::Report, trace::write_str};
+use std::{
+    collections::HashMap,
+    ffi::{c_char, CStr, OsStr},
+    fmt::Write as _,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+struct Entry {
+    interface: Option<Interface>,
+    first_seen: SystemTime,
+    calls: u64,
+    /// Where it was asked for: an export or a hooked method.
+    via: Vec<&'static str>,
+}
+
+/// Entries by version string, canonical if it parsed.
+type Entries = HashMap<String, Entry>;
+
+static REPORT: Report<Entries> = Report::new(
+    "interface inventory",
+    |config| config.inventory.as_deref(),
+    lines,
+);
+
+/// Whether the inventory is enabled in the config.
+pub fn enabled() -> bool {
+    REPORT.enabled()
+}
+
+fn add(name: String, interface: Option<Interface>, via: &'static str) {
+    let mut entries = REPORT.state();
+    let entry = entries.entry(name).or_insert_with(|| Entry {
+        interface,
+        first_seen: SystemTime::now(),
+        calls: 0,
+        via: Vec::new(),
+    });
+    entry.calls += 1;
+    if !entry.via.contains(&via) {
+        entry.via.push(via);
+    }
+}
+
+/// Record a request for the interface version string `ver`, which may be
+/// null, made through `via`.
+///
+/// # Safety
+///
+/// `ver` must be null or point to a nul-terminated string.
+pub unsafe fn record_ptr(ver: *const c_char, via: &'static str) {
+    if ver.is_null() || !enabled() {
+        return;
+    }
+    let ver = CStr::from_ptr(ver).to_bytes();
+    match Interface::parse(ver) {
+        Some(interface) => add(interface.to_string(), Some(interface), via),
+        None => add(String::from_utf8_lossy(ver).into_owned(), None, via),
+    }
+}
+
+/// Record a request for `interface`, made through `via`.
+pub fn record(interface: Interface, via: &'static str) {
+    if enabled() {
+        add(interface.to_string(), Some(interface), via);
+    }
+}
+
+/// Record a call to a flat API accessor like `SteamAPI_SteamUser_v021`.
+pub fn record_accessor(name: &'static str) {
+    if let Some(interface) = Interface::from_accessor(name) {
+        record(interface, name);
+    }
+}
+
+fn lines(entries: &Entries) -> String {
+    let mut entries = entries.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(name, entry)| (entry.first_seen, *name));
+    let mut out = String::new();
+    for (name, entry) in entries {
+        out.push_str("{\"interface\":");
+        write_str(&mut out, name);
+        match entry.interface {
+            Some(interface) => {
+                let _ = write!(
+                    out,
+                    ",\"kind\":\"{:?}\",\"version\":{}",
+                    interface.kind, interface.version
+                );
+            }
+            None => out.push_str(",\"kind\":null,\"version\":null"),
+        }
+        let first_seen = entry
+            .first_seen
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let _ = write!(
+            out,
+            ",\"first_seen_ms\":{},\"calls\":{},\"via\":[",
+            first_seen.as_millis(),
+            entry.calls
+        );
+        for (i, via) in entry.via.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            write_str(&mut out, via);
+        }
+        out.push_str("]}\n");
+    }
+    out
+}
+
+/// Write the report to `path`, or to the configured path if it's `None`.
+/// Returns whether it was written. Interfaces are only recorded while the
+/// inventory is enabled in the config.
+pub fn write(path: Option<&OsStr>) -> bool {
+    REPORT.write(path)
+}
+
+/// Write the report to the configured path, if there is one.
+pub fn shutdown() {
+    REPORT.shutdown();
+}
@@ -0,0 +1,42 @@
+//! The parts of konigsberg a Steam API proxy is built from, for the
+//! `konigsberg` shim itself and for other proxies in front of the same
+//! library.
+//!
+//! - [`ffi`] loads the original library and resolves symbols in it, and
+//!   [`reexport!`] and [`import!`] declare functions that forward to them.
+//! - [`interface`] parses interface version strings.
+//! - [`hooks`] patches hooks declared with [`vtable_method!`] into the
+//!   vtables of interfaces the game is handed.
+//! - [`inventory`] records which interface versions the game asks for.
+//! - [`callbacks`] records which callbacks the game registers for and is
+//!   delivered.
+//! - [`calls`] follows asynchronous API calls from their handle being
+//!   issued to their result.
+//! - [`lint`] checks calls for common Steamworks integration bugs.
+//! - [`threads`] records which threads call into Steam, and when they do
+//!   so at once.
+//! - [`plugin`] loads shared objects that add hooks through a C API.
+//!
+//! Everything is configured by `konigsberg.cfg` and `KONIGSBERG_*`
+//! environment variables, read the first time they're needed. Diagnostics
+//! go to stderr or the configured log file.
+
+pub mod callbacks;
+pub mod calls;
+mod config;
+pub mod ffi;
+pub mod hooks;
+pub mod interface;
+pub mod inventory;
+pub mod lint;
+mod log;
+mod method;
+mod os;
+pub mod plugin;
+mod report;
+pub mod threads;
+#[doc(hidden)]
+pub mod trace;
+
+#[doc(hidden)]
+pub use once_cell::sync::OnceCell;
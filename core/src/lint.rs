@@ -0,0 +1,376 @@
+//! Opt-in checks for Steamworks integration bugs, warned about as they're
+//! found and summed up in a report.
+//!
+//! Set `KONIGSBERG_LINT` or the `lint` config key to a file path to enable
+//! it. Every forwarded call is checked for:
+//!
+//! - `before_init`: calls that need Steam running made before `SteamAPI_Init`
+//!   or `SteamGameServer_Init` succeeded.
+//! - `after_shutdown`: the same, after `SteamAPI_Shutdown` or
+//!   `SteamGameServer_Shutdown`.
+//! - `callback_gap`: `SteamAPI_RunCallbacks` (or
+//!   `SteamAPI_ManualDispatch_RunFrame`) not being called for longer than
+//!   `lint_callback_gap` milliseconds, 1000 by default, while Steam is running,
+//!   including up to `SteamAPI_Shutdown` or the process exiting.
+//! - `auth_ticket_not_cancelled`: `GetAuthSessionTicket` handles never passed
+//!   to `CancelAuthTicket`.
+//! - `auth_session_not_ended`: `BeginAuthSession` without `EndAuthSession`
+//!   for the same Steam ID.
+//! - `thread_memory_not_released`: threads other than the one that
+//!   initialized Steam calling into it without ever calling
+//!   `SteamAPI_ReleaseCurrentThreadMemory`.
+//!
+//! Calls through interface pointers aren't forwarded, so they're only seen
+//! by the auth checks, which the konigsberg shim also hooks `ISteamUser`'s
+//! methods for.
+//!
+//! The report is rewritten by [`shutdown`] and [`write()`], which the
+//! konigsberg shim calls after `SteamAPI_Shutdown`, when it's unloaded and
+//! from `konigsberg_write_lint`. It has one line per check, whether it found
+//! anything or not, like
+//! `{"lint":"after_shutdown","count":2,"functions":{"SteamAPI_ISteamUser_GetSteamID":2}}`.
+
+use crate::{log::log, report::Report};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ffi::OsStr,
+    fmt::Write as _,
+    time::{Duration, Instant},
+};
+use steamworks_sys::{EBeginAuthSessionResult, HAuthTicket};
+
+#[derive(Default)]
+struct ThreadStats {
+    calls: u64,
+    released: bool,
+}
+
+#[derive(Default)]
+struct State {
+    /// Whether the client and the game server are initialized.
+    client: bool,
+    game_server: bool,
+    /// Whether either ever was, and whether both were shut down since.
+    initialized: bool,
+    shut_down: bool,
+    before_init: BTreeMap<&'static str, u64>,
+    after_shutdown: BTreeMap<&'static str, u64>,
+    /// When callbacks were last run, or the client initialized.
+    last_run: Option<Instant>,
+    gaps: u64,
+    max_gap: Duration,
+    /// Outstanding tickets and auth sessions, and whether they're the game
+    /// server's.
+    tickets: BTreeSet<(bool, HAuthTicket)>,
+    sessions: BTreeSet<(bool, u64)>,
+    /// The thread that initialized Steam, or failing that the first to call
+    /// into it.
+    main_thread: Option<u64>,
+    threads: HashMap<u64, ThreadStats>,
+}
+
+static REPORT: Report<State> = Report::new("lint report", |config| config.lint.as_deref(), lines);
+
+/// Whether the checks are enabled in the config.
+pub fn enabled() -> bool {
+    REPORT.enabled()
+}
+
+/// How long the game can go without running callbacks.
+fn callback_gap() -> Duration {
+    crate::config::get()
+        .lint_callback_gap
+        .unwrap_or(Duration::from_secs(1))
+}
+
+/// Whether `function` starts Steam, for the client or the game server.
+fn is_init(function: &str) -> bool {
+    function.starts_with("SteamAPI_Init")
+        || matches!(
+            function,
+            "SteamAPI_ManualDispatch_Init"
+                | "SteamInternal_GameServer_Init"
+                | "SteamGameServer_InitSafe"
+        )
+}
+
+/// Whether `function` does anything useful without Steam running. Flat
+/// interface methods and accessors don't, helpers for value types like
+/// `CSteamID` do, and neither do the functions that start it.
+fn needs_steam(function: &str) -> bool {
+    if is_init(function) {
+        return false;
+    }
+    function.starts_with("SteamAPI_ISteam")
+        || crate::interface::is_accessor(function)
+        || function.starts_with("SteamAPI_ManualDispatch_")
+        || (function.starts_with("SteamGameServer_") && function != "SteamGameServer_Shutdown")
+        || matches!(
+            function,
+            "SteamAPI_RunCallbacks"
+                | "SteamAPI_GetHSteamPipe"
+                | "SteamAPI_GetHSteamUser"
+                | "SteamInternal_CreateInterface"
+                | "SteamInternal_FindOrCreateUserInterface"
+                | "SteamInternal_FindOrCreateGameServerInterface"
+        )
+}
+
+/// Count the time from callbacks last being run to `now` as a gap if it's
+/// longer than [`callback_gap`].
+fn check_gap(state: &mut State, now: Instant) {
+    let Some(gap) = state.last_run.map(|last| now - last) else {
+        return;
+    };
+    let threshold = callback_gap();
+    if gap > threshold {
+        if state.gaps == 0 {
+            log!(
+                "lint: callbacks weren't run for {} ms, more than {} ms",
+                gap.as_millis(),
+                threshold.as_millis()
+            );
+        }
+        state.gaps += 1;
+        state.max_gap = state.max_gap.max(gap);
+    }
+}
+
+/// Check a call to forwarder `function`, before it's forwarded.
+pub fn call(function: &'static str) {
+    if !enabled() {
+        return;
+    }
+    let thread = crate::os::thread_id();
+    let mut state = REPORT.state();
+    let state = &mut *state;
+    state.main_thread.get_or_insert(thread);
+    let stats = state.threads.entry(thread).or_default();
+    match function {
+        "SteamAPI_ReleaseCurrentThreadMemory" => stats.released = true,
+        _ => stats.calls += 1,
+    }
+    if matches!(
+        function,
+        "SteamAPI_RunCallbacks" | "SteamAPI_ManualDispatch_RunFrame"
+    ) && state.client
+    {
+        let now = Instant::now();
+        check_gap(state, now);
+        state.last_run = Some(now);
+    }
+    if !needs_steam(function) || state.client || state.game_server {
+        return;
+    }
+    if state.shut_down {
+        let count = state.after_shutdown.entry(function).or_default();
+        if *count == 0 {
+            log!("lint: {function} called after Steam was shut down");
+        }
+        *count += 1;
+    } else {
+        *state.before_init.entry(function).or_default() += 1;
+    }
+}
+
+/// Record the client, or the game server, being initialized, if `ok`.
+pub fn init(game_server: bool, ok: bool) {
+    if !enabled() || !ok {
+        return;
+    }
+    let mut state = REPORT.state();
+    if !state.initialized && !state.before_init.is_empty() {
+        let functions = state
+            .before_init
+            .keys()
+            .copied()
+            .collect::<Vec<_>>()
+            .join(", ");
+        log!("lint: called before Steam was initialized: {functions}");
+    }
+    state.initialized = true;
+    state.shut_down = false;
+    if game_server {
+        state.game_server = true;
+    } else {
+        state.client = true;
+        state.last_run = Some(Instant::now());
+        state.main_thread = Some(crate::os::thread_id());
+    }
+}
+
+/// Record `ticket` from `GetAuthSessionTicket`.
+pub fn auth_ticket(game_server: bool, ticket: HAuthTicket) {
+    if enabled() && ticket != steamworks_sys::k_HAuthTicketInvalid {
+        REPORT.state().tickets.insert((game_server, ticket));
+    }
+}
+
+/// Record `ticket` being passed to `CancelAuthTicket`.
+pub fn cancel_auth_ticket(game_server: bool, ticket: HAuthTicket) {
+    if enabled() {
+        REPORT.state().tickets.remove(&(game_server, ticket));
+    }
+}
+
+/// Record `BeginAuthSession` for `steam_id` returning `result`.
+pub fn begin_auth_session(game_server: bool, steam_id: u64, result: EBeginAuthSessionResult) {
+    if enabled() && result == EBeginAuthSessionResult::k_EBeginAuthSessionResultOK {
+        REPORT.state().sessions.insert((game_server, steam_id));
+    }
+}
+
+/// Record `EndAuthSession` for `steam_id`.
+pub fn end_auth_session(game_server: bool, steam_id: u64) {
+    if enabled() {
+        REPORT.state().sessions.remove(&(game_server, steam_id));
+    }
+}
+
+fn lines(state: &State) -> String {
+    let mut out = String::new();
+    for (lint, functions) in [
+        ("before_init", &state.before_init),
+        ("after_shutdown", &state.after_shutdown),
+    ] {
+        let _ = write!(
+            out,
+            "{{\"lint\":\"{lint}\",\"count\":{},",
+            functions.values().sum::<u64>()
+        );
+        if lint == "before_init" {
+            let _ = write!(out, "\"init_seen\":{},", state.initialized);
+        }
+        out.push_str("\"functions\":{");
+        for (i, (function, count)) in functions.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "\"{function}\":{count}");
+        }
+        out.push_str("}}\n");
+    }
+    let _ = writeln!(
+        out,
+        "{{\"lint\":\"callback_gap\",\"count\":{},\"threshold_ms\":{},\"max_gap_ms\":{}}}",
+        state.gaps,
+        callback_gap().as_millis(),
+        state.max_gap.as_millis(),
+    );
+    let tickets = state
+        .tickets
+        .iter()
+        .map(|(game_server, ticket)| {
+            format!("{{\"ticket\":{ticket},\"game_server\":{game_server}}}")
+        })
+        .collect::<Vec<_>>();
+    let _ = writeln!(
+        out,
+        "{{\"lint\":\"auth_ticket_not_cancelled\",\"count\":{},\"tickets\":[{}]}}",
+        tickets.len(),
+        tickets.join(","),
+    );
+    let sessions = state
+        .sessions
+        .iter()
+        .map(|&(game_server, steam_id)| {
+            format!(
+                "{{\"steam_id\":{},\"game_server\":{game_server}}}",
+                crate::trace::steam_id(steam_id)
+            )
+        })
+        .collect::<Vec<_>>();
+    let _ = writeln!(
+        out,
+        "{{\"lint\":\"auth_session_not_ended\",\"count\":{},\"sessions\":[{}]}}",
+        sessions.len(),
+        sessions.join(","),
+    );
+    let mut threads = state
+        .threads
+        .iter()
+        .filter(|&(&thread, stats)| {
+            Some(thread) != state.main_thread && stats.calls != 0 && !stats.released
+        })
+        .map(|(thread, stats)| (*thread, stats.calls))
+        .collect::<Vec<_>>();
+    threads.sort();
+    let threads = threads
+        .into_iter()
+        .map(|(thread, calls)| format!("{{\"thread\":{thread},\"calls\":{calls}}}"))
+        .collect::<Vec<_>>();
+    let _ = writeln!(
+        out,
+        "{{\"lint\":\"thread_memory_not_released\",\"count\":{},\"threads\":[{}]}}",
+        threads.len(),
+        threads.join(","),
+    );
+    out
+}
+
+/// Write the report to `path`, or to the configured path if it's `None`.
+/// Returns whether it was written. Calls are only checked while the checks
+/// are enabled in the config.
+pub fn write(path: Option<&OsStr>) -> bool {
+    REPORT.write(path)
+}
+
+/// Record the client, or the game server, being shut down, and write the
+/// report to the configured path if there is one. Call after forwarding the
+/// shutdown.
+pub fn shutdown(game_server: bool) {
+    if !enabled() {
+        return;
+    }
+    {
+        let mut state = REPORT.state();
+        if game_server {
+            state.game_server = false;
+        } else if state.client {
+            // callbacks stopping before the shutdown is a gap too
+            check_gap(&mut state, Instant::now());
+            state.last_run = None;
+            state.client = false;
+        }
+        state.shut_down = state.initialized && !state.client && !state.game_server;
+        let (tickets, sessions) = (state.tickets.len(), state.sessions.len());
+        if !state.client && !state.game_server && tickets + sessions != 0 {
+            log!("lint: shut down with {tickets} auth tickets not cancelled and {sessions} auth sessions not ended");
+        }
+    }
+    write(None);
+}
+
+/// Write the report to the configured path, if there is one, and log a
+/// summary of what it found. Call when the process exits.
+pub fn exit() {
+    if !enabled() {
+        return;
+    }
+    {
+        let mut state = REPORT.state();
+        if state.client {
+            check_gap(&mut state, Instant::now());
+            state.last_run = None;
+        }
+        let found = [
+            state.before_init.values().sum::<u64>(),
+            state.after_shutdown.values().sum(),
+            state.gaps,
+            state.tickets.len() as u64,
+            state.sessions.len() as u64,
+        ];
+        if found.iter().any(|&count| count != 0) {
+            log!(
+                "lint: {} calls before init, {} after shutdown, {} callback gaps, \
+                 {} auth tickets not cancelled, {} auth sessions not ended",
+                found[0],
+                found[1],
+                found[2],
+                found[3],
+                found[4],
+            );
+        }
+    }
+    write(None);
+}
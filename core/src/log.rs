@@ -0,0 +1,37 @@
+//! Diagnostics, written to stderr or to the file named by `log` in the config
+//! (or `KONIGSBERG_LOG`).
+
+use once_cell::sync::OnceCell;
+use std::{
+    fmt,
+    fs::File,
+    io::{LineWriter, Write},
+    sync::Mutex,
+};
+
+fn sink() -> &'static Mutex<Box<dyn Write + Send>> {
+    static CELL: OnceCell<Mutex<Box<dyn Write + Send>>> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let file = crate::config::get()
+            .log
+            .as_ref()
+            .and_then(|path| File::options().create(true).append(true).open(path).ok());
+        Mutex::new(match file {
+            Some(file) => Box::new(LineWriter::new(file)),
+            None => Box::new(std::io::stderr()),
+        })
+    })
+}
+
+pub(crate) fn write(args: fmt::Arguments) {
+    if let Ok(mut sink) = sink().lock() {
+        let _ = writeln!(sink, "konigsberg: {args}");
+    }
+}
+
+macro_rules! log {
+    ($($arg:tt)*) => {
+        $crate::log::write(format_args!($($arg)*))
+    };
+}
+pub(crate) use log;
@@ -0,0 +1,66 @@
+//! Declaring vtable methods once for every target's calling convention.
+//!
+//! Steam's interfaces are C++ classes, so their methods take `this` first
+//! and use whatever convention the compiler uses for member functions. That's
+//! plain cdecl everywhere but 32-bit Windows, where MSVC uses thiscall:
+//! `this` in ecx and the rest on the stack, callee cleaning up. That's
+//! fastcall with nothing in edx, so methods there are declared as fastcall
+//! with an unused second argument.
+
+/// Declare a vtable method's function pointer type, a hook for one, or call
+/// one, with the right calling convention for the target.
+///
+/// - `vtable_method!(type AppFn = fn(AppId_t) -> bool;)` declares the
+///   function pointer type of a method, without `this`, which is always
+///   `*mut c_void`.
+/// - `vtable_method!(unsafe fn hook(this: *mut c_void, app_id: AppId_t) ->
+///   bool { .. })` declares a hook, which coerces to that type and is seen
+///   by [`threads`](crate::threads).
+/// - `vtable_method!(call func(this, app_id))` calls one.
+#[macro_export]
+macro_rules! vtable_method {
+    ($(#[$attr:meta])* $vis:vis type $name:ident = fn($($arg:ty),* $(,)?) $(-> $ret:ty)?;) => {
+        $(#[$attr])*
+        #[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
+        #[allow(improper_ctypes_definitions)]
+        $vis type $name = unsafe extern "C" fn(*mut ::std::ffi::c_void, $($arg),*) $(-> $ret)?;
+        $(#[$attr])*
+        #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
+        #[allow(improper_ctypes_definitions)]
+        $vis type $name =
+            unsafe extern "fastcall" fn(*mut ::std::ffi::c_void, usize, $($arg),*) $(-> $ret)?;
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis unsafe fn $name:ident(
+            $this:ident: $this_ty:ty $(, $arg:ident: $ty:ty)* $(,)?
+        ) $(-> $ret:ty)? $body:block
+    ) => {
+        $(#[$attr])*
+        #[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
+        #[allow(clippy::missing_safety_doc, improper_ctypes_definitions)]
+        $vis unsafe extern "C" fn $name($this: $this_ty $(, $arg: $ty)*) $(-> $ret)? {
+            let _hook = $crate::threads::hook();
+            $body
+        }
+        $(#[$attr])*
+        #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
+        #[allow(clippy::missing_safety_doc, improper_ctypes_definitions)]
+        $vis unsafe extern "fastcall" fn $name(
+            $this: $this_ty,
+            _edx: usize
+            $(, $arg: $ty)*
+        ) $(-> $ret)? {
+            let _hook = $crate::threads::hook();
+            $body
+        }
+    };
+    (call $func:ident($this:expr $(, $arg:expr)* $(,)?)) => {
+        $func(
+            $this,
+            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
+            0,
+            $($arg),*
+        )
+    };
+}
@@ -0,0 +1,102 @@
+/// OS-level id of the calling thread, the one debuggers and profilers show.
+pub(crate) fn thread_id() -> u64 {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    unsafe {
+        libc::gettid() as u64
+    }
+    #[cfg(target_os = "macos")]
+    unsafe {
+        let mut tid = 0;
+        libc::pthread_threadid_np(0, &mut tid);
+        tid
+    }
+    #[cfg(target_os = "windows")]
+    unsafe {
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetCurrentThreadId() -> u32;
+        }
+        GetCurrentThreadId() as u64
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "windows"
+    )))]
+    unsafe {
+        libc::pthread_self() as u64
+    }
+}
+
+/// Path of the shared object konigsberg itself was loaded from.
+pub(crate) fn module_path() -> Option<std::path::PathBuf> {
+    let addr = module_path as *const std::ffi::c_void;
+    #[cfg(unix)]
+    unsafe {
+        use std::os::unix::ffi::OsStrExt;
+        let info = dladdr(addr)?;
+        if info.dli_fname.is_null() {
+            return None;
+        }
+        let name = std::ffi::CStr::from_ptr(info.dli_fname);
+        Some(std::ffi::OsStr::from_bytes(name.to_bytes()).into())
+    }
+    #[cfg(windows)]
+    unsafe {
+        use std::os::windows::ffi::OsStringExt;
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetModuleFileNameW(module: *mut std::ffi::c_void, buf: *mut u16, len: u32) -> u32;
+        }
+        let module = module_handle(addr)?;
+        let mut buf = vec![0u16; 1024];
+        let len = GetModuleFileNameW(module, buf.as_mut_ptr(), buf.len() as u32) as usize;
+        if len == 0 || len >= buf.len() {
+            return None;
+        }
+        Some(std::ffi::OsString::from_wide(&buf[..len]).into())
+    }
+}
+
+/// Base address of the shared object or executable `addr` is in, if any.
+pub(crate) fn module_base(addr: *const std::ffi::c_void) -> Option<usize> {
+    #[cfg(unix)]
+    unsafe {
+        Some(dladdr(addr)?.dli_fbase as usize).filter(|&base| base != 0)
+    }
+    #[cfg(windows)]
+    unsafe {
+        module_handle(addr).map(|module| module as usize)
+    }
+}
+
+#[cfg(unix)]
+unsafe fn dladdr(addr: *const std::ffi::c_void) -> Option<libc::Dl_info> {
+    let mut info = std::mem::zeroed::<libc::Dl_info>();
+    (libc::dladdr(addr, &mut info) != 0).then_some(info)
+}
+
+#[cfg(windows)]
+unsafe fn module_handle(addr: *const std::ffi::c_void) -> Option<*mut std::ffi::c_void> {
+    const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+    const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetModuleHandleExW(
+            flags: u32,
+            name: *const std::ffi::c_void,
+            module: *mut *mut std::ffi::c_void,
+        ) -> i32;
+    }
+    let mut module = std::ptr::null_mut();
+    if GetModuleHandleExW(
+        GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+        addr,
+        &mut module,
+    ) == 0
+    {
+        return None;
+    }
+    Some(module)
+}
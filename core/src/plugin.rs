@@ -0,0 +1,213 @@
+//! Plugins: shared objects loaded from the directory set by `plugins` in the
+//! config (or `KONIGSBERG_PLUGINS`) that add hooks without forking
+//! konigsberg.
+//!
+//! Every library in the directory is loaded, in file name order, the first
+//! time anything is forwarded or hooked. Each must export
+//!
+//! ```c
+//! bool konigsberg_plugin_init(const konigsberg_api *api);
+//! ```
+//!
+//! which registers its hooks through `api` and returns whether it succeeded.
+//! `api` stays valid for as long as the process lives, and the C declarations
+//! are in `include/konigsberg_plugin.h`. Plugins are never unloaded.
+//!
+//! `konigsberg_plugin_init` can call Steam API exports, but one it calls
+//! before registering an export hook for it keeps calling the original.
+//!
+//! [`Api::version`] is bumped whenever fields are added, which only ever
+//! happens at the end, so a plugin built against an older version keeps
+//! working.
+
+use crate::{
+    hooks::{HookFn, VtableHook},
+    interface::InterfaceKind,
+    log::log,
+};
+use std::{
+    cell::Cell,
+    ffi::{c_char, c_void, CStr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+/// Version of [`Api`] konigsberg passes to plugins.
+pub const API_VERSION: u32 = 1;
+
+/// What `konigsberg_plugin_init` is given, `konigsberg_api` in C.
+#[repr(C)]
+pub struct Api {
+    /// [`API_VERSION`].
+    pub version: u32,
+    /// Size of this struct in bytes.
+    pub size: u32,
+    /// `konigsberg_register_vtable_hook`: replace `slot` of the vtable of
+    /// the interface class named `class` (like `ISteamApps`), for versions
+    /// `min_version` to `max_version` inclusive, with `func`. `method` names
+    /// it for `call_original`, like `ISteamApps::GetCurrentGameLanguage`.
+    /// Plugin hooks take precedence over konigsberg's own for the same slot.
+    /// Returns false if an argument is null or `class` is unknown.
+    pub register_vtable_hook: unsafe extern "C" fn(
+        class: *const c_char,
+        min_version: u8,
+        max_version: u8,
+        slot: usize,
+        method: *const c_char,
+        func: *const c_void,
+    ) -> bool,
+    /// `konigsberg_register_export_hook`: make konigsberg's export `symbol`
+    /// call `func`, which must have the same signature, instead of the
+    /// original library's. Returns false if an argument is null.
+    pub register_export_hook:
+        unsafe extern "C" fn(symbol: *const c_char, func: *const c_void) -> bool,
+    /// `konigsberg_call_original`: the function a hook of `method` on `this`
+    /// calls to call through to whatever was in the slot before, or null.
+    pub call_original:
+        unsafe extern "C" fn(this: *mut c_void, method: *const c_char) -> *const c_void,
+    /// `konigsberg_original_export`: the original library's `symbol`, for an
+    /// export hook to call through to, or null if it's missing. The
+    /// `on_missing` policy doesn't apply.
+    pub original_export: unsafe extern "C" fn(symbol: *const c_char) -> *const c_void,
+    /// `konigsberg_log`: write `message` to konigsberg's log.
+    pub log: unsafe extern "C" fn(message: *const c_char),
+}
+
+static API: Api = Api {
+    version: API_VERSION,
+    size: std::mem::size_of::<Api>() as u32,
+    register_vtable_hook,
+    register_export_hook,
+    call_original,
+    original_export,
+    log: log_message,
+};
+
+unsafe extern "C" fn register_vtable_hook(
+    class: *const c_char,
+    min_version: u8,
+    max_version: u8,
+    slot: usize,
+    method: *const c_char,
+    func: *const c_void,
+) -> bool {
+    if class.is_null() || method.is_null() || func.is_null() {
+        return false;
+    }
+    let class = CStr::from_ptr(class).to_string_lossy();
+    let Some(&interface) = InterfaceKind::ALL.iter().find(|kind| kind.class() == class) else {
+        log!("a plugin tried to hook unknown interface {class}");
+        return false;
+    };
+    let method = CStr::from_ptr(method).to_string_lossy().into_owned();
+    crate::hooks::register_first(VtableHook {
+        interface,
+        versions: min_version..=max_version,
+        slot,
+        method: Box::leak(method.into_boxed_str()),
+        func: HookFn(func),
+    });
+    true
+}
+
+unsafe extern "C" fn register_export_hook(symbol: *const c_char, func: *const c_void) -> bool {
+    if symbol.is_null() || func.is_null() {
+        return false;
+    }
+    crate::ffi::hook_export(&CStr::from_ptr(symbol).to_string_lossy(), func);
+    true
+}
+
+unsafe extern "C" fn call_original(this: *mut c_void, method: *const c_char) -> *const c_void {
+    if this.is_null() || method.is_null() {
+        return std::ptr::null();
+    }
+    let method = CStr::from_ptr(method).to_string_lossy();
+    crate::hooks::original::<*const c_void>(this, &method).unwrap_or(std::ptr::null())
+}
+
+unsafe extern "C" fn original_export(symbol: *const c_char) -> *const c_void {
+    if symbol.is_null() {
+        return std::ptr::null();
+    }
+    let symbol = CStr::from_ptr(symbol).to_string_lossy();
+    crate::ffi::original_symbol(&symbol).unwrap_or(std::ptr::null())
+}
+
+unsafe extern "C" fn log_message(message: *const c_char) {
+    if !message.is_null() {
+        log!("{}", CStr::from_ptr(message).to_string_lossy());
+    }
+}
+
+type InitFn = unsafe extern "C" fn(api: *const Api) -> bool;
+
+/// Load every plugin in the configured directory, unless that's been done
+/// already. A plugin calling back into a forwarder while it's being loaded
+/// doesn't load plugins again.
+pub fn load() {
+    static LOADED: AtomicBool = AtomicBool::new(false);
+    static LOCK: Mutex<()> = Mutex::new(());
+    thread_local! {
+        static LOADING: Cell<bool> = const { Cell::new(false) };
+    }
+    if LOADED.load(Ordering::Acquire) || LOADING.with(Cell::get) {
+        return;
+    }
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    if LOADED.load(Ordering::Acquire) {
+        return;
+    }
+    LOADING.with(|x| x.set(true));
+    if let Some(dir) = &crate::config::get().plugins {
+        load_dir(dir);
+    }
+    LOADING.with(|x| x.set(false));
+    LOADED.store(true, Ordering::Release);
+}
+
+fn load_dir(dir: &std::path::Path) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            log!(
+                "failed to read the plugin directory {}: {err}",
+                dir.display()
+            );
+            return;
+        }
+    };
+    let mut paths = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension() == Some(std::env::consts::DLL_EXTENSION.as_ref()))
+        .collect::<Vec<_>>();
+    paths.sort();
+    for path in paths {
+        let lib = match unsafe { libloading::Library::new(&path) } {
+            Ok(lib) => lib,
+            Err(err) => {
+                log!("failed to load plugin {}: {err}", path.display());
+                continue;
+            }
+        };
+        let init = match unsafe { lib.get::<InitFn>(b"konigsberg_plugin_init") } {
+            Ok(init) => *init,
+            Err(_) => {
+                log!(
+                    "{} isn't a plugin, it has no konigsberg_plugin_init",
+                    path.display()
+                );
+                continue;
+            }
+        };
+        // hooks it registered may point into it even if it failed
+        std::mem::forget(lib);
+        if unsafe { init(&API) } {
+            log!("loaded plugin {}", path.display());
+        } else {
+            log!("plugin {} failed to initialize", path.display());
+        }
+    }
+}
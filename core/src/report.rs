@@ -0,0 +1,74 @@
+//! What the opt-in reports ([`inventory`](crate::inventory),
+//! [`callbacks`](crate::callbacks), [`calls`](crate::calls),
+//! [`lint`](crate::lint) and [`threads`](crate::threads)) have in common:
+//! state behind a lock, and a file of JSON lines rewritten from it.
+
+use crate::{config::Config, log::log};
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::OsStr,
+    sync::{Mutex, MutexGuard},
+};
+
+/// A report, with its state `S`.
+pub(crate) struct Report<S> {
+    /// What it's called in log messages, like "thread report".
+    name: &'static str,
+    /// Its path in the config, which also enables it.
+    path: fn(&Config) -> Option<&OsStr>,
+    /// Its lines, from its state.
+    lines: fn(&S) -> String,
+    state: OnceCell<Mutex<S>>,
+}
+
+impl<S: Default> Report<S> {
+    pub(crate) const fn new(
+        name: &'static str,
+        path: fn(&Config) -> Option<&OsStr>,
+        lines: fn(&S) -> String,
+    ) -> Self {
+        Self {
+            name,
+            path,
+            lines,
+            state: OnceCell::new(),
+        }
+    }
+
+    /// Whether the report is enabled in the config.
+    pub(crate) fn enabled(&self) -> bool {
+        (self.path)(crate::config::get()).is_some()
+    }
+
+    pub(crate) fn state(&self) -> MutexGuard<'_, S> {
+        self.state
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Write the report to `path`, or to the configured path if it's `None`.
+    /// Returns whether it was written.
+    pub(crate) fn write(&self, path: Option<&OsStr>) -> bool {
+        let Some(path) = path.or((self.path)(crate::config::get())) else {
+            return false;
+        };
+        let lines = (self.lines)(&self.state());
+        match std::fs::write(path, lines) {
+            Ok(()) => true,
+            Err(err) => {
+                log!(
+                    "failed to write the {} to {}: {err}",
+                    self.name,
+                    path.to_string_lossy()
+                );
+                false
+            }
+        }
+    }
+
+    /// Write the report to the configured path, if there is one.
+    pub(crate) fn shutdown(&self) {
+        self.write(None);
+    }
+}
@@ -0,0 +1,262 @@
+//! Opt-in report of which threads call into Steam, and of calls into the
+//! same interface from different threads at once.
+//!
+//! Set `KONIGSBERG_THREADS` or the `threads` config key to a file path to
+//! enable it. Forwarders and hooked vtable methods are both recorded:
+//! forwarders by name, like `SteamAPI_ISteamUGC_SendQueryUGCRequest`, and
+//! methods by the name they were hooked under, like
+//! `ISteamUser::GetAuthSessionTicket`. Either belongs to the interface its
+//! name starts with, so a flat call and a call through an interface pointer
+//! are into the same `ISteamUser`.
+//!
+//! A call is concurrent when another thread is inside the same interface,
+//! or inside `SteamAPI_RunCallbacks` or `SteamAPI_ManualDispatch_RunFrame`,
+//! which touch every interface, when it starts. Methods are only seen from
+//! hooks declared with [`vtable_method!`](crate::vtable_method) once they
+//! look up their [`original`](crate::hooks::original); plugin hooks are
+//! counted, but aren't checked for overlapping.
+//!
+//! The report is rewritten by [`shutdown`] and [`write()`], which the
+//! konigsberg shim calls from `SteamAPI_Shutdown` and
+//! `konigsberg_write_threads`. It has one line per thread, like
+//! `{"thread":1234,"calls":3,"interfaces":{"ISteamUGC":2},"functions":{"SteamAPI_ISteamUGC_GetNumSubscribedItems":2,"SteamAPI_RunCallbacks":1}}`,
+//! and then one per pair of calls seen overlapping, like
+//! `{"concurrent":"ISteamUGC","count":1,"running":{"thread":1234,"fn":"SteamAPI_RunCallbacks"},"entering":{"thread":1240,"fn":"SteamAPI_ISteamUGC_GetNumSubscribedItems"}}`,
+//! where `running` was already inside when `entering` started.
+
+use crate::{log::log, report::Report};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    ffi::OsStr,
+    fmt::Write as _,
+};
+
+/// A call some thread is inside of.
+struct Active {
+    id: u64,
+    thread: u64,
+    function: &'static str,
+    interface: Option<&'static str>,
+}
+
+#[derive(Default)]
+struct ThreadStats {
+    functions: BTreeMap<&'static str, u64>,
+    interfaces: BTreeMap<&'static str, u64>,
+}
+
+/// Two overlapping calls, into this interface: (thread, function) of the one
+/// already running and of the one entering.
+type Overlap = (&'static str, (u64, &'static str), (u64, &'static str));
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    active: Vec<Active>,
+    threads: BTreeMap<u64, ThreadStats>,
+    overlaps: BTreeMap<Overlap, u64>,
+    /// Interfaces already logged as called concurrently.
+    logged: HashSet<&'static str>,
+}
+
+static REPORT: Report<State> =
+    Report::new("thread report", |config| config.threads.as_deref(), lines);
+
+thread_local! {
+    /// One entry per hook this thread is inside of, with the call it
+    /// started once it looked up the original.
+    static HOOKS: RefCell<Vec<Option<u64>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Whether the report is enabled in the config.
+pub fn enabled() -> bool {
+    REPORT.enabled()
+}
+
+/// The interface forwarder or method `function` belongs to, if any.
+fn interface(function: &'static str) -> Option<&'static str> {
+    match function.strip_prefix("SteamAPI_") {
+        Some(rest) if rest.starts_with("ISteam") => rest.split('_').next(),
+        Some(_) => None,
+        None => function
+            .split_once("::")
+            .map(|(interface, _)| interface)
+            .filter(|interface| interface.starts_with("ISteam")),
+    }
+}
+
+/// Whether `function` runs callbacks, which can touch any interface.
+fn runs_callbacks(function: &str) -> bool {
+    matches!(
+        function,
+        "SteamAPI_RunCallbacks" | "SteamAPI_ManualDispatch_RunFrame"
+    )
+}
+
+/// Record the calling thread starting a call to `function`, and whether it
+/// overlaps another thread's. Returns the call's id if it's tracked until
+/// [`exit`].
+fn start(function: &'static str, track: bool) -> Option<u64> {
+    let thread = crate::os::thread_id();
+    let interface = interface(function);
+    let mut state = REPORT.state();
+    let state = &mut *state;
+    let stats = state.threads.entry(thread).or_default();
+    *stats.functions.entry(function).or_default() += 1;
+    if let Some(interface) = interface {
+        *stats.interfaces.entry(interface).or_default() += 1;
+    }
+    if interface.is_none() && !runs_callbacks(function) {
+        return None;
+    }
+    for active in &state.active {
+        if active.thread == thread {
+            continue;
+        }
+        let shared = match (interface, active.interface) {
+            (Some(interface), Some(other)) if interface == other => interface,
+            (Some(interface), _) if runs_callbacks(active.function) => interface,
+            (None, Some(other)) => other,
+            _ => continue,
+        };
+        let overlap = (shared, (active.thread, active.function), (thread, function));
+        *state.overlaps.entry(overlap).or_default() += 1;
+        if state.logged.insert(shared) {
+            log!(
+                "threads: {function} called on thread {thread} while {} is running on thread {}",
+                active.function,
+                active.thread
+            );
+        }
+    }
+    if !track {
+        return None;
+    }
+    let id = state.next_id;
+    state.next_id += 1;
+    state.active.push(Active {
+        id,
+        thread,
+        function,
+        interface,
+    });
+    Some(id)
+}
+
+fn exit(id: u64) {
+    let mut state = REPORT.state();
+    if let Some(i) = state.active.iter().position(|active| active.id == id) {
+        state.active.swap_remove(i);
+    }
+}
+
+/// A forwarded call, recorded until it's dropped.
+#[doc(hidden)]
+pub struct Call(Option<u64>);
+
+impl Drop for Call {
+    fn drop(&mut self) {
+        if let Some(id) = self.0 {
+            exit(id);
+        }
+    }
+}
+
+/// Record forwarder `function` being called, until the returned [`Call`] is
+/// dropped.
+#[doc(hidden)]
+pub fn enter(function: &'static str) -> Call {
+    Call(enabled().then(|| start(function, true)).flatten())
+}
+
+/// A hook being run, recorded as a call to the method it looks up the
+/// original of, if any, until it's dropped.
+#[doc(hidden)]
+pub struct Hook(bool);
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        if !self.0 {
+            return;
+        }
+        let call = HOOKS
+            .try_with(|hooks| hooks.borrow_mut().pop().flatten())
+            .ok()
+            .flatten();
+        if let Some(id) = call {
+            exit(id);
+        }
+    }
+}
+
+/// Record a hook starting to run on this thread. Called by every hook
+/// [`vtable_method!`](crate::vtable_method) declares.
+#[doc(hidden)]
+pub fn hook() -> Hook {
+    let enabled = enabled()
+        && HOOKS
+            .try_with(|hooks| hooks.borrow_mut().push(None))
+            .is_ok();
+    Hook(enabled)
+}
+
+/// Record the innermost hook running on this thread being for `method`, or
+/// just count the call if it wasn't declared with
+/// [`vtable_method!`](crate::vtable_method).
+pub(crate) fn method(method: &'static str) {
+    if !enabled() {
+        return;
+    }
+    let _ = HOOKS.try_with(|hooks| {
+        let mut hooks = hooks.borrow_mut();
+        match hooks.last_mut() {
+            Some(call @ None) => *call = start(method, true),
+            _ => {
+                start(method, false);
+            }
+        }
+    });
+}
+
+fn lines(state: &State) -> String {
+    let mut out = String::new();
+    let object = |counts: &BTreeMap<&str, u64>| {
+        let fields = counts
+            .iter()
+            .map(|(name, count)| format!("\"{name}\":{count}"))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", fields.join(","))
+    };
+    for (thread, stats) in &state.threads {
+        let _ = writeln!(
+            out,
+            "{{\"thread\":{thread},\"calls\":{},\"interfaces\":{},\"functions\":{}}}",
+            stats.functions.values().sum::<u64>(),
+            object(&stats.interfaces),
+            object(&stats.functions),
+        );
+    }
+    for ((interface, running, entering), count) in &state.overlaps {
+        let _ = writeln!(
+            out,
+            "{{\"concurrent\":\"{interface}\",\"count\":{count},\
+             \"running\":{{\"thread\":{},\"fn\":\"{}\"}},\
+             \"entering\":{{\"thread\":{},\"fn\":\"{}\"}}}}",
+            running.0, running.1, entering.0, entering.1,
+        );
+    }
+    out
+}
+
+/// Write the report to `path`, or to the configured path if it's `None`.
+/// Returns whether it was written. Calls are only recorded while the report
+/// is enabled in the config.
+pub fn write(path: Option<&OsStr>) -> bool {
+    REPORT.write(path)
+}
+
+/// Write the report to the configured path, if there is one.
+pub fn shutdown() {
+    REPORT.shutdown();
+}
@@ -0,0 +1,390 @@
+//! Opt-in JSONL log of every call konigsberg forwards to the original library.
+//!
+//! Set `KONIGSBERG_TRACE` or the `trace` config key to a file path (or `-` for
+//! stderr) to enable it.
+//! Each line looks like
+//! `{"fn":"SteamAPI_RestartAppIfNecessary","args":{"unOwnAppID":480},"ret":false,"thread":1234,"dur_ns":5120}`.
+//! Callbacks delivered to the game are written in between, see
+//! [`crate::callbacks`].
+
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_int},
+    fmt::{Debug, Write as _},
+    fs::File,
+    io::{LineWriter, Write},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Mutex,
+    time::Duration,
+};
+use steamworks_sys::{
+    CGameID, CSteamID, SteamAPICall_t, SteamIPAddress_t, SteamNetworkingIPAddr,
+    SteamNetworkingIdentity,
+};
+
+type Sink = Mutex<Box<dyn Write + Send>>;
+
+fn sink() -> Option<&'static Sink> {
+    static CELL: OnceCell<Option<Sink>> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let path = crate::config::get().trace.as_ref()?;
+        let out: Box<dyn Write + Send> = if path == "-" {
+            Box::new(std::io::stderr())
+        } else {
+            Box::new(LineWriter::new(
+                File::options().create(true).append(true).open(path).ok()?,
+            ))
+        };
+        Some(Mutex::new(out))
+    })
+    .as_ref()
+}
+
+pub fn enabled() -> bool {
+    sink().is_some()
+}
+
+pub fn record(name: &str, args: &[(&str, String)], ret: String, duration: Duration) {
+    let Some(sink) = sink() else {
+        return;
+    };
+    let mut line = String::with_capacity(128);
+    line.push_str("{\"fn\":");
+    write_str(&mut line, name);
+    line.push_str(",\"args\":{");
+    for (i, (name, value)) in args.iter().enumerate() {
+        if i != 0 {
+            line.push(',');
+        }
+        write_str(&mut line, name);
+        line.push(':');
+        line.push_str(value);
+    }
+    let _ = writeln!(
+        line,
+        "}},\"ret\":{ret},\"thread\":{},\"dur_ns\":{}}}",
+        crate::os::thread_id(),
+        duration.as_nanos()
+    );
+    if let Ok(mut sink) = sink.lock() {
+        let _ = sink.write_all(line.as_bytes());
+    }
+}
+
+/// Write a delivery of callback `id`, with its decoded `payload` and the
+/// call it's the result of, if any, and whether that failed.
+pub(crate) fn record_callback(
+    id: c_int,
+    name: Option<&str>,
+    payload: Option<String>,
+    call: Option<(SteamAPICall_t, bool)>,
+) {
+    let Some(sink) = sink() else {
+        return;
+    };
+    let mut line = String::with_capacity(128);
+    let _ = write!(line, "{{\"callback\":{id}");
+    if let Some(name) = name {
+        line.push_str(",\"name\":");
+        write_str(&mut line, name);
+    }
+    if let Some((call, io_failure)) = call {
+        let _ = write!(line, ",\"call\":{call},\"io_failure\":{io_failure}");
+    }
+    let _ = writeln!(
+        line,
+        ",\"payload\":{},\"thread\":{}}}",
+        payload.as_deref().unwrap_or("null"),
+        crate::os::thread_id(),
+    );
+    if let Ok(mut sink) = sink.lock() {
+        let _ = sink.write_all(line.as_bytes());
+    }
+}
+
+pub(crate) fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// How many bytes of a C string are traced, and of a buffer.
+const STR_LIMIT: usize = 256;
+const BUF_LIMIT: usize = 32;
+
+/// A length argument as a `usize`, negative ones as 0.
+pub fn len<T: TryInto<usize>>(len: T) -> usize {
+    len.try_into().unwrap_or(0)
+}
+
+/// The NUL-terminated string at `s`, reading no more than `max` bytes and
+/// cutting it short with `…` after 256.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL-terminated string or `max` bytes.
+pub unsafe fn c_str(s: *const c_char, max: usize) -> String {
+    if s.is_null() {
+        return "null".to_owned();
+    }
+    let mut bytes = Vec::new();
+    while bytes.len() < max.min(STR_LIMIT) {
+        match *s.add(bytes.len()) as u8 {
+            0 => break,
+            c => bytes.push(c),
+        }
+    }
+    let mut text = String::from_utf8_lossy(&bytes).into_owned();
+    if bytes.len() == STR_LIMIT && max > STR_LIMIT && *s.add(STR_LIMIT) != 0 {
+        text.push('…');
+    }
+    let mut out = String::new();
+    write_str(&mut out, &text);
+    out
+}
+
+/// The `len` bytes at `buf` in hex, cut short with `…` after 32.
+///
+/// # Safety
+///
+/// `buf` must be null or point to `len` bytes.
+pub unsafe fn bytes(buf: *const u8, len: usize) -> String {
+    if buf.is_null() {
+        return "null".to_owned();
+    }
+    let mut out = String::from("\"");
+    for byte in std::slice::from_raw_parts(buf, len.min(BUF_LIMIT)) {
+        let _ = write!(out, "{byte:02x}");
+    }
+    if len > BUF_LIMIT {
+        out.push('…');
+    }
+    out.push('"');
+    out
+}
+
+/// A 64-bit Steam ID in Steam3 form, `[U:1:22202]`, with the instance after
+/// the account id where it isn't the usual one.
+pub fn steam_id(id: u64) -> String {
+    let account = id as u32;
+    let instance = (id >> 32) as u32 & 0xfffff;
+    let kind = (id >> 52) as u32 & 0xf;
+    let universe = id >> 56;
+    let letter = match kind {
+        1 => 'U',
+        2 => 'M',
+        3 => 'G',
+        4 => 'A',
+        5 => 'P',
+        6 => 'C',
+        7 => 'g',
+        // chat ids of clan chats and lobbies say so in the instance
+        8 if instance & 0x80000 != 0 => 'c',
+        8 if instance & 0x40000 != 0 => 'L',
+        8 => 'T',
+        10 => 'a',
+        _ => 'I',
+    };
+    if kind == 4 || (kind == 1 && instance != 1) {
+        format!("\"[{letter}:{universe}:{account}:{instance}]\"")
+    } else {
+        format!("\"[{letter}:{universe}:{account}]\"")
+    }
+}
+
+/// An IPv4 address in host byte order, dotted.
+pub fn ipv4(ip: u32) -> String {
+    format!("\"{}\"", Ipv4Addr::from(ip))
+}
+
+/// Types that have a natural JSON representation.
+pub trait Scalar {
+    fn to_json(&self) -> String;
+}
+
+macro_rules! scalar_num {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn to_json(&self) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+scalar_num!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, bool);
+
+macro_rules! scalar_float {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn to_json(&self) -> String {
+                if self.is_finite() {
+                    self.to_string()
+                } else {
+                    "null".to_owned()
+                }
+            }
+        }
+    )*};
+}
+scalar_float!(f32, f64);
+
+impl Scalar for () {
+    fn to_json(&self) -> String {
+        "null".to_owned()
+    }
+}
+
+/// Fixed-size string buffers, up to the first NUL.
+impl<const N: usize> Scalar for [c_char; N] {
+    fn to_json(&self) -> String {
+        let bytes = self.iter().map(|&c| c as u8);
+        let bytes = bytes.take_while(|&c| c != 0).collect::<Vec<_>>();
+        let mut out = String::new();
+        write_str(&mut out, &String::from_utf8_lossy(&bytes));
+        out
+    }
+}
+
+impl Scalar for CSteamID {
+    fn to_json(&self) -> String {
+        steam_id(unsafe { self.m_steamid.m_unAll64Bits })
+    }
+}
+
+impl Scalar for SteamNetworkingIPAddr {
+    fn to_json(&self) -> String {
+        let ip = Ipv6Addr::from(unsafe { self.__bindgen_anon_1.m_ipv6 });
+        let addr = match ip.to_ipv4_mapped() {
+            Some(ip) => SocketAddr::from((ip, self.m_port)),
+            None => SocketAddr::from((ip, self.m_port)),
+        };
+        format!("\"{addr}\"")
+    }
+}
+
+impl Scalar for SteamIPAddress_t {
+    fn to_json(&self) -> String {
+        // read as an integer, the game may not have set a valid type
+        let kind = unsafe {
+            std::ptr::addr_of!(self.m_eType)
+                .cast::<u32>()
+                .read_unaligned()
+        };
+        match kind {
+            0 => ipv4(unsafe { self.__bindgen_anon_1.m_unIPv4 }),
+            1 => format!(
+                "\"{}\"",
+                Ipv6Addr::from(unsafe { self.__bindgen_anon_1.m_rgubIPv6 })
+            ),
+            _ => "null".to_owned(),
+        }
+    }
+}
+
+/// `steamid:[U:1:n]`, `ip:1.2.3.4:27015` and so on, like the SDK's
+/// `SteamNetworkingIdentity::ToString`.
+impl Scalar for SteamNetworkingIdentity {
+    fn to_json(&self) -> String {
+        let kind = unsafe {
+            std::ptr::addr_of!(self.m_eType)
+                .cast::<i32>()
+                .read_unaligned()
+        };
+        let id = &self.__bindgen_anon_1;
+        let unquote = |json: String| json[1..json.len() - 1].to_owned();
+        let text = unsafe {
+            match kind {
+                0 => return "null".to_owned(),
+                1 => format!("ip:{}", unquote(id.m_ip.to_json())),
+                2 => format!("str:{}", unquote(id.m_szGenericString.to_json())),
+                3 => {
+                    let len = len(self.m_cbSize).min(id.m_genericBytes.len());
+                    format!("gen:{}", unquote(bytes(id.m_genericBytes.as_ptr(), len)))
+                }
+                4 => unquote(id.m_szUnknownRawString.to_json()),
+                16 => format!("steamid:{}", unquote(steam_id(id.m_steamID64))),
+                17 => format!("xboxpairwise:{}", unquote(id.m_szXboxPairwiseID.to_json())),
+                18 => format!("psn:{}", { id.m_PSNID }),
+                19 => format!("stadia:{}", { id.m_stadiaID }),
+                kind => format!("type{kind}"),
+            }
+        };
+        let mut out = String::new();
+        write_str(&mut out, &text);
+        out
+    }
+}
+
+impl Scalar for CGameID {
+    fn to_json(&self) -> String {
+        unsafe { self.__bindgen_anon_1.m_ulGameID }.to_string()
+    }
+}
+
+impl<T> Scalar for *const T {
+    fn to_json(&self) -> String {
+        format!("\"{:#x}\"", *self as usize)
+    }
+}
+
+impl<T> Scalar for *mut T {
+    fn to_json(&self) -> String {
+        format!("\"{:#x}\"", *self as usize)
+    }
+}
+
+// `trace_value!` picks the first of these that applies to a value, by autoref:
+// scalars are written as-is, other `Debug` types (mostly enums) as their
+// `Debug` string, anything else as null.
+pub struct Wrap<'a, T>(pub &'a T);
+
+pub trait ViaScalar {
+    fn trace_json(&self) -> String;
+}
+impl<T: Scalar> ViaScalar for &&Wrap<'_, T> {
+    fn trace_json(&self) -> String {
+        self.0.to_json()
+    }
+}
+
+pub trait ViaDebug {
+    fn trace_json(&self) -> String;
+}
+impl<T: Debug> ViaDebug for &Wrap<'_, T> {
+    fn trace_json(&self) -> String {
+        let mut out = String::new();
+        write_str(&mut out, &format!("{:?}", self.0));
+        out
+    }
+}
+
+pub trait ViaOpaque {
+    fn trace_json(&self) -> String;
+}
+impl<T> ViaOpaque for Wrap<'_, T> {
+    fn trace_json(&self) -> String {
+        "null".to_owned()
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! trace_value {
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::trace::{ViaDebug, ViaOpaque, ViaScalar};
+        (&&&$crate::trace::Wrap(&$value)).trace_json()
+    }};
+}
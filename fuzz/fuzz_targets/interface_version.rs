@@ -0,0 +1,16 @@
+#![no_main]
+
+use konigsberg_core::interface::Interface;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|name: &[u8]| {
+    if let Some(interface) = Interface::parse(name) {
+        let prefix = interface.kind.prefix().as_bytes();
+        assert!(name.starts_with(prefix));
+        assert!(name[prefix.len()..].iter().all(u8::is_ascii_digit));
+        assert_eq!(
+            Interface::parse(interface.to_string().as_bytes()),
+            Some(interface)
+        );
+    }
+});
@@ -0,0 +1,931 @@
+//! Generates konigsberg's forwarders (`src/*reexports.rs`), vtable slot
+//! table (`src/vtable_slots.rs`) and callback table (`src/callback_types.rs`)
+//! from the `steamworks-sys` bindings.
+//!
+//! `build.rs` uses this for the target platform when the `rebuild-reexports`
+//! feature is on, the `konigsberg-gen` binary for all platforms at once.
+
+use std::{
+    collections::HashSet,
+    ffi::OsStr,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    Linux,
+    Macos,
+    Windows,
+}
+
+impl Platform {
+    pub const ALL: [Self; 3] = [Self::Linux, Self::Macos, Self::Windows];
+
+    /// The platform `target_os` builds for.
+    pub fn from_target_os(os: &str) -> Self {
+        match os {
+            "macos" => Self::Macos,
+            "windows" => Self::Windows,
+            _ => Self::Linux,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Linux => "linux",
+            Self::Macos => "macos",
+            Self::Windows => "windows",
+        }
+    }
+
+    fn cfg(self) -> &'static str {
+        match self {
+            Self::Linux => r#"all(not(target_os = "macos"), not(target_os = "windows"))"#,
+            Self::Macos => r#"target_os = "macos""#,
+            Self::Windows => r#"target_os = "windows""#,
+        }
+    }
+
+    /// The Steamworks redistributable for this platform in a `steamworks-sys`
+    /// source directory.
+    pub fn reference_lib(self, steamworks_sys: &Path) -> PathBuf {
+        let dir = steamworks_sys.join("lib/steam/redistributable_bin");
+        match self {
+            Self::Linux => dir.join("linux64/libsteam_api.so"),
+            Self::Macos => dir.join("osx/libsteam_api.dylib"),
+            Self::Windows => dir.join("win64/steam_api64.dll"),
+        }
+    }
+
+    /// This platform's bindings in a `steamworks-sys` source directory.
+    pub fn bindings(self, steamworks_sys: &Path) -> PathBuf {
+        steamworks_sys
+            .join("src")
+            .join(format!("{}_bindings.rs", self.name()))
+    }
+}
+
+/// Source directory of the `steamworks-sys` that the crate at `manifest`
+/// depends on, as found by `cargo metadata`.
+pub fn steamworks_sys_dir(cargo: &OsStr, manifest: &Path) -> PathBuf {
+    let output = std::process::Command::new(cargo)
+        .args(["metadata", "--format-version", "1", "--manifest-path"])
+        .arg(manifest)
+        .output()
+        .expect("failed to run cargo metadata");
+    assert!(
+        output.status.success(),
+        "cargo metadata failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let metadata = String::from_utf8(output.stdout).unwrap();
+    // no JSON parser here, but manifest paths only need backslashes unescaped
+    metadata
+        .split("\"manifest_path\":\"")
+        .skip(1)
+        .filter_map(|rest| {
+            let path = rest.split('"').next()?.replace("\\\\", "\\");
+            let dir = PathBuf::from(path).parent()?.to_owned();
+            dir.file_name()?
+                .to_str()?
+                .starts_with("steamworks-sys")
+                .then_some(dir)
+        })
+        .next()
+        .expect("steamworks-sys not found in cargo metadata")
+}
+
+pub fn parse(path: &Path) -> syn::File {
+    let bindings = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    syn::parse_str(&bindings).expect("failed to parse bindings")
+}
+
+/// Split each platform's `reexports` into files by the set of platforms that
+/// share them. Lines common to every platform go to `reexports.rs`, which
+/// also `include!`s the rest under the right `cfg`s; the others go to
+/// `<platforms>_reexports.rs`, like `linux_windows_reexports.rs`.
+pub fn platform_files(platforms: &[(Platform, Vec<String>)]) -> Vec<(String, String)> {
+    let sets = platforms
+        .iter()
+        .map(|(_, lines)| lines.iter().collect::<HashSet<_>>())
+        .collect::<Vec<_>>();
+    // file by the bitmask of platforms (indices into `platforms`) that have
+    // its lines, in order of first appearance
+    let mut files = Vec::<(usize, String)>::new();
+    let mut done = HashSet::new();
+    for (_, lines) in platforms {
+        for line in lines {
+            if !done.insert(line) {
+                continue;
+            }
+            let mask = sets
+                .iter()
+                .enumerate()
+                .filter(|(_, set)| set.contains(line))
+                .fold(0, |mask, (i, _)| mask | 1 << i);
+            let i = match files.iter().position(|(m, _)| *m == mask) {
+                Some(i) => i,
+                None => {
+                    files.push((mask, String::new()));
+                    files.len() - 1
+                }
+            };
+            files[i].1.push_str(line);
+            files[i].1.push('\n');
+        }
+    }
+
+    let all = (1 << platforms.len()) - 1;
+    let name = |mask: usize| {
+        let mut name = platforms
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & 1 << i != 0)
+            .map(|(_, (platform, _))| platform.name())
+            .collect::<Vec<_>>()
+            .join("_");
+        name.push_str("_reexports.rs");
+        name
+    };
+    let mut shared = String::new();
+    for (i, (platform, _)) in platforms.iter().enumerate() {
+        let mut masks = files
+            .iter()
+            .map(|(mask, _)| *mask)
+            .filter(|mask| *mask != all && mask & 1 << i != 0)
+            .collect::<Vec<_>>();
+        masks.sort_by_key(|mask| (mask.count_ones(), *mask));
+        for mask in masks {
+            writeln!(shared, "#[cfg({})]", platform.cfg()).unwrap();
+            writeln!(shared, "include!({:?});", name(mask)).unwrap();
+        }
+    }
+    shared.push('\n');
+    if let Some((_, lines)) = files.iter().find(|(mask, _)| *mask == all) {
+        shared.push_str(lines);
+    }
+
+    let mut out = vec![("reexports.rs".to_owned(), shared)];
+    out.extend(
+        files
+            .into_iter()
+            .filter(|(mask, _)| *mask != all)
+            .map(|(mask, lines)| (name(mask), lines)),
+    );
+    out
+}
+
+/// The platform a library is built for.
+pub fn platform_of(path: &Path) -> Platform {
+    let data = std::fs::read(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    use object::FileKind;
+    match FileKind::parse(&*data) {
+        Ok(FileKind::Elf32 | FileKind::Elf64) => Platform::Linux,
+        Ok(FileKind::MachO32 | FileKind::MachO64 | FileKind::MachOFat32 | FileKind::MachOFat64) => {
+            Platform::Macos
+        }
+        Ok(FileKind::Pe32 | FileKind::Pe64) => Platform::Windows,
+        _ => panic!(
+            "{} isn't a library konigsberg can be built for",
+            path.display()
+        ),
+    }
+}
+
+/// Functions `reference` exports that neither `reexports` nor `src/lib.rs`
+/// cover, like ones that aren't in the SDK headers anymore.
+pub fn dynamic_exports(reference: &Path, reexports: &[String]) -> Vec<String> {
+    use object::{Object, ObjectSymbol};
+    let data = std::fs::read(reference)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", reference.display()));
+    let file = object::File::parse(x86_64_slice(&data))
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", reference.display()));
+    let known = reexports
+        .iter()
+        .map(|line| exported_name(line))
+        .chain(HOOKED.iter().copied())
+        .collect::<HashSet<_>>();
+    let macho = file.format() == object::BinaryFormat::MachO;
+    file.dynamic_symbols()
+        .chain(file.symbols())
+        .filter(|sym| {
+            sym.is_definition() && sym.is_global() && sym.kind() == object::SymbolKind::Text
+        })
+        .filter_map(|sym| {
+            let name = sym.name().ok()?;
+            Some(if macho { name.strip_prefix('_')? } else { name })
+        })
+        // same as the functions taken from the bindings
+        .filter(|name| name.contains("Steam") || name.starts_with('C'))
+        .filter(|name| !known.contains(name))
+        .filter(|name| {
+            name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric())
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+        })
+        .map(str::to_owned)
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// The x86_64 part of a library: the macOS one is universal, and the
+/// trampolines and [`dispatched_slots`] are x86_64 only.
+fn x86_64_slice(data: &[u8]) -> &[u8] {
+    use object::{macho::FatHeader, read::macho::FatArch};
+    match FatHeader::parse_arch32(data) {
+        Ok(arches) => arches
+            .iter()
+            .find(|arch| arch.architecture() == object::Architecture::X86_64)
+            .and_then(|arch| arch.data(data).ok())
+            .expect("no x86_64 slice in the universal library"),
+        Err(_) => data,
+    }
+}
+
+/// The symbol a `reexport!` line exports.
+fn exported_name(line: &str) -> &str {
+    match line.strip_prefix("reexport!(\"") {
+        Some(rest) => rest.split('"').next().unwrap(),
+        None => {
+            let rest = line.split_once("fn ").unwrap().1;
+            rest.split('(').next().unwrap()
+        }
+    }
+}
+
+/// `dynamic_exports.rs`, a `dynamic_exports!` per platform with its
+/// [`dynamic_exports`].
+pub fn dynamic_exports_file(platforms: &[(Platform, Vec<String>)]) -> String {
+    let mut out = String::new();
+    for (platform, names) in platforms {
+        if names.is_empty() {
+            continue;
+        }
+        writeln!(out, "#[cfg({})]", platform.cfg()).unwrap();
+        writeln!(out, "dynamic_exports!(").unwrap();
+        for name in names {
+            writeln!(out, "    {name},").unwrap();
+        }
+        writeln!(out, ");").unwrap();
+    }
+    out
+}
+
+/// Exports that `src/lib.rs` defines itself.
+const HOOKED: &[&str] = &[
+    "SteamInternal_FindOrCreateUserInterface",
+    "SteamAPI_Shutdown",
+    "SteamGameServer_Shutdown",
+    "SteamAPI_SteamApps_v008",
+    "SteamAPI_SteamApps_v009",
+    "SteamAPI_RegisterCallback",
+    "SteamAPI_UnregisterCallback",
+    "SteamAPI_RegisterCallResult",
+    "SteamAPI_UnregisterCallResult",
+    "SteamAPI_ManualDispatch_GetNextCallback",
+    "SteamAPI_ManualDispatch_FreeLastCallback",
+    "SteamAPI_ManualDispatch_GetAPICallResult",
+    "SteamAPI_ISteamUtils_GetAPICallResult",
+    "SteamAPI_Init",
+    "SteamAPI_InitSafe",
+    "SteamInternal_GameServer_Init",
+    "SteamGameServer_InitSafe",
+    "SteamAPI_ISteamUser_GetAuthSessionTicket",
+    "SteamAPI_ISteamUser_CancelAuthTicket",
+    "SteamAPI_ISteamUser_BeginAuthSession",
+    "SteamAPI_ISteamUser_EndAuthSession",
+    "SteamAPI_ISteamGameServer_GetAuthSessionTicket",
+    "SteamAPI_ISteamGameServer_CancelAuthTicket",
+    "SteamAPI_ISteamGameServer_BeginAuthSession",
+    "SteamAPI_ISteamGameServer_EndAuthSession",
+];
+
+/// One `reexport!` line per function in the bindings, in their order.
+///
+/// Arguments and return values that are better traced some other way than
+/// as their raw value get a marker saying how, see [`ArgTypes::arg_marker`].
+pub fn reexports(code: &syn::File) -> Vec<String> {
+    use quote::ToTokens;
+    let types = ArgTypes::new(code);
+    let mut lines = Vec::new();
+    for item in code.items.iter().cloned() {
+        #[allow(clippy::single_match)]
+        match item {
+            syn::Item::ForeignMod(item) => {
+                assert_eq!(item.attrs, []);
+                assert_eq!(item.unsafety, None);
+                assert_eq!(
+                    item.abi.name.as_ref().map(|x| format!("{}", x.token())),
+                    Some(format!("{}", proc_macro2::Literal::string("C")))
+                );
+                for it in item.items {
+                    match it {
+                        syn::ForeignItem::Fn(it) => {
+                            let ident = format!("{}", it.sig.ident);
+                            if HOOKED.contains(&ident.as_str()) {
+                                continue;
+                            }
+                            if !ident.contains("Steam") && !ident.starts_with('C') {
+                                continue;
+                            }
+                            let mut link_name = None;
+                            assert!(matches!(it.vis, syn::Visibility::Public(_)));
+                            for attr in it.attrs {
+                                match attr.meta {
+                                    syn::Meta::NameValue(meta) => {
+                                        let name = format!("{}", meta.path.get_ident().unwrap());
+                                        if name == "doc" {
+                                            continue;
+                                        }
+                                        assert_eq!(name, "link_name");
+                                        link_name = Some(match meta.value {
+                                            syn::Expr::Lit(syn::ExprLit {
+                                                lit: syn::Lit::Str(lit),
+                                                ..
+                                            }) => lit.value(),
+                                            _ => panic!(),
+                                        });
+                                    }
+                                    _ => panic!(),
+                                }
+                            }
+                            assert_eq!(it.sig.constness, None);
+                            assert_eq!(it.sig.asyncness, None);
+                            assert_eq!(it.sig.unsafety, None);
+                            assert_eq!(it.sig.abi, None);
+                            assert_eq!(it.sig.variadic, None);
+                            assert_eq!(it.sig.generics.params.len(), 0);
+                            let return_type = match it.sig.output {
+                                syn::ReturnType::Type(_, typ) => Some(typ),
+                                syn::ReturnType::Default => None,
+                            };
+                            let inputs = it
+                                .sig
+                                .inputs
+                                .into_iter()
+                                .map(|inp| match inp {
+                                    syn::FnArg::Typed(arg) => {
+                                        assert!(arg.attrs.is_empty());
+                                        arg
+                                    }
+                                    _ => panic!(),
+                                })
+                                .collect::<Vec<_>>();
+                            let mut generated = String::from("reexport!(");
+                            if let Some(link_name) = link_name {
+                                // bindgen prefixes mangled names with \x01 to keep
+                                // them as-is, which can't be exported
+                                let link_name = link_name.trim_start_matches('\u{1}');
+                                generated.push_str(&format!("{link_name:?}"));
+                                generated.push_str(", ");
+                            }
+                            generated.push_str("fn ");
+                            generated.push_str(&ident);
+                            generated.push('(');
+                            for (i, arg) in inputs.iter().enumerate() {
+                                if i != 0 {
+                                    generated.push_str(", ");
+                                }
+                                let next = inputs.get(i + 1).map(|next| (&*next.pat, &*next.ty));
+                                if let Some(marker) = types.arg_marker(&arg.pat, &arg.ty, next) {
+                                    generated.push_str(&format!("#[{marker}] "));
+                                }
+                                generated.push_str(&format!("{}", arg.into_token_stream()));
+                            }
+                            generated.push(')');
+                            if let Some(ret) = return_type {
+                                generated.push_str(" -> ");
+                                if let Some(marker) = types.ret_marker(&ret) {
+                                    generated.push_str(&format!("#[{marker}] "));
+                                }
+                                generated.push_str(&format!("{}", ret.into_token_stream()));
+                            }
+                            generated.push_str(");");
+                            lines.push(generated);
+                        }
+                        syn::ForeignItem::Static(..) => {}
+                        _ => panic!(),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// Integer types the bindings' aliases end up at.
+const INTS: &[&str] = &[
+    "c_short",
+    "c_ushort",
+    "c_int",
+    "c_uint",
+    "c_long",
+    "c_ulong",
+    "c_longlong",
+    "c_ulonglong",
+    "c_schar",
+    "c_uchar",
+    "i8",
+    "u8",
+    "i16",
+    "u16",
+    "i32",
+    "u32",
+    "i64",
+    "u64",
+    "isize",
+    "usize",
+];
+
+/// The bindings' type aliases, for telling what function arguments are.
+struct ArgTypes {
+    aliases: std::collections::HashMap<String, String>,
+}
+
+impl ArgTypes {
+    fn new(code: &syn::File) -> Self {
+        let mut aliases = std::collections::HashMap::new();
+        for item in &code.items {
+            if let syn::Item::Type(item) = item {
+                if let syn::Type::Path(ty) = &*item.ty {
+                    let target = ty.path.segments.last().unwrap().ident.to_string();
+                    aliases.insert(item.ident.to_string(), target);
+                }
+            }
+        }
+        Self { aliases }
+    }
+
+    /// The name of the type `ty` is an alias of, if it's a named type.
+    fn resolve(&self, ty: &syn::Type) -> Option<String> {
+        let syn::Type::Path(ty) = ty else {
+            return None;
+        };
+        let mut ty = ty.path.segments.last().unwrap().ident.to_string();
+        while let Some(target) = self.aliases.get(&ty) {
+            ty = target.clone();
+        }
+        Some(ty)
+    }
+
+    fn is_int(&self, ty: &syn::Type) -> bool {
+        self.resolve(ty).is_some_and(|ty| INTS.contains(&&*ty))
+    }
+
+    /// Whether what a pointer to `ty` points to can be traced as one value.
+    /// Enums aren't, since an out-parameter the call didn't write to may
+    /// not hold a valid variant.
+    fn is_value(&self, ty: &syn::Type) -> bool {
+        self.resolve(ty).is_some_and(|ty| {
+            INTS.contains(&&*ty)
+                || ["bool", "f32", "f64"].contains(&&*ty)
+                || LEAF_STRUCTS.contains(&&*ty)
+        })
+    }
+
+    /// How `reexport!` should trace argument `name` of type `ty`, given the
+    /// argument after it, as the marker it goes after:
+    ///
+    /// - `str`: a C string.
+    /// - `buf(len)`: a byte buffer `len` long, as hex.
+    /// - `deref`: a pointer to a value, as that value.
+    /// - `out`, `out_str(len)`, `out_buf(len)`, `out_ipv4`: pointers the
+    ///   call writes a value, string, buffer or IPv4 address to, read after
+    ///   the call.
+    /// - `steam_id`: a 64-bit Steam ID, as `[U:1:n]`.
+    /// - `ipv4`: an IPv4 address in an integer, dotted.
+    fn arg_marker(
+        &self,
+        name: &syn::Pat,
+        ty: &syn::Type,
+        next: Option<(&syn::Pat, &syn::Type)>,
+    ) -> Option<String> {
+        let name = pat_name(name);
+        let len = next
+            .filter(|(next, ty)| is_length(&pat_name(next)) && self.is_int(ty))
+            .map(|(next, _)| pat_name(next));
+        let syn::Type::Ptr(ptr) = ty else {
+            let resolved = self.resolve(ty)?;
+            return match &*resolved {
+                "c_ulonglong" | "u64"
+                    if type_name(ty) == "uint64_steamid"
+                        || name.to_ascii_lowercase().contains("steamid") =>
+                {
+                    Some("steam_id".to_owned())
+                }
+                "c_uint" | "u32" if is_ip(&name) => Some("ipv4".to_owned()),
+                _ => None,
+            };
+        };
+        let pointee = self.resolve(&ptr.elem)?;
+        let bytes = ["c_void", "c_uchar", "u8"].contains(&&*pointee);
+        Some(match (ptr.mutability.is_some(), len) {
+            (false, _) if pointee == "c_char" => "str".to_owned(),
+            (false, Some(len)) if bytes => format!("buf({len})"),
+            (false, _) if LEAF_STRUCTS.contains(&&*pointee) => "deref".to_owned(),
+            (false, _) => return None,
+            // the object a method is called on, not something it returns
+            (true, _) if name == "this" || name == "self_" => return None,
+            (true, Some(len)) if pointee == "c_char" => format!("out_str({len})"),
+            (true, Some(len)) if bytes => format!("out_buf({len})"),
+            (true, None) if pointee == "c_uint" && is_ip(&name) => "out_ipv4".to_owned(),
+            (true, None) if self.is_value(&ptr.elem) => "out".to_owned(),
+            (true, _) => return None,
+        })
+    }
+
+    /// How `reexport!` should treat a return value of type `ty`: like
+    /// [`Self::arg_marker`], or `call` for an API call handle.
+    fn ret_marker(&self, ty: &syn::Type) -> Option<&'static str> {
+        let syn::Type::Ptr(ptr) = ty else {
+            return match &*type_name(ty) {
+                "uint64_steamid" => Some("steam_id"),
+                "SteamAPICall_t" => Some("call"),
+                _ => None,
+            };
+        };
+        let pointee = self.resolve(&ptr.elem)?;
+        match ptr.mutability {
+            None if pointee == "c_char" => Some("str"),
+            None if LEAF_STRUCTS.contains(&&*pointee) => Some("deref"),
+            _ => None,
+        }
+    }
+}
+
+/// The last path segment of `ty`, without resolving aliases.
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(ty) => ty.path.segments.last().unwrap().ident.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn pat_name(pat: &syn::Pat) -> String {
+    match pat {
+        syn::Pat::Ident(pat) => pat.ident.to_string(),
+        _ => panic!(),
+    }
+}
+
+/// Whether an integer argument named `name` right after a buffer is its
+/// length, going by the SDK's naming (`cubData`, `cchNameMax`,
+/// `unBufferSize`), as opposed to something else entirely (`nScore`).
+fn is_length(name: &str) -> bool {
+    ["cb", "cch", "cub"].iter().any(|x| name.starts_with(x))
+        || ["Size", "Len", "Max"].iter().any(|x| name.contains(x))
+}
+
+/// Whether an integer argument named `name` is an IPv4 address (`unIP`,
+/// `nIPv4`, `punGameServerIP`).
+fn is_ip(name: &str) -> bool {
+    name == "ip" || name.contains("IP")
+}
+
+/// The slot every flat function in `reference` jumps to in its object's
+/// vtable, for the ones that do nothing else.
+pub fn dispatched_slots(reference: &Path) -> std::collections::HashMap<String, usize> {
+    use object::{Object, ObjectSection};
+    let data = std::fs::read(reference)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", reference.display()));
+    let file = object::File::parse(x86_64_slice(&data))
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", reference.display()));
+    let macho = file.format() == object::BinaryFormat::MachO;
+    let exports = file
+        .exports()
+        .unwrap_or_else(|err| panic!("failed to read {}'s exports: {err}", reference.display()));
+    exports
+        .iter()
+        .filter_map(|export| {
+            let name = std::str::from_utf8(export.name()).ok()?;
+            let name = if macho { name.strip_prefix('_')? } else { name };
+            if !name.starts_with("SteamAPI_ISteam") {
+                return None;
+            }
+            let code = file.sections().find_map(|section| {
+                let offset = export.address().checked_sub(section.address())?;
+                section.data().ok()?.get(usize::try_from(offset).ok()?..)
+            })?;
+            Some((name.to_owned(), vtable_jump(code)?))
+        })
+        .collect()
+}
+
+/// The slot x86_64 `code` jumps to in the vtable of the object its first
+/// argument (`rdi`, or `rcx` on Windows) points to, if that's all it does.
+fn vtable_jump(code: &[u8]) -> Option<usize> {
+    /// The displacement of a `[rax + disp]` ModRM operand whose reg field is
+    /// `reg`, and what follows it.
+    fn rax_operand(code: &[u8], reg: u8) -> Option<(i64, &[u8])> {
+        let (&modrm, rest) = code.split_first()?;
+        if modrm & 0o77 != reg << 3 {
+            return None;
+        }
+        match modrm >> 6 {
+            0 => Some((0, rest)),
+            1 => Some((*rest.first()? as i8 as i64, &rest[1..])),
+            2 => Some((
+                i32::from_le_bytes(rest.get(..4)?.try_into().unwrap()) as i64,
+                &rest[4..],
+            )),
+            _ => None,
+        }
+    }
+
+    fn rex(code: &[u8]) -> &[u8] {
+        code.strip_prefix(&[0x48]).unwrap_or(code)
+    }
+
+    // push rbp; mov rbp, rsp, and pop rbp before jumping, on macOS
+    let framed = code.strip_prefix(&[0x55, 0x48, 0x89, 0xe5]);
+    let code = framed.unwrap_or(code);
+    let pop = |code| match framed {
+        Some(_) => <[u8]>::strip_prefix(code, &[0x5d]),
+        None => Some(code),
+    };
+    // mov rax, [rdi] / [rcx]
+    let code = code
+        .strip_prefix(&[0x48, 0x8b, 0x07])
+        .or_else(|| code.strip_prefix(&[0x48, 0x8b, 0x01]))?;
+    let disp = match pop(code).and_then(|code| rex(code).strip_prefix(&[0xff])) {
+        // jmp [rax + disp]
+        Some(code) => rax_operand(code, 4)?.0,
+        // mov rax, [rax + disp]; jmp rax
+        None => {
+            let (disp, code) = rax_operand(code.strip_prefix(&[0x48, 0x8b])?, 0)?;
+            rex(pop(code)?).strip_prefix(&[0xff, 0xe0])?;
+            disp
+        }
+    };
+    const WORD: i64 = std::mem::size_of::<u64>() as i64;
+    (disp >= 0 && disp % WORD == 0).then_some((disp / WORD) as usize)
+}
+
+/// Slot of every method of every `ISteam*` interface, as `pub(crate) const`s
+/// in a module per interface.
+///
+/// The slots are the ones the flat API (`SteamAPI_ISteamApps_BIsSubscribedApp`
+/// and so on) in each of the `references` jumps to, if they all agree. Flat
+/// functions that do more than jump aren't known for sure, but the flat API
+/// declares methods in vtable order, so a run of them filling the exact gap
+/// between two known slots gets the slots in between. Otherwise they're left
+/// out, since the flat API also leaves out private virtual methods, like
+/// `ISteamClient`'s, and adds non-virtual ones. Interfaces that have an
+/// `*_INTERFACE_VERSION` constant also get `INTERFACE_VERSION` and `VERSION`,
+/// the version the slots are valid for.
+pub fn vtable_slots(code: &syn::File, references: &[PathBuf]) -> String {
+    let dispatched = references
+        .iter()
+        .map(|reference| dispatched_slots(reference))
+        .collect::<Vec<_>>();
+
+    let mut versions = std::collections::HashMap::new();
+    let mut interfaces = Vec::<(String, Vec<String>)>::new();
+    for item in &code.items {
+        match item {
+            syn::Item::Const(item) if item.ident.to_string().ends_with("_INTERFACE_VERSION") => {
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::ByteStr(lit),
+                    ..
+                }) = &*item.expr
+                else {
+                    panic!("unexpected value for {}", item.ident);
+                };
+                let value = String::from_utf8(lit.value()).unwrap();
+                let value = value.trim_end_matches('\0').to_owned();
+                versions.insert(
+                    item.ident
+                        .to_string()
+                        .trim_end_matches("_INTERFACE_VERSION")
+                        .to_owned(),
+                    value,
+                );
+            }
+            syn::Item::ForeignMod(item) => {
+                for it in &item.items {
+                    let syn::ForeignItem::Fn(it) = it else {
+                        continue;
+                    };
+                    let ident = it.sig.ident.to_string();
+                    let Some(name) = ident.strip_prefix("SteamAPI_ISteam") else {
+                        continue;
+                    };
+                    let Some(syn::FnArg::Typed(this)) = it.sig.inputs.first() else {
+                        continue;
+                    };
+                    let syn::Type::Ptr(this) = &*this.ty else {
+                        continue;
+                    };
+                    let syn::Type::Path(this) = &*this.elem else {
+                        continue;
+                    };
+                    let interface = this.path.segments.last().unwrap().ident.to_string();
+                    let Some(method) = name.strip_prefix(&format!("{}_", &interface[6..])) else {
+                        continue;
+                    };
+                    match interfaces.iter_mut().find(|(name, _)| *name == interface) {
+                        Some((_, methods)) => methods.push(method.to_owned()),
+                        None => interfaces.push((interface, vec![method.to_owned()])),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut generated = String::new();
+    for (interface, methods) in interfaces {
+        writeln!(generated, "pub(crate) mod {interface} {{").unwrap();
+        if let Some(version) = versions.get(&interface[1..].to_uppercase()) {
+            let number = version.trim_start_matches(|c: char| !c.is_ascii_digit());
+            let number = number.parse::<u8>().expect("unexpected interface version");
+            writeln!(
+                generated,
+                "    pub(crate) const INTERFACE_VERSION: &str = {version:?};"
+            )
+            .unwrap();
+            writeln!(generated, "    pub(crate) const VERSION: u8 = {number};").unwrap();
+        }
+        let known = methods
+            .iter()
+            .map(|method| {
+                let name = format!("SteamAPI_{interface}_{method}");
+                let mut slots = dispatched.iter().map(|slots| slots.get(&name));
+                let first = slots.next().flatten()?;
+                slots.all(|slot| slot == Some(first)).then_some(*first)
+            })
+            .collect::<Vec<_>>();
+        let mut slots = known.clone();
+        let mut prev = None::<usize>;
+        let mut run = 0;
+        for (i, slot) in known.iter().enumerate() {
+            let Some(slot) = *slot else {
+                continue;
+            };
+            // the unknown ones since the last known slot, if they fill the
+            // gap up to this one exactly
+            let start = prev.map_or(0, |prev| prev + 1);
+            if slot.checked_sub(start) == Some(i - run) {
+                for (j, unknown) in slots[run..i].iter_mut().enumerate() {
+                    *unknown = Some(start + j);
+                }
+            }
+            prev = Some(slot);
+            run = i + 1;
+        }
+        for (method, slot) in methods.iter().zip(slots) {
+            if let Some(slot) = slot {
+                writeln!(generated, "    pub(crate) const {method}: usize = {slot};").unwrap();
+            }
+        }
+        writeln!(generated, "}}").unwrap();
+    }
+    generated
+}
+
+/// Structs traced as a whole rather than field by field, because konigsberg
+/// knows how to show them.
+const LEAF_STRUCTS: &[&str] = &[
+    "CSteamID",
+    "CGameID",
+    "SteamNetworkingIdentity",
+    "SteamNetworkingIPAddr",
+    "SteamIPAddress_t",
+];
+
+/// A `callback_types!` invocation with every callback struct, its
+/// `k_iCallback` id and its fields, in the bindings' order.
+///
+/// Fields that are structs themselves get their own fields in braces. Enum
+/// fields are marked with the integer type they're read as, and bool fields
+/// with `as bool`, since the payload may not hold a valid value of their own
+/// type. Unions, padding and bindgen's bitfields are left out.
+pub fn callback_types(code: &syn::File) -> String {
+    let mut structs = std::collections::HashMap::new();
+    let mut unions = HashSet::new();
+    let mut enums = std::collections::HashMap::new();
+    let mut aliases = std::collections::HashMap::new();
+    let mut ids = Vec::new();
+    for item in &code.items {
+        match item {
+            syn::Item::Struct(item) => {
+                if let syn::Fields::Named(fields) = &item.fields {
+                    structs.insert(item.ident.to_string(), fields.clone());
+                }
+            }
+            syn::Item::Union(item) => {
+                unions.insert(item.ident.to_string());
+            }
+            syn::Item::Type(item) => {
+                if let syn::Type::Path(ty) = &*item.ty {
+                    let target = ty.path.segments.last().unwrap().ident.to_string();
+                    aliases.insert(item.ident.to_string(), target);
+                }
+            }
+            syn::Item::Enum(item) => {
+                let ident = item.ident.to_string();
+                let repr = item
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path().is_ident("repr"))
+                    .and_then(|attr| attr.parse_args::<syn::Ident>().ok());
+                if let Some(repr) = repr {
+                    enums.insert(ident.clone(), repr.to_string());
+                }
+                // `enum Foo_t__bindgen_ty_1 { k_iCallback = 123 }`
+                let Some(name) = ident.strip_suffix("__bindgen_ty_1") else {
+                    continue;
+                };
+                let [variant] = &item.variants.iter().collect::<Vec<_>>()[..] else {
+                    continue;
+                };
+                if variant.ident != "k_iCallback" {
+                    continue;
+                }
+                let Some((
+                    _,
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(id),
+                        ..
+                    }),
+                )) = &variant.discriminant
+                else {
+                    panic!("unexpected k_iCallback for {name}");
+                };
+                ids.push((name.to_owned(), id.base10_parse::<i32>().unwrap()));
+            }
+            _ => {}
+        }
+    }
+
+    let mut generated = String::from("callback_types! {\n");
+    for (name, id) in ids {
+        let Some(struct_fields) = structs.get(&name) else {
+            continue;
+        };
+        let mut fields = String::new();
+        let types = FieldTypes {
+            structs: &structs,
+            unions: &unions,
+            aliases: &aliases,
+            enums: &enums,
+        };
+        callback_fields(&mut fields, 8, struct_fields, &types);
+        if fields.is_empty() {
+            writeln!(generated, "    {name} = {id} {{}}").unwrap();
+        } else {
+            writeln!(generated, "    {name} = {id} {{\n{fields}    }}").unwrap();
+        }
+    }
+    generated.push_str("}\n");
+    generated
+}
+
+/// Fields the SDK declares only to pad a struct to some size.
+fn is_padding(name: &str) -> bool {
+    name.starts_with("m__pad") || name == "reserved"
+}
+
+/// The types in the bindings [`callback_fields`] needs to look fields up in.
+struct FieldTypes<'a> {
+    structs: &'a std::collections::HashMap<String, syn::FieldsNamed>,
+    unions: &'a HashSet<String>,
+    aliases: &'a std::collections::HashMap<String, String>,
+    /// Enums, and their `repr`.
+    enums: &'a std::collections::HashMap<String, String>,
+}
+
+/// The fields of one callback struct, or of a struct inside one.
+fn callback_fields(out: &mut String, indent: usize, fields: &syn::FieldsNamed, types: &FieldTypes) {
+    for field in &fields.named {
+        let name = field.ident.as_ref().unwrap().to_string();
+        let mut ty = match &field.ty {
+            syn::Type::Path(ty) => ty.path.segments.last().unwrap().ident.to_string(),
+            _ => String::new(),
+        };
+        while let Some(target) = types.aliases.get(&ty) {
+            ty = target.clone();
+        }
+        if name.starts_with('_') || is_padding(&name) || types.unions.contains(&ty) {
+            continue;
+        }
+        write!(out, "{:indent$}{name}", "").unwrap();
+        if let Some(repr) = types.enums.get(&ty) {
+            writeln!(out, " as {repr},").unwrap();
+            continue;
+        }
+        match types.structs.get(&ty) {
+            Some(nested) if !LEAF_STRUCTS.contains(&ty.as_str()) => {
+                out.push_str(": {\n");
+                callback_fields(out, indent + 4, nested, types);
+                writeln!(out, "{:indent$}}},", "").unwrap();
+            }
+            _ if ty == "bool" => out.push_str(" as bool,\n"),
+            _ => out.push_str(",\n"),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Regenerates every platform's forwarders, the vtable slot table and the
+//! callback table in `src/`, from the `steamworks-sys` sources `Cargo.lock`
+//! points at or the directory given as an argument.
+//!
+//! `--reference <lib>` makes the trampolines in `src/dynamic_exports.rs`
+//! cover what `lib` exports instead of what the Steamworks redistributable
+//! does, and the vtable slots follow `lib`'s, for the platform `lib` is built
+//! for.
+//!
+//! Usage: `konigsberg-gen [--reference <lib>]... [steamworks-sys dir]`
+
+use konigsberg_gen::Platform;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut references = Vec::new();
+    let mut steamworks_sys = None;
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--reference" {
+            let path = PathBuf::from(args.next().expect("--reference needs a path"));
+            references.push((konigsberg_gen::platform_of(&path), path));
+        } else {
+            steamworks_sys = Some(PathBuf::from(arg));
+        }
+    }
+    let steamworks_sys = steamworks_sys.unwrap_or_else(|| {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        konigsberg_gen::steamworks_sys_dir(&cargo, &root.join("Cargo.toml"))
+    });
+
+    let reference = |platform: Platform| {
+        references.iter().find(|(p, _)| *p == platform).map_or_else(
+            || platform.reference_lib(&steamworks_sys),
+            |(_, path)| path.clone(),
+        )
+    };
+
+    let mut platforms = Vec::new();
+    let mut dynamic = Vec::new();
+    for platform in Platform::ALL {
+        let code = konigsberg_gen::parse(&platform.bindings(&steamworks_sys));
+        if platform == Platform::Linux {
+            write(
+                root,
+                "vtable_slots.rs",
+                &konigsberg_gen::vtable_slots(&code, &Platform::ALL.map(reference)),
+            );
+            write(
+                root,
+                "callback_types.rs",
+                &konigsberg_gen::callback_types(&code),
+            );
+        }
+        let reexports = konigsberg_gen::reexports(&code);
+        // trampolines are only implemented for unix
+        if platform != Platform::Windows {
+            dynamic.push((
+                platform,
+                konigsberg_gen::dynamic_exports(&reference(platform), &reexports),
+            ));
+        }
+        platforms.push((platform, reexports));
+    }
+    for (name, contents) in konigsberg_gen::platform_files(&platforms) {
+        write(root, &name, &contents);
+    }
+    write(
+        root,
+        "dynamic_exports.rs",
+        &konigsberg_gen::dynamic_exports_file(&dynamic),
+    );
+}
+
+fn write(root: &Path, name: &str, contents: &str) {
+    let path = root.join("src").join(name);
+    std::fs::write(&path, contents)
+        .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+    println!("wrote {}", path.display());
+}
@@ -0,0 +1,184 @@
+//! The checked-in generated files match what the generator makes of the
+//! `steamworks-sys` in `Cargo.lock`.
+
+use konigsberg_gen::Platform;
+use std::path::Path;
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let steamworks_sys = konigsberg_gen::steamworks_sys_dir(
+        &std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()),
+        &root.join("Cargo.toml"),
+    );
+    let mut platforms = Vec::new();
+    let mut dynamic = Vec::new();
+    let mut files = Vec::new();
+    for platform in Platform::ALL {
+        let code = konigsberg_gen::parse(&platform.bindings(&steamworks_sys));
+        if platform == Platform::Linux {
+            files.push((
+                "vtable_slots.rs".to_owned(),
+                konigsberg_gen::vtable_slots(
+                    &code,
+                    &Platform::ALL.map(|platform| platform.reference_lib(&steamworks_sys)),
+                ),
+            ));
+            files.push((
+                "callback_types.rs".to_owned(),
+                konigsberg_gen::callback_types(&code),
+            ));
+        }
+        let reexports = konigsberg_gen::reexports(&code);
+        if platform != Platform::Windows {
+            let reference = platform.reference_lib(&steamworks_sys);
+            dynamic.push((
+                platform,
+                konigsberg_gen::dynamic_exports(&reference, &reexports),
+            ));
+        }
+        platforms.push((platform, reexports));
+    }
+    files.extend(konigsberg_gen::platform_files(&platforms));
+    files.push((
+        "dynamic_exports.rs".to_owned(),
+        konigsberg_gen::dynamic_exports_file(&dynamic),
+    ));
+
+    for (name, contents) in files {
+        let path = root.join("src").join(&name);
+        let checked_in = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            checked_in == contents,
+            "{name} is out of date, run `cargo run -p konigsberg-gen`"
+        );
+    }
+}
+
+#[test]
+fn shared_lines_are_deduplicated() {
+    let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let files = konigsberg_gen::platform_files(&[
+        (Platform::Linux, lines(&["a", "b", "c"])),
+        (Platform::Macos, lines(&["a", "d"])),
+        (Platform::Windows, lines(&["a", "b", "e"])),
+    ]);
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, contents)| contents.as_str())
+            .unwrap_or_else(|| panic!("no {name}"))
+    };
+    assert_eq!(files.len(), 5);
+    assert!(file("reexports.rs").ends_with("\na\n"));
+    assert_eq!(file("linux_windows_reexports.rs"), "b\n");
+    assert_eq!(file("linux_reexports.rs"), "c\n");
+    assert_eq!(file("macos_reexports.rs"), "d\n");
+    assert_eq!(file("windows_reexports.rs"), "e\n");
+}
+
+#[test]
+fn callback_types_list_fields() {
+    let code = syn::parse_str(
+        r#"
+        pub type HConn = uint32;
+        pub type Info_t = Info;
+        pub struct Info {
+            pub __bindgen_anon_1: Info__bindgen_ty_1,
+            pub m_eState: EState,
+            pub m__pad1: uint16,
+            pub m_id: CSteamID,
+        }
+        #[repr(i32)]
+        pub enum EState {
+            k_EStateNone = 0,
+        }
+        pub union Info__bindgen_ty_1 {
+            pub m_unIPv4: uint32,
+        }
+        pub struct Changed_t {
+            pub m_hConn: HConn,
+            pub m_info: Info_t,
+            pub m_blob: Info__bindgen_ty_1,
+            pub m_bOk: bool,
+        }
+        pub struct Empty_t {
+            pub _address: u8,
+        }
+        pub enum Changed_t__bindgen_ty_1 {
+            k_iCallback = 1221,
+        }
+        pub enum Empty_t__bindgen_ty_1 {
+            k_iCallback = 101,
+        }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        konigsberg_gen::callback_types(&code),
+        concat!(
+            "callback_types! {\n",
+            "    Changed_t = 1221 {\n",
+            "        m_hConn,\n",
+            "        m_info: {\n",
+            "            m_eState as i32,\n",
+            "            m_id,\n",
+            "        },\n",
+            "        m_bOk as bool,\n",
+            "    }\n",
+            "    Empty_t = 101 {}\n",
+            "}\n",
+        )
+    );
+}
+
+#[test]
+fn reexports_mark_traced_arguments() {
+    let code = syn::parse_str(
+        r#"
+        pub type uint32 = ::std::os::raw::c_uint;
+        pub type uint64 = ::std::os::raw::c_ulonglong;
+        pub type uint64_steamid = uint64;
+        extern "C" {
+            pub fn SteamAPI_ISteamX_Get(
+                self_: *mut ISteamX,
+                pchName: *const ::std::os::raw::c_char,
+                pchValue: *mut ::std::os::raw::c_char,
+                cchValueMax: ::std::os::raw::c_int,
+                pvData: *const ::std::os::raw::c_void,
+                nScore: uint32,
+                pnCount: *mut uint32,
+                pState: *mut EState,
+            ) -> *const ::std::os::raw::c_char;
+            pub fn SteamAPI_ISteamX_Connect(
+                steamID: uint64_steamid,
+                unIP: uint32,
+                punGameServerIP: *mut uint32,
+                pIdentity: *const SteamNetworkingIdentity,
+            ) -> uint64_steamid;
+        }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        konigsberg_gen::reexports(&code),
+        [
+            concat!(
+                "reexport!(fn SteamAPI_ISteamX_Get(self_ : * mut ISteamX, ",
+                "#[str] pchName : * const :: std :: os :: raw :: c_char, ",
+                "#[out_str(cchValueMax)] pchValue : * mut :: std :: os :: raw :: c_char, ",
+                "cchValueMax : :: std :: os :: raw :: c_int, ",
+                "pvData : * const :: std :: os :: raw :: c_void, nScore : uint32, ",
+                "#[out] pnCount : * mut uint32, pState : * mut EState) ",
+                "-> #[str] * const :: std :: os :: raw :: c_char);",
+            ),
+            concat!(
+                "reexport!(fn SteamAPI_ISteamX_Connect(#[steam_id] steamID : uint64_steamid, ",
+                "#[ipv4] unIP : uint32, #[out_ipv4] punGameServerIP : * mut uint32, ",
+                "#[deref] pIdentity : * const SteamNetworkingIdentity) ",
+                "-> #[steam_id] uint64_steamid);",
+            ),
+        ]
+    );
+}
@@ -0,0 +1,263 @@
+//! Callbacks and call results, delivered to registered `CCallbackBase`
+//! objects by `SteamAPI_RunCallbacks` or handed out by the manual dispatch
+//! functions and `ISteamUtils::GetAPICallResult`. Tests queue them with `mock_post_callback` and
+//! `mock_complete_call`, and `SteamAPI_ISteamUser_RequestEncryptedAppTicket`
+//! issues handles for them.
+
+use super::record;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    ffi::{c_int, c_void},
+    sync::Mutex,
+};
+use steamworks_sys::*;
+
+/// Itanium vtable slots of `CCallbackBase`'s `Run` overloads.
+const RUN: usize = 0;
+const RUN_CALL_RESULT: usize = 1;
+
+const REGISTERED: u8 = CCallbackBase_k_ECallbackFlagsRegistered as u8;
+
+type RunFn = unsafe extern "C" fn(*mut CCallbackBase, *mut c_void);
+type RunCallResultFn = unsafe extern "C" fn(*mut CCallbackBase, *mut c_void, bool, SteamAPICall_t);
+
+struct CallResult {
+    id: c_int,
+    data: Vec<u8>,
+    failed: bool,
+}
+
+struct State {
+    callbacks: Vec<usize>,
+    call_results: BTreeMap<SteamAPICall_t, usize>,
+    queue: VecDeque<(c_int, Vec<u8>)>,
+    results: BTreeMap<SteamAPICall_t, CallResult>,
+    /// What the last `SteamAPI_ManualDispatch_GetNextCallback` returned.
+    current: Option<Vec<u8>>,
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    callbacks: Vec::new(),
+    call_results: BTreeMap::new(),
+    queue: VecDeque::new(),
+    results: BTreeMap::new(),
+    current: None,
+});
+
+unsafe fn slot<F: Copy>(callback: *mut CCallbackBase, index: usize) -> F {
+    let vtable = *(callback as *const *const F);
+    *vtable.add(index)
+}
+
+/// Queue callback `id` with `len` bytes at `data`.
+#[no_mangle]
+pub unsafe extern "C" fn mock_post_callback(id: c_int, data: *const c_void, len: c_int) {
+    let data = std::slice::from_raw_parts(data as *const u8, len as usize).to_vec();
+    STATE.lock().unwrap().queue.push_back((id, data));
+}
+
+/// Complete `call` with callback `id`'s `len` bytes at `data`, and queue the
+/// `SteamAPICallCompleted_t` announcing it.
+#[no_mangle]
+pub unsafe extern "C" fn mock_complete_call(
+    call: SteamAPICall_t,
+    id: c_int,
+    data: *const c_void,
+    len: c_int,
+    failed: bool,
+) {
+    let data = std::slice::from_raw_parts(data as *const u8, len as usize).to_vec();
+    let completed = SteamAPICallCompleted_t {
+        m_hAsyncCall: call,
+        m_iCallback: id,
+        m_cubParam: len as u32,
+    };
+    let completed = std::slice::from_raw_parts(
+        &completed as *const _ as *const u8,
+        std::mem::size_of_val(&completed),
+    );
+    let mut state = STATE.lock().unwrap();
+    state.results.insert(call, CallResult { id, data, failed });
+    state.queue.push_back((
+        SteamAPICallCompleted_t_k_iCallback as c_int,
+        completed.to_vec(),
+    ));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_RegisterCallback(callback: *mut CCallbackBase, id: c_int) {
+    record("SteamAPI_RegisterCallback\0");
+    (*callback).m_iCallback = id;
+    (*callback).m_nCallbackFlags |= REGISTERED;
+    STATE.lock().unwrap().callbacks.push(callback as usize);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_UnregisterCallback(callback: *mut CCallbackBase) {
+    record("SteamAPI_UnregisterCallback\0");
+    (*callback).m_nCallbackFlags &= !REGISTERED;
+    STATE
+        .lock()
+        .unwrap()
+        .callbacks
+        .retain(|&x| x != callback as usize);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_RegisterCallResult(
+    callback: *mut CCallbackBase,
+    call: SteamAPICall_t,
+) {
+    record("SteamAPI_RegisterCallResult\0");
+    (*callback).m_nCallbackFlags |= REGISTERED;
+    STATE
+        .lock()
+        .unwrap()
+        .call_results
+        .insert(call, callback as usize);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_UnregisterCallResult(
+    callback: *mut CCallbackBase,
+    call: SteamAPICall_t,
+) {
+    record("SteamAPI_UnregisterCallResult\0");
+    (*callback).m_nCallbackFlags &= !REGISTERED;
+    let mut state = STATE.lock().unwrap();
+    if state.call_results.get(&call) == Some(&(callback as usize)) {
+        state.call_results.remove(&call);
+    }
+}
+
+/// Deliver everything queued, call results first, like the real library.
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_RunCallbacks() {
+    record("SteamAPI_RunCallbacks\0");
+    loop {
+        // handlers may register and unregister, so the lock isn't held
+        // while they run
+        let (id, mut data, result, callbacks) = {
+            let mut state = STATE.lock().unwrap();
+            let Some((id, data)) = state.queue.pop_front() else {
+                break;
+            };
+            let mut result = None;
+            if id == SteamAPICallCompleted_t_k_iCallback as c_int {
+                let call = (*(data.as_ptr() as *const SteamAPICallCompleted_t)).m_hAsyncCall;
+                if let Some(callback) = state.call_results.remove(&call) {
+                    let res = state.results.remove(&call);
+                    result = res.map(|res| (callback, call, res));
+                }
+            }
+            let callbacks = state.callbacks.clone();
+            (id, data, result, callbacks)
+        };
+        if let Some((callback, call, mut res)) = result {
+            let callback = callback as *mut CCallbackBase;
+            (*callback).m_nCallbackFlags &= !REGISTERED;
+            let func = slot::<RunCallResultFn>(callback, RUN_CALL_RESULT);
+            func(
+                callback,
+                res.data.as_mut_ptr() as *mut c_void,
+                res.failed,
+                call,
+            );
+        }
+        for callback in callbacks {
+            let callback = callback as *mut CCallbackBase;
+            if (*callback).m_iCallback == id {
+                let func = slot::<RunFn>(callback, RUN);
+                func(callback, data.as_mut_ptr() as *mut c_void);
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_GetNextCallback(
+    _pipe: HSteamPipe,
+    msg: *mut CallbackMsg_t,
+) -> bool {
+    record("SteamAPI_ManualDispatch_GetNextCallback\0");
+    let mut state = STATE.lock().unwrap();
+    let Some((id, data)) = state.queue.pop_front() else {
+        return false;
+    };
+    let data = state.current.insert(data);
+    *msg = CallbackMsg_t {
+        m_hSteamUser: 1,
+        m_iCallback: id,
+        m_pubParam: data.as_mut_ptr(),
+        m_cubParam: data.len() as c_int,
+    };
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_FreeLastCallback(_pipe: HSteamPipe) {
+    record("SteamAPI_ManualDispatch_FreeLastCallback\0");
+    STATE.lock().unwrap().current = None;
+}
+
+/// Copy `call`'s result to `data` and forget it, if it's there and is
+/// callback `id`.
+unsafe fn take_result(
+    call: SteamAPICall_t,
+    data: *mut c_void,
+    size: c_int,
+    id: c_int,
+    failed: *mut bool,
+) -> bool {
+    let mut state = STATE.lock().unwrap();
+    match state.results.get(&call) {
+        Some(res) if res.id == id && res.data.len() <= size as usize => {}
+        _ => return false,
+    }
+    let res = state.results.remove(&call).unwrap();
+    std::ptr::copy_nonoverlapping(res.data.as_ptr(), data as *mut u8, res.data.len());
+    *failed = res.failed;
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_GetAPICallResult(
+    _pipe: HSteamPipe,
+    call: SteamAPICall_t,
+    data: *mut c_void,
+    size: c_int,
+    id: c_int,
+    failed: *mut bool,
+) -> bool {
+    record("SteamAPI_ManualDispatch_GetAPICallResult\0");
+    take_result(call, data, size, id, failed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUtils_GetAPICallResult(
+    _self: *mut ISteamUtils,
+    call: SteamAPICall_t,
+    data: *mut c_void,
+    size: c_int,
+    id: c_int,
+    failed: *mut bool,
+) -> bool {
+    record("SteamAPI_ISteamUtils_GetAPICallResult\0");
+    take_result(call, data, size, id, failed)
+}
+
+static NEXT_CALL: Mutex<SteamAPICall_t> = Mutex::new(0x1000);
+
+/// Hands out a new API call handle each time, for tests to complete with
+/// `mock_complete_call`.
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_RequestEncryptedAppTicket(
+    _self: *mut ISteamUser,
+    _data: *mut c_void,
+    _len: c_int,
+) -> SteamAPICall_t {
+    record("SteamAPI_ISteamUser_RequestEncryptedAppTicket\0");
+    let mut next = NEXT_CALL.lock().unwrap();
+    *next += 1;
+    *next
+}
@@ -0,0 +1,692 @@
+//! A stand-in for `libsteam_api` used by konigsberg's integration tests.
+//!
+//! Every symbol konigsberg forwards is exported here as a stub that records
+//! its own name and returns a zeroed value. The interface getters hand out
+//! fake `ISteamClient`/`ISteamApps`/`ISteamUser` objects whose vtables are laid
+//! out like the real SDK's, so slot patching can be checked end to end.
+//! Callbacks and call results queued by tests are delivered like the real
+//! library does, see [`callbacks`].
+#![allow(
+    non_snake_case,
+    non_camel_case_types,
+    clippy::missing_safety_doc,
+    improper_ctypes_definitions
+)]
+
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    ffi::{c_char, c_int, c_void, CStr},
+    sync::Mutex,
+};
+use steamworks_sys::*;
+
+include!("types.rs");
+
+mod callbacks;
+
+thread_local! {
+    static LAST_CALL: Cell<*const c_char> = const { Cell::new(std::ptr::null()) };
+}
+
+fn record(name: &'static str) {
+    LAST_CALL.with(|x| x.set(name.as_ptr() as *const c_char));
+}
+
+/// Name of the last mock export or hooked method called on this thread, or
+/// null.
+#[no_mangle]
+pub extern "C" fn mock_last_call() -> *const c_char {
+    LAST_CALL.with(|x| x.replace(std::ptr::null()))
+}
+
+macro_rules! reexport {
+    (fn SteamInternal_CreateInterface $($rest:tt)*) => {};
+    (fn SteamAPI_RunCallbacks $($rest:tt)*) => {};
+    (fn SteamAPI_ISteamUser_RequestEncryptedAppTicket $($rest:tt)*) => {};
+    (fn SteamAPI_ISteamUtils_GetAPICallResult $($rest:tt)*) => {};
+    (fn $name:ident($( $(#[$($fmt:tt)*])? $arg:ident : $type:ty ),*) $(-> $(#[$($rfmt:tt)*])? $ret:ty)?) => {
+        #[no_mangle]
+        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
+            $( let _ = $arg; )*
+            record(concat!(stringify!($name), "\0"));
+            std::mem::zeroed()
+        }
+    };
+    ($link:literal, fn $name:ident($( $(#[$($fmt:tt)*])? $arg:ident : $type:ty ),*) $(-> $(#[$($rfmt:tt)*])? $ret:ty)?) => {
+        #[export_name = $link]
+        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
+            $( let _ = $arg; )*
+            record(concat!(stringify!($name), "\0"));
+            std::mem::zeroed()
+        }
+    };
+}
+
+include!("../../src/reexports.rs");
+
+macro_rules! dynamic_exports {
+    ($($name:ident),* $(,)?) => {$(
+        dynamic_export!($name);
+    )*};
+}
+
+macro_rules! dynamic_export {
+    (SteamAPI_UseBreakpadCrashHandler) => {};
+    ($name:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $name() -> usize {
+            record(concat!(stringify!($name), "\0"));
+            0
+        }
+    };
+}
+
+include!("../../src/dynamic_exports.rs");
+
+static BREAKPAD_ARGS: Mutex<[usize; 6]> = Mutex::new([0; 6]);
+
+/// Takes as many arguments as fit in registers, so the trampoline konigsberg
+/// exports for it can be checked to pass them all through.
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_UseBreakpadCrashHandler(
+    version: *const c_char,
+    date: *const c_char,
+    time: *const c_char,
+    full_memory_dumps: bool,
+    context: *mut c_void,
+    callback: PFNPreMinidumpCallback,
+) {
+    record("SteamAPI_UseBreakpadCrashHandler\0");
+    *BREAKPAD_ARGS.lock().unwrap() = [
+        version as usize,
+        date as usize,
+        time as usize,
+        full_memory_dumps as usize,
+        context as usize,
+        callback.map_or(0, |x| x as usize),
+    ];
+}
+
+/// The arguments of the last `SteamAPI_UseBreakpadCrashHandler` call.
+#[no_mangle]
+pub unsafe extern "C" fn mock_breakpad_args(out: *mut [usize; 6]) {
+    *out = *BREAKPAD_ARGS.lock().unwrap();
+}
+
+static TICKETS: Mutex<HAuthTicket> = Mutex::new(0);
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_Init() -> bool {
+    record("SteamAPI_Init\0");
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_InitSafe() -> bool {
+    record("SteamAPI_InitSafe\0");
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamInternal_GameServer_Init(
+    _ip: u32,
+    _legacy_port: u16,
+    _game_port: u16,
+    _query_port: u16,
+    _mode: EServerMode,
+    _version: *const c_char,
+) -> bool {
+    record("SteamInternal_GameServer_Init\0");
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamGameServer_InitSafe(
+    _ip: u32,
+    _steam_port: u16,
+    _game_port: u16,
+    _query_port: u16,
+    _mode: EServerMode,
+    _version: *const c_char,
+) -> bool {
+    record("SteamGameServer_InitSafe\0");
+    true
+}
+
+/// Writes a 4-byte ticket, so tracing of out-parameters can be checked, and
+/// returns a new handle for it.
+unsafe fn auth_session_ticket(ticket: *mut c_void, max: c_int, len: *mut u32) -> HAuthTicket {
+    const TICKET: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    if ticket.is_null() || len.is_null() || (max as usize) < TICKET.len() {
+        return k_HAuthTicketInvalid;
+    }
+    std::ptr::copy_nonoverlapping(TICKET.as_ptr(), ticket as *mut u8, TICKET.len());
+    *len = TICKET.len() as u32;
+    let mut tickets = TICKETS.lock().unwrap();
+    *tickets += 1;
+    *tickets
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_GetAuthSessionTicket(
+    _self: *mut ISteamUser,
+    ticket: *mut c_void,
+    max: c_int,
+    len: *mut u32,
+) -> HAuthTicket {
+    record("SteamAPI_ISteamUser_GetAuthSessionTicket\0");
+    auth_session_ticket(ticket, max, len)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_CancelAuthTicket(
+    _self: *mut ISteamUser,
+    _ticket: HAuthTicket,
+) {
+    record("SteamAPI_ISteamUser_CancelAuthTicket\0");
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_BeginAuthSession(
+    _self: *mut ISteamUser,
+    _ticket: *const c_void,
+    _len: c_int,
+    _steam_id: uint64_steamid,
+) -> EBeginAuthSessionResult {
+    record("SteamAPI_ISteamUser_BeginAuthSession\0");
+    EBeginAuthSessionResult::k_EBeginAuthSessionResultOK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_EndAuthSession(
+    _self: *mut ISteamUser,
+    _steam_id: uint64_steamid,
+) {
+    record("SteamAPI_ISteamUser_EndAuthSession\0");
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_GetAuthSessionTicket(
+    _self: *mut ISteamGameServer,
+    ticket: *mut c_void,
+    max: c_int,
+    len: *mut u32,
+) -> HAuthTicket {
+    record("SteamAPI_ISteamGameServer_GetAuthSessionTicket\0");
+    auth_session_ticket(ticket, max, len)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_CancelAuthTicket(
+    _self: *mut ISteamGameServer,
+    _ticket: HAuthTicket,
+) {
+    record("SteamAPI_ISteamGameServer_CancelAuthTicket\0");
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_BeginAuthSession(
+    _self: *mut ISteamGameServer,
+    _ticket: *const c_void,
+    _len: c_int,
+    _steam_id: uint64_steamid,
+) -> EBeginAuthSessionResult {
+    record("SteamAPI_ISteamGameServer_BeginAuthSession\0");
+    EBeginAuthSessionResult::k_EBeginAuthSessionResultOK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_EndAuthSession(
+    _self: *mut ISteamGameServer,
+    _steam_id: uint64_steamid,
+) {
+    record("SteamAPI_ISteamGameServer_EndAuthSession\0");
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+enum Kind {
+    Apps,
+    Client,
+    User,
+}
+
+const VTABLE_LEN: usize = 64;
+
+#[repr(C)]
+struct Object {
+    vtable: *const *const c_void,
+}
+
+static OBJECTS: Mutex<Option<HashMap<(Kind, u8), usize>>> = Mutex::new(None);
+
+fn parse_ver(ver: &str) -> Option<(Kind, u8)> {
+    let kind = if ver.starts_with("STEAMAPPS_INTERFACE_VERSION") {
+        Kind::Apps
+    } else if ver.starts_with("SteamUser") {
+        Kind::User
+    } else if ver.starts_with("SteamClient") {
+        Kind::Client
+    } else {
+        return None;
+    };
+    let digits = ver.trim_start_matches(|c: char| !c.is_ascii_digit());
+    Some((kind, digits.parse().ok()?))
+}
+
+/// Make the interface getters return `object` for `ver` from now on.
+#[no_mangle]
+pub unsafe extern "C" fn mock_set_object(ver: *const c_char, object: *mut c_void) -> bool {
+    let Some(key) = CStr::from_ptr(ver).to_str().ok().and_then(parse_ver) else {
+        return false;
+    };
+    let mut lock = OBJECTS.lock().unwrap();
+    lock.get_or_insert_with(Default::default)
+        .insert(key, object as usize);
+    true
+}
+
+unsafe fn object_for(ver: *const c_char) -> *mut c_void {
+    if ver.is_null() {
+        return std::ptr::null_mut();
+    }
+    let Some(key) = CStr::from_ptr(ver).to_str().ok().and_then(parse_ver) else {
+        return std::ptr::null_mut();
+    };
+    let mut lock = OBJECTS.lock().unwrap();
+    *lock
+        .get_or_insert_with(Default::default)
+        .entry(key)
+        .or_insert_with(|| {
+            let vtable = build_vtable(key.0, key.1);
+            Box::into_raw(Box::new(Object { vtable })) as usize
+        }) as *mut c_void
+}
+
+const ARENA_VTABLES: usize = 64;
+
+/// Where vtables go: inside the mock's own image, so they belong to it like
+/// the real library's `.rodata` does, and read-only except while one is
+/// written.
+#[repr(C, align(4096))]
+struct Vtables([[*const c_void; VTABLE_LEN]; ARENA_VTABLES]);
+
+static mut VTABLES: Vtables = Vtables([[std::ptr::null(); VTABLE_LEN]; ARENA_VTABLES]);
+
+struct Arena {
+    used: usize,
+    protected: bool,
+}
+
+static ARENA: Mutex<Arena> = Mutex::new(Arena {
+    used: 0,
+    protected: false,
+});
+
+/// `ISteamClient`'s virtual methods in `SteamClient020`'s header, in
+/// declaration order, `STEAM_PRIVATE_API` ones included.
+const CLIENT_020: &[&str] = &[
+    "CreateSteamPipe",
+    "BReleaseSteamPipe",
+    "ConnectToGlobalUser",
+    "CreateLocalUser",
+    "ReleaseUser",
+    "GetISteamUser",
+    "GetISteamGameServer",
+    "SetLocalIPBinding",
+    "GetISteamFriends",
+    "GetISteamUtils",
+    "GetISteamMatchmaking",
+    "GetISteamMatchmakingServers",
+    "GetISteamGenericInterface",
+    "GetISteamUserStats",
+    "GetISteamGameServerStats",
+    "GetISteamApps",
+    "GetISteamNetworking",
+    "GetISteamRemoteStorage",
+    "GetISteamScreenshots",
+    "GetISteamGameSearch",
+    "RunFrame",
+    "GetIPCCallCount",
+    "SetWarningMessageHook",
+    "BShutdownIfAllPipesClosed",
+    "GetISteamHTTP",
+    "DEPRECATED_GetISteamUnifiedMessages",
+    "GetISteamController",
+    "GetISteamUGC",
+    "GetISteamAppList",
+    "GetISteamMusic",
+    "GetISteamMusicRemote",
+    "GetISteamHTMLSurface",
+    "DEPRECATED_Set_SteamAPI_CPostAPIResultInProcess",
+    "DEPRECATED_Remove_SteamAPI_CPostAPIResultInProcess",
+    "Set_SteamAPI_CCheckCallbackRegisteredInProcess",
+    "GetISteamInventory",
+    "GetISteamVideo",
+    "GetISteamParentalSettings",
+    "GetISteamInput",
+    "GetISteamParties",
+    "GetISteamRemotePlay",
+    "DestroyAllInterfaces",
+];
+
+/// `ISteamUser`'s virtual methods in `SteamUser021`'s header.
+const USER_021: &[&str] = &[
+    "GetHSteamUser",
+    "BLoggedOn",
+    "GetSteamID",
+    "InitiateGameConnection_DEPRECATED",
+    "TerminateGameConnection_DEPRECATED",
+    "TrackAppUsageEvent",
+    "GetUserDataFolder",
+    "StartVoiceRecording",
+    "StopVoiceRecording",
+    "GetAvailableVoice",
+    "GetVoice",
+    "DecompressVoice",
+    "GetVoiceOptimalSampleRate",
+    "GetAuthSessionTicket",
+    "BeginAuthSession",
+    "EndAuthSession",
+    "CancelAuthTicket",
+    "UserHasLicenseForApp",
+    "BIsBehindNAT",
+    "AdvertiseGame",
+    "RequestEncryptedAppTicket",
+    "GetEncryptedAppTicket",
+    "GetGameBadgeLevel",
+    "GetPlayerSteamLevel",
+    "RequestStoreAuthURL",
+    "BIsPhoneVerified",
+    "BIsTwoFactorEnabled",
+    "BIsPhoneIdentifying",
+    "BIsPhoneRequiringVerification",
+    "GetMarketEligibility",
+    "GetDurationControl",
+    "BSetDurationControlOnlineState",
+];
+
+/// `ISteamApps`'s virtual methods in `STEAMAPPS_INTERFACE_VERSION008`'s
+/// header.
+const APPS_008: &[&str] = &[
+    "BIsSubscribed",
+    "BIsLowViolence",
+    "BIsCybercafe",
+    "BIsVACBanned",
+    "GetCurrentGameLanguage",
+    "GetAvailableGameLanguages",
+    "BIsSubscribedApp",
+    "BIsDlcInstalled",
+    "GetEarliestPurchaseUnixTime",
+    "BIsSubscribedFromFreeWeekend",
+    "GetDLCCount",
+    "BGetDLCDataByIndex",
+    "InstallDLC",
+    "UninstallDLC",
+    "RequestAppProofOfPurchaseKey",
+    "GetCurrentBetaName",
+    "MarkContentCorrupt",
+    "GetInstalledDepots",
+    "GetAppInstallDir",
+    "BIsAppInstalled",
+    "GetAppOwner",
+    "GetLaunchQueryParam",
+    "GetDlcDownloadProgress",
+    "GetAppBuildId",
+    "RequestAllProofOfPurchaseKeys",
+    "GetFileDetails",
+    "GetLaunchCommandLine",
+    "BIsSubscribedFromFamilySharing",
+    "BIsTimedTrial",
+];
+
+/// The declaration order of the newest version of `kind` in the headers,
+/// for that version and later ones.
+fn declared(kind: Kind, ver: u8) -> Option<&'static [&'static str]> {
+    match kind {
+        Kind::Apps if ver >= 8 => Some(APPS_008),
+        Kind::User if ver >= 21 => Some(USER_021),
+        Kind::Client if ver >= 20 => Some(CLIENT_020),
+        _ => None,
+    }
+}
+
+/// The slot of `method` in the headers of `ver`, or -1 if the mock doesn't
+/// know it, for tests to check konigsberg's generated slots against.
+#[no_mangle]
+pub unsafe extern "C" fn mock_declared_slot(ver: *const c_char, method: *const c_char) -> c_int {
+    let (Some((kind, ver)), Ok(method)) = (
+        CStr::from_ptr(ver).to_str().ok().and_then(parse_ver),
+        CStr::from_ptr(method).to_str(),
+    ) else {
+        return -1;
+    };
+    declared(kind, ver)
+        .and_then(|methods| methods.iter().position(|x| *x == method))
+        .map_or(-1, |slot| slot as c_int)
+}
+
+/// Lay out a vtable the way the given interface version does and place it in
+/// read-only memory. The newest versions follow the headers' declaration
+/// order, older ones have their slots spelled out.
+fn build_vtable(kind: Kind, ver: u8) -> *const *const c_void {
+    let mut slots = [unknown_method as *const c_void; VTABLE_LEN];
+    if let Some(methods) = declared(kind, ver) {
+        let implemented: &[(&str, *const c_void)] = match kind {
+            Kind::Apps => &[
+                ("BIsSubscribed", b_is_subscribed as *const c_void),
+                ("BIsSubscribedApp", b_is_subscribed_app as *const c_void),
+                ("BIsDlcInstalled", b_is_dlc_installed as *const c_void),
+            ],
+            Kind::User => &[
+                (
+                    "GetAuthSessionTicket",
+                    get_auth_session_ticket as *const c_void,
+                ),
+                ("BeginAuthSession", begin_auth_session as *const c_void),
+                ("EndAuthSession", end_auth_session as *const c_void),
+                ("CancelAuthTicket", cancel_auth_ticket as *const c_void),
+                (
+                    "UserHasLicenseForApp",
+                    user_has_license_for_app as *const c_void,
+                ),
+            ],
+            Kind::Client => &[
+                ("GetISteamUser", get_i_steam_user as *const c_void),
+                (
+                    "GetISteamGenericInterface",
+                    get_i_steam_generic_interface as *const c_void,
+                ),
+                ("GetISteamApps", get_i_steam_apps as *const c_void),
+            ],
+        };
+        for &(method, func) in implemented {
+            slots[methods.iter().position(|x| *x == method).unwrap()] = func;
+        }
+        return place_vtable(slots);
+    }
+    match kind {
+        Kind::Apps => {
+            slots[0] = b_is_subscribed as *const c_void;
+            if ver >= 2 {
+                slots[6] = b_is_subscribed_app as *const c_void;
+            }
+            if ver >= 3 {
+                slots[7] = b_is_dlc_installed as *const c_void;
+            }
+        }
+        Kind::User => {
+            let slot = match ver {
+                ..=11 => None,
+                12 => Some(15),
+                13..=14 => Some(16),
+                15.. => Some(17),
+            };
+            if let Some(slot) = slot {
+                slots[slot] = user_has_license_for_app as *const c_void;
+            }
+        }
+        Kind::Client => {
+            slots[if ver <= 6 { 6 } else { 5 }] = get_i_steam_user as *const c_void;
+            slots[match ver {
+                ..=6 => 16,
+                7 => 18,
+                8 => 15,
+                9..=11 => 16,
+                12.. => 15,
+            }] = get_i_steam_apps as *const c_void;
+            let generic = match ver {
+                ..=6 => None,
+                7 => Some(14),
+                8..=11 => Some(13),
+                12.. => Some(12),
+            };
+            if let Some(generic) = generic {
+                slots[generic] = get_i_steam_generic_interface as *const c_void;
+            }
+        }
+    }
+    place_vtable(slots)
+}
+
+/// Copy `slots` into the arena.
+fn place_vtable(slots: [*const c_void; VTABLE_LEN]) -> *const *const c_void {
+    let mut arena = ARENA.lock().unwrap();
+    let arena = &mut *arena;
+    assert!(arena.used < ARENA_VTABLES, "out of vtables");
+    let base = std::ptr::addr_of_mut!(VTABLES) as *mut [*const c_void; VTABLE_LEN];
+    unsafe {
+        if !arena.protected {
+            region::protect(
+                base,
+                std::mem::size_of::<Vtables>(),
+                region::Protection::READ,
+            )
+            .expect("mprotect failed");
+            arena.protected = true;
+        }
+        let ptr = base.add(arena.used);
+        arena.used += 1;
+        let _handle = region::protect_with_handle(
+            ptr,
+            std::mem::size_of_val(&slots),
+            region::Protection::READ_WRITE,
+        )
+        .expect("mprotect failed");
+        ptr.write(slots);
+        ptr as *const *const c_void
+    }
+}
+
+unsafe extern "C" fn unknown_method(_this: *mut c_void) -> usize {
+    0
+}
+
+unsafe extern "C" fn b_is_subscribed(_this: *mut c_void) -> bool {
+    true
+}
+
+unsafe extern "C" fn b_is_subscribed_app(_this: *mut c_void, _app_id: AppId_t) -> bool {
+    record("ISteamApps::BIsSubscribedApp\0");
+    false
+}
+
+unsafe extern "C" fn b_is_dlc_installed(_this: *mut c_void, _app_id: AppId_t) -> bool {
+    record("ISteamApps::BIsDlcInstalled\0");
+    false
+}
+
+unsafe extern "C" fn user_has_license_for_app(
+    _this: *mut c_void,
+    _steam_id: CSteamID,
+    _app_id: AppId_t,
+) -> EUserHasLicenseForAppResult {
+    record("ISteamUser::UserHasLicenseForApp\0");
+    EUserHasLicenseForAppResult::k_EUserHasLicenseResultDoesNotHaveLicense
+}
+
+unsafe extern "C" fn get_auth_session_ticket(
+    _this: *mut c_void,
+    ticket: *mut c_void,
+    max: c_int,
+    len: *mut u32,
+) -> HAuthTicket {
+    record("ISteamUser::GetAuthSessionTicket\0");
+    auth_session_ticket(ticket, max, len)
+}
+
+unsafe extern "C" fn begin_auth_session(
+    _this: *mut c_void,
+    _ticket: *const c_void,
+    _len: c_int,
+    _steam_id: CSteamID,
+) -> EBeginAuthSessionResult {
+    record("ISteamUser::BeginAuthSession\0");
+    EBeginAuthSessionResult::k_EBeginAuthSessionResultOK
+}
+
+unsafe extern "C" fn end_auth_session(_this: *mut c_void, _steam_id: CSteamID) {
+    record("ISteamUser::EndAuthSession\0");
+}
+
+unsafe extern "C" fn cancel_auth_ticket(_this: *mut c_void, _ticket: HAuthTicket) {
+    record("ISteamUser::CancelAuthTicket\0");
+}
+
+unsafe extern "C" fn get_i_steam_user(
+    _this: *mut c_void,
+    _steam_user: HSteamUser,
+    _steam_pipe: HSteamPipe,
+    ver: *const c_char,
+) -> *mut c_void {
+    object_for(ver)
+}
+
+unsafe extern "C" fn get_i_steam_apps(
+    _this: *mut c_void,
+    _steam_user: HSteamUser,
+    _steam_pipe: HSteamPipe,
+    ver: *const c_char,
+) -> *mut c_void {
+    object_for(ver)
+}
+
+unsafe extern "C" fn get_i_steam_generic_interface(
+    _this: *mut c_void,
+    _steam_user: HSteamUser,
+    _steam_pipe: HSteamPipe,
+    ver: *const c_char,
+) -> *mut c_void {
+    object_for(ver)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamInternal_CreateInterface(ver: *const c_char) -> *mut c_void {
+    record("SteamInternal_CreateInterface\0");
+    object_for(ver)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamInternal_FindOrCreateUserInterface(
+    _user: HSteamUser,
+    ver: *const c_char,
+) -> *mut c_void {
+    record("SteamInternal_FindOrCreateUserInterface\0");
+    object_for(ver)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_SteamApps_v008() -> *mut ISteamApps {
+    record("SteamAPI_SteamApps_v008\0");
+    object_for(STEAMAPPS_INTERFACE_VERSION.as_ptr() as *const c_char) as *mut ISteamApps
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_Shutdown() {
+    record("SteamAPI_Shutdown\0");
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SteamGameServer_Shutdown() {
+    record("SteamGameServer_Shutdown\0");
+}
@@ -0,0 +1,15 @@
+// Enums whose zero value isn't a variant. The stubs hand out zeroed values,
+// so these are passed as their underlying integer instead, which is
+// ABI-compatible with the `#[repr]` bindgen gives them.
+#[allow(dead_code)]
+type ESteamNetworkingGetConfigValueResult = i32;
+#[allow(dead_code)]
+type ESteamNetworkingConfigScope = u32;
+#[allow(dead_code)]
+type ESteamNetworkingConfigDataType = u32;
+#[allow(dead_code)]
+type EPlayerResult_t = u32;
+#[allow(dead_code)]
+type EGameSearchErrorCode_t = u32;
+#[allow(dead_code)]
+type EControllerHapticLocation = u32;
@@ -0,0 +1,79 @@
+//! An example konigsberg plugin, which tells the game Steam is running and
+//! that its language is English.
+//!
+//! It only uses `konigsberg-core` for the [`Api`] type and
+//! [`vtable_method!`]: everything it does goes through the [`Api`] it's
+//! given, like it would for a plugin written in C.
+
+use konigsberg_core::{plugin::Api, vtable_method};
+use std::{
+    ffi::{c_char, c_void, CStr},
+    sync::OnceLock,
+};
+
+static API: OnceLock<&'static Api> = OnceLock::new();
+
+const GET_LANGUAGE: &CStr = c"ISteamApps::GetCurrentGameLanguage";
+
+type IsSteamRunningFn = unsafe extern "C" fn() -> bool;
+
+unsafe extern "C" fn is_steam_running() -> bool {
+    let api = API.get().unwrap();
+    let original = (api.original_export)(c"SteamAPI_IsSteamRunning".as_ptr());
+    if !original.is_null() {
+        let original = std::mem::transmute::<*const c_void, IsSteamRunningFn>(original);
+        if !original() {
+            (api.log)(c"example plugin: Steam isn't running, saying it is".as_ptr());
+        }
+    }
+    true
+}
+
+vtable_method! {
+    type GetLanguageFn = fn() -> *const c_char;
+}
+
+vtable_method! {
+    unsafe fn get_current_game_language(this: *mut c_void) -> *const c_char {
+        let api = API.get().unwrap();
+        let original = (api.call_original)(this, GET_LANGUAGE.as_ptr());
+        if !original.is_null() {
+            let original = std::mem::transmute::<*const c_void, GetLanguageFn>(original);
+            vtable_method!(call original(this));
+        }
+        c"english".as_ptr()
+    }
+}
+
+/// Register the hooks. Needs version 1 of the API.
+///
+/// # Safety
+///
+/// `api` must point to an [`Api`] that lives as long as the process.
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_plugin_init(api: *const Api) -> bool {
+    let Some(api) = api.as_ref() else {
+        return false;
+    };
+    if api.version < 1 {
+        return false;
+    }
+    API.get_or_init(|| api);
+    // newer than the SDK konigsberg is built against, so usually missing
+    if (api.original_export)(c"SteamAPI_ISteamUser_GetAuthTicketForWebApi".as_ptr()).is_null() {
+        (api.log)(c"example plugin: no GetAuthTicketForWebApi in this library".as_ptr());
+    }
+    let language = get_current_game_language as GetLanguageFn;
+    (api.register_export_hook)(
+        c"SteamAPI_IsSteamRunning".as_ptr(),
+        is_steam_running as IsSteamRunningFn as *const c_void,
+    ) && (api.register_vtable_hook)(
+        c"ISteamApps".as_ptr(),
+        1,
+        u8::MAX,
+        // the same in every version
+        4,
+        GET_LANGUAGE.as_ptr(),
+        language as *const c_void,
+    )
+}
@@ -0,0 +1,106 @@
+//! Compares the symbols a konigsberg build exports with the ones an original
+//! `libsteam_api` exports (ELF, PE or Mach-O), to catch games linking
+//! something konigsberg doesn't forward.
+//!
+//! Usage: `konigsberg-exports <konigsberg library> <original library>`
+//!
+//! Exits with 1 if the export tables differ, 2 if a library can't be read.
+
+use object::Object;
+use std::{collections::BTreeSet, path::Path, process::ExitCode};
+
+fn main() -> ExitCode {
+    let args = std::env::args_os().skip(1).collect::<Vec<_>>();
+    let [ours, original] = &args[..] else {
+        eprintln!("usage: konigsberg-exports <konigsberg library> <original library>");
+        return ExitCode::from(2);
+    };
+    let (ours, original) = match (exports(ours.as_ref()), exports(original.as_ref())) {
+        (Ok(ours), Ok(original)) => (ours, original),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut missing = original.difference(&ours).collect::<BTreeSet<_>>();
+    let mut extra = ours
+        .difference(&original)
+        .filter(|name| !name.trim_start_matches('_').starts_with("konigsberg_"))
+        .collect::<BTreeSet<_>>();
+
+    // the same C++ method mangled differently, like `l` vs `x` for a 64-bit
+    // integer parameter
+    let mut mismatched = Vec::new();
+    for name in missing.clone() {
+        let Some(base) = mangled_base(name) else {
+            continue;
+        };
+        if let Some(&ours) = extra.iter().find(|ours| mangled_base(ours) == Some(base)) {
+            mismatched.push((name, ours));
+            missing.remove(name);
+            extra.remove(ours);
+        }
+    }
+
+    for name in &missing {
+        println!("not forwarded: {name}");
+    }
+    for name in &extra {
+        println!("not in the original: {name}");
+    }
+    for (original, ours) in &mismatched {
+        println!("mangled differently: {original} (original) vs {ours} (konigsberg)");
+    }
+    if missing.is_empty() && extra.is_empty() && mismatched.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
+
+fn exports(path: &Path) -> Result<BTreeSet<String>, String> {
+    let err = |err: &dyn std::fmt::Display| format!("{}: {err}", path.display());
+    let data = std::fs::read(path).map_err(|e| err(&e))?;
+    let file = object::File::parse(&*data).map_err(|e| err(&e))?;
+    Ok(file
+        .exports()
+        .map_err(|e| err(&e))?
+        .iter()
+        .map(|export| String::from_utf8_lossy(export.name()).into_owned())
+        .filter(|name| !is_runtime(name))
+        .collect())
+}
+
+/// Symbols the toolchain exports along with the Steam API, like the C++
+/// runtime that the original links statically. Games don't link these
+/// through `libsteam_api`.
+fn is_runtime(name: &str) -> bool {
+    let name = name.strip_prefix('_').unwrap_or(name);
+    matches!(name, "init" | "fini" | "edata" | "end" | "_bss_start")
+        || ["_cxa_", "_gxx_", "_gcclibcxx_", "_new_handler", "_wrap_"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        || ["ZTI", "ZTS", "ZTV"].iter().any(|prefix| {
+            ["N10__cxxabiv1", "N9__gnu_cxx"]
+                .iter()
+                .any(|ns| name.starts_with(&format!("{prefix}{ns}")))
+        })
+}
+
+/// The `_ZN...E` nested name of an Itanium-mangled symbol, without the
+/// parameter types.
+fn mangled_base(name: &str) -> Option<&str> {
+    let start = name.find("_ZN")?;
+    let mut rest = &name[start + 3..];
+    // cv-qualifiers of the method
+    rest = rest.trim_start_matches(['r', 'V', 'K']);
+    loop {
+        if let Some(tail) = rest.strip_prefix('E') {
+            return Some(&name[..name.len() - tail.len()]);
+        }
+        let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let n = rest[..len].parse::<usize>().ok()?;
+        rest = rest.get(len + n..)?;
+    }
+}
@@ -0,0 +1,1026 @@
+callback_types! {
+    SteamServersConnected_t = 101 {}
+    SteamServerConnectFailure_t = 102 {
+        m_eResult as u32,
+        m_bStillRetrying as bool,
+    }
+    SteamServersDisconnected_t = 103 {
+        m_eResult as u32,
+    }
+    ClientGameServerDeny_t = 113 {
+        m_uAppID,
+        m_unGameServerIP,
+        m_usGameServerPort,
+        m_bSecure,
+        m_uReason,
+    }
+    IPCFailure_t = 117 {
+        m_eFailureType,
+    }
+    LicensesUpdated_t = 125 {}
+    ValidateAuthTicketResponse_t = 143 {
+        m_SteamID,
+        m_eAuthSessionResponse as u32,
+        m_OwnerSteamID,
+    }
+    MicroTxnAuthorizationResponse_t = 152 {
+        m_unAppID,
+        m_ulOrderID,
+        m_bAuthorized,
+    }
+    EncryptedAppTicketResponse_t = 154 {
+        m_eResult as u32,
+    }
+    GetAuthSessionTicketResponse_t = 163 {
+        m_hAuthTicket,
+        m_eResult as u32,
+    }
+    GameWebCallback_t = 164 {
+        m_szURL,
+    }
+    StoreAuthURLResponse_t = 165 {
+        m_szURL,
+    }
+    MarketEligibilityResponse_t = 166 {
+        m_bAllowed as bool,
+        m_eNotAllowedReason as u32,
+        m_rtAllowedAtTime,
+        m_cdaySteamGuardRequiredDays,
+        m_cdayNewDeviceCooldown,
+    }
+    DurationControl_t = 167 {
+        m_eResult as u32,
+        m_appid,
+        m_bApplicable as bool,
+        m_csecsLast5h,
+        m_progress as u32,
+        m_notification as u32,
+        m_csecsToday,
+        m_csecsRemaining,
+    }
+    PersonaStateChange_t = 304 {
+        m_ulSteamID,
+        m_nChangeFlags,
+    }
+    GameOverlayActivated_t = 331 {
+        m_bActive,
+    }
+    GameServerChangeRequested_t = 332 {
+        m_rgchServer,
+        m_rgchPassword,
+    }
+    GameLobbyJoinRequested_t = 333 {
+        m_steamIDLobby,
+        m_steamIDFriend,
+    }
+    AvatarImageLoaded_t = 334 {
+        m_steamID,
+        m_iImage,
+        m_iWide,
+        m_iTall,
+    }
+    ClanOfficerListResponse_t = 335 {
+        m_steamIDClan,
+        m_cOfficers,
+        m_bSuccess,
+    }
+    FriendRichPresenceUpdate_t = 336 {
+        m_steamIDFriend,
+        m_nAppID,
+    }
+    GameRichPresenceJoinRequested_t = 337 {
+        m_steamIDFriend,
+        m_rgchConnect,
+    }
+    GameConnectedClanChatMsg_t = 338 {
+        m_steamIDClanChat,
+        m_steamIDUser,
+        m_iMessageID,
+    }
+    GameConnectedChatJoin_t = 339 {
+        m_steamIDClanChat,
+        m_steamIDUser,
+    }
+    GameConnectedChatLeave_t = 340 {
+        m_steamIDClanChat,
+        m_steamIDUser,
+        m_bKicked as bool,
+        m_bDropped as bool,
+    }
+    DownloadClanActivityCountsResult_t = 341 {
+        m_bSuccess as bool,
+    }
+    JoinClanChatRoomCompletionResult_t = 342 {
+        m_steamIDClanChat,
+        m_eChatRoomEnterResponse as u32,
+    }
+    GameConnectedFriendChatMsg_t = 343 {
+        m_steamIDUser,
+        m_iMessageID,
+    }
+    FriendsGetFollowerCount_t = 344 {
+        m_eResult as u32,
+        m_steamID,
+        m_nCount,
+    }
+    FriendsIsFollowing_t = 345 {
+        m_eResult as u32,
+        m_steamID,
+        m_bIsFollowing as bool,
+    }
+    FriendsEnumerateFollowingList_t = 346 {
+        m_eResult as u32,
+        m_rgSteamID,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+    }
+    SetPersonaNameResponse_t = 347 {
+        m_bSuccess as bool,
+        m_bLocalSuccess as bool,
+        m_result as u32,
+    }
+    UnreadChatMessagesChanged_t = 348 {}
+    OverlayBrowserProtocolNavigation_t = 349 {
+        rgchURI,
+    }
+    EquippedProfileItemsChanged_t = 350 {
+        m_steamID,
+    }
+    EquippedProfileItems_t = 351 {
+        m_eResult as u32,
+        m_steamID,
+        m_bHasAnimatedAvatar as bool,
+        m_bHasAvatarFrame as bool,
+        m_bHasProfileModifier as bool,
+        m_bHasProfileBackground as bool,
+        m_bHasMiniProfileBackground as bool,
+    }
+    IPCountry_t = 701 {}
+    LowBatteryPower_t = 702 {
+        m_nMinutesBatteryLeft,
+    }
+    SteamAPICallCompleted_t = 703 {
+        m_hAsyncCall,
+        m_iCallback,
+        m_cubParam,
+    }
+    SteamShutdown_t = 704 {}
+    CheckFileSignature_t = 705 {
+        m_eCheckFileSignature as u32,
+    }
+    GamepadTextInputDismissed_t = 714 {
+        m_bSubmitted as bool,
+        m_unSubmittedText,
+    }
+    AppResumingFromSuspend_t = 736 {}
+    FloatingGamepadTextInputDismissed_t = 738 {}
+    FavoritesListChanged_t = 502 {
+        m_nIP,
+        m_nQueryPort,
+        m_nConnPort,
+        m_nAppID,
+        m_nFlags,
+        m_bAdd as bool,
+        m_unAccountId,
+    }
+    LobbyInvite_t = 503 {
+        m_ulSteamIDUser,
+        m_ulSteamIDLobby,
+        m_ulGameID,
+    }
+    LobbyEnter_t = 504 {
+        m_ulSteamIDLobby,
+        m_rgfChatPermissions,
+        m_bLocked as bool,
+        m_EChatRoomEnterResponse,
+    }
+    LobbyDataUpdate_t = 505 {
+        m_ulSteamIDLobby,
+        m_ulSteamIDMember,
+        m_bSuccess,
+    }
+    LobbyChatUpdate_t = 506 {
+        m_ulSteamIDLobby,
+        m_ulSteamIDUserChanged,
+        m_ulSteamIDMakingChange,
+        m_rgfChatMemberStateChange,
+    }
+    LobbyChatMsg_t = 507 {
+        m_ulSteamIDLobby,
+        m_ulSteamIDUser,
+        m_eChatEntryType,
+        m_iChatID,
+    }
+    LobbyGameCreated_t = 509 {
+        m_ulSteamIDLobby,
+        m_ulSteamIDGameServer,
+        m_unIP,
+        m_usPort,
+    }
+    LobbyMatchList_t = 510 {
+        m_nLobbiesMatching,
+    }
+    LobbyKicked_t = 512 {
+        m_ulSteamIDLobby,
+        m_ulSteamIDAdmin,
+        m_bKickedDueToDisconnect,
+    }
+    LobbyCreated_t = 513 {
+        m_eResult as u32,
+        m_ulSteamIDLobby,
+    }
+    PSNGameBootInviteResult_t = 515 {
+        m_bGameBootInviteExists as bool,
+        m_steamIDLobby,
+    }
+    FavoritesListAccountsUpdated_t = 516 {
+        m_eResult as u32,
+    }
+    SearchForGameProgressCallback_t = 5201 {
+        m_ullSearchID,
+        m_eResult as u32,
+        m_lobbyID,
+        m_steamIDEndedSearch,
+        m_nSecondsRemainingEstimate,
+        m_cPlayersSearching,
+    }
+    SearchForGameResultCallback_t = 5202 {
+        m_ullSearchID,
+        m_eResult as u32,
+        m_nCountPlayersInGame,
+        m_nCountAcceptedGame,
+        m_steamIDHost,
+        m_bFinalCallback as bool,
+    }
+    RequestPlayersForGameProgressCallback_t = 5211 {
+        m_eResult as u32,
+        m_ullSearchID,
+    }
+    RequestPlayersForGameResultCallback_t = 5212 {
+        m_eResult as u32,
+        m_ullSearchID,
+        m_SteamIDPlayerFound,
+        m_SteamIDLobby,
+        m_ePlayerAcceptState as u32,
+        m_nPlayerIndex,
+        m_nTotalPlayersFound,
+        m_nTotalPlayersAcceptedGame,
+        m_nSuggestedTeamIndex,
+        m_ullUniqueGameID,
+    }
+    RequestPlayersForGameFinalResultCallback_t = 5213 {
+        m_eResult as u32,
+        m_ullSearchID,
+        m_ullUniqueGameID,
+    }
+    SubmitPlayerResultResultCallback_t = 5214 {
+        m_eResult as u32,
+        ullUniqueGameID,
+        steamIDPlayer,
+    }
+    EndGameResultCallback_t = 5215 {
+        m_eResult as u32,
+        ullUniqueGameID,
+    }
+    JoinPartyCallback_t = 5301 {
+        m_eResult as u32,
+        m_ulBeaconID,
+        m_SteamIDBeaconOwner,
+        m_rgchConnectString,
+    }
+    CreateBeaconCallback_t = 5302 {
+        m_eResult as u32,
+        m_ulBeaconID,
+    }
+    ReservationNotificationCallback_t = 5303 {
+        m_ulBeaconID,
+        m_steamIDJoiner,
+    }
+    ChangeNumOpenSlotsCallback_t = 5304 {
+        m_eResult as u32,
+    }
+    AvailableBeaconLocationsUpdated_t = 5305 {}
+    ActiveBeaconsUpdated_t = 5306 {}
+    RemoteStorageFileShareResult_t = 1307 {
+        m_eResult as u32,
+        m_hFile,
+        m_rgchFilename,
+    }
+    RemoteStoragePublishFileResult_t = 1309 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_bUserNeedsToAcceptWorkshopLegalAgreement as bool,
+    }
+    RemoteStorageDeletePublishedFileResult_t = 1311 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+    }
+    RemoteStorageEnumerateUserPublishedFilesResult_t = 1312 {
+        m_eResult as u32,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+        m_rgPublishedFileId,
+    }
+    RemoteStorageSubscribePublishedFileResult_t = 1313 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+    }
+    RemoteStorageEnumerateUserSubscribedFilesResult_t = 1314 {
+        m_eResult as u32,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+        m_rgPublishedFileId,
+        m_rgRTimeSubscribed,
+    }
+    RemoteStorageUnsubscribePublishedFileResult_t = 1315 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+    }
+    RemoteStorageUpdatePublishedFileResult_t = 1316 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_bUserNeedsToAcceptWorkshopLegalAgreement as bool,
+    }
+    RemoteStorageDownloadUGCResult_t = 1317 {
+        m_eResult as u32,
+        m_hFile,
+        m_nAppID,
+        m_nSizeInBytes,
+        m_pchFileName,
+        m_ulSteamIDOwner,
+    }
+    RemoteStorageGetPublishedFileDetailsResult_t = 1318 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_nCreatorAppID,
+        m_nConsumerAppID,
+        m_rgchTitle,
+        m_rgchDescription,
+        m_hFile,
+        m_hPreviewFile,
+        m_ulSteamIDOwner,
+        m_rtimeCreated,
+        m_rtimeUpdated,
+        m_eVisibility as u32,
+        m_bBanned as bool,
+        m_rgchTags,
+        m_bTagsTruncated as bool,
+        m_pchFileName,
+        m_nFileSize,
+        m_nPreviewFileSize,
+        m_rgchURL,
+        m_eFileType as u32,
+        m_bAcceptedForUse as bool,
+    }
+    RemoteStorageEnumerateWorkshopFilesResult_t = 1319 {
+        m_eResult as u32,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+        m_rgPublishedFileId,
+        m_rgScore,
+        m_nAppId,
+        m_unStartIndex,
+    }
+    RemoteStorageGetPublishedItemVoteDetailsResult_t = 1320 {
+        m_eResult as u32,
+        m_unPublishedFileId,
+        m_nVotesFor,
+        m_nVotesAgainst,
+        m_nReports,
+        m_fScore,
+    }
+    RemoteStoragePublishedFileSubscribed_t = 1321 {
+        m_nPublishedFileId,
+        m_nAppID,
+    }
+    RemoteStoragePublishedFileUnsubscribed_t = 1322 {
+        m_nPublishedFileId,
+        m_nAppID,
+    }
+    RemoteStoragePublishedFileDeleted_t = 1323 {
+        m_nPublishedFileId,
+        m_nAppID,
+    }
+    RemoteStorageUpdateUserPublishedItemVoteResult_t = 1324 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+    }
+    RemoteStorageUserVoteDetails_t = 1325 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_eVote as u32,
+    }
+    RemoteStorageEnumerateUserSharedWorkshopFilesResult_t = 1326 {
+        m_eResult as u32,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+        m_rgPublishedFileId,
+    }
+    RemoteStorageSetUserPublishedFileActionResult_t = 1327 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_eAction as u32,
+    }
+    RemoteStorageEnumeratePublishedFilesByUserActionResult_t = 1328 {
+        m_eResult as u32,
+        m_eAction as u32,
+        m_nResultsReturned,
+        m_nTotalResultCount,
+        m_rgPublishedFileId,
+        m_rgRTimeUpdated,
+    }
+    RemoteStoragePublishFileProgress_t = 1329 {
+        m_dPercentFile,
+        m_bPreview as bool,
+    }
+    RemoteStoragePublishedFileUpdated_t = 1330 {
+        m_nPublishedFileId,
+        m_nAppID,
+        m_ulUnused,
+    }
+    RemoteStorageFileWriteAsyncComplete_t = 1331 {
+        m_eResult as u32,
+    }
+    RemoteStorageFileReadAsyncComplete_t = 1332 {
+        m_hFileReadAsync,
+        m_eResult as u32,
+        m_nOffset,
+        m_cubRead,
+    }
+    RemoteStorageLocalFileChange_t = 1333 {}
+    UserStatsReceived_t = 1101 {
+        m_nGameID,
+        m_eResult as u32,
+        m_steamIDUser,
+    }
+    UserStatsStored_t = 1102 {
+        m_nGameID,
+        m_eResult as u32,
+    }
+    UserAchievementStored_t = 1103 {
+        m_nGameID,
+        m_bGroupAchievement as bool,
+        m_rgchAchievementName,
+        m_nCurProgress,
+        m_nMaxProgress,
+    }
+    LeaderboardFindResult_t = 1104 {
+        m_hSteamLeaderboard,
+        m_bLeaderboardFound,
+    }
+    LeaderboardScoresDownloaded_t = 1105 {
+        m_hSteamLeaderboard,
+        m_hSteamLeaderboardEntries,
+        m_cEntryCount,
+    }
+    LeaderboardScoreUploaded_t = 1106 {
+        m_bSuccess,
+        m_hSteamLeaderboard,
+        m_nScore,
+        m_bScoreChanged,
+        m_nGlobalRankNew,
+        m_nGlobalRankPrevious,
+    }
+    NumberOfCurrentPlayers_t = 1107 {
+        m_bSuccess,
+        m_cPlayers,
+    }
+    UserStatsUnloaded_t = 1108 {
+        m_steamIDUser,
+    }
+    UserAchievementIconFetched_t = 1109 {
+        m_nGameID,
+        m_rgchAchievementName,
+        m_bAchieved as bool,
+        m_nIconHandle,
+    }
+    GlobalAchievementPercentagesReady_t = 1110 {
+        m_nGameID,
+        m_eResult as u32,
+    }
+    LeaderboardUGCSet_t = 1111 {
+        m_eResult as u32,
+        m_hSteamLeaderboard,
+    }
+    PS3TrophiesInstalled_t = 1112 {
+        m_nGameID,
+        m_eResult as u32,
+        m_ulRequiredDiskSpace,
+    }
+    GlobalStatsReceived_t = 1112 {
+        m_nGameID,
+        m_eResult as u32,
+    }
+    DlcInstalled_t = 1005 {
+        m_nAppID,
+    }
+    RegisterActivationCodeResponse_t = 1008 {
+        m_eResult as u32,
+        m_unPackageRegistered,
+    }
+    NewUrlLaunchParameters_t = 1014 {}
+    AppProofOfPurchaseKeyResponse_t = 1021 {
+        m_eResult as u32,
+        m_nAppID,
+        m_cchKeyLength,
+        m_rgchKey,
+    }
+    FileDetailsResult_t = 1023 {
+        m_eResult as u32,
+        m_ulFileSize,
+        m_FileSHA,
+        m_unFlags,
+    }
+    TimedTrialStatus_t = 1030 {
+        m_unAppID,
+        m_bIsOffline as bool,
+        m_unSecondsAllowed,
+        m_unSecondsPlayed,
+    }
+    P2PSessionRequest_t = 1202 {
+        m_steamIDRemote,
+    }
+    P2PSessionConnectFail_t = 1203 {
+        m_steamIDRemote,
+        m_eP2PSessionError,
+    }
+    SocketStatusCallback_t = 1201 {
+        m_hSocket,
+        m_hListenSocket,
+        m_steamIDRemote,
+        m_eSNetSocketState,
+    }
+    ScreenshotReady_t = 2301 {
+        m_hLocal,
+        m_eResult as u32,
+    }
+    ScreenshotRequested_t = 2302 {}
+    PlaybackStatusHasChanged_t = 4001 {}
+    VolumeHasChanged_t = 4002 {
+        m_flNewVolume,
+    }
+    MusicPlayerRemoteWillActivate_t = 4101 {}
+    MusicPlayerRemoteWillDeactivate_t = 4102 {}
+    MusicPlayerRemoteToFront_t = 4103 {}
+    MusicPlayerWillQuit_t = 4104 {}
+    MusicPlayerWantsPlay_t = 4105 {}
+    MusicPlayerWantsPause_t = 4106 {}
+    MusicPlayerWantsPlayPrevious_t = 4107 {}
+    MusicPlayerWantsPlayNext_t = 4108 {}
+    MusicPlayerWantsShuffled_t = 4109 {
+        m_bShuffled as bool,
+    }
+    MusicPlayerWantsLooped_t = 4110 {
+        m_bLooped as bool,
+    }
+    MusicPlayerWantsVolume_t = 4011 {
+        m_flNewVolume,
+    }
+    MusicPlayerSelectsQueueEntry_t = 4012 {
+        nID,
+    }
+    MusicPlayerSelectsPlaylistEntry_t = 4013 {
+        nID,
+    }
+    MusicPlayerWantsPlayingRepeatStatus_t = 4114 {
+        m_nPlayingRepeatStatus,
+    }
+    HTTPRequestCompleted_t = 2101 {
+        m_hRequest,
+        m_ulContextValue,
+        m_bRequestSuccessful as bool,
+        m_eStatusCode as u32,
+        m_unBodySize,
+    }
+    HTTPRequestHeadersReceived_t = 2102 {
+        m_hRequest,
+        m_ulContextValue,
+    }
+    HTTPRequestDataReceived_t = 2103 {
+        m_hRequest,
+        m_ulContextValue,
+        m_cOffset,
+        m_cBytesReceived,
+    }
+    SteamInputDeviceConnected_t = 2801 {
+        m_ulConnectedDeviceHandle,
+    }
+    SteamInputDeviceDisconnected_t = 2802 {
+        m_ulDisconnectedDeviceHandle,
+    }
+    SteamInputConfigurationLoaded_t = 2803 {
+        m_unAppID,
+        m_ulDeviceHandle,
+        m_ulMappingCreator,
+        m_unMajorRevision,
+        m_unMinorRevision,
+        m_bUsesSteamInputAPI as bool,
+        m_bUsesGamepadAPI as bool,
+    }
+    SteamUGCQueryCompleted_t = 3401 {
+        m_handle,
+        m_eResult as u32,
+        m_unNumResultsReturned,
+        m_unTotalMatchingResults,
+        m_bCachedData as bool,
+        m_rgchNextCursor,
+    }
+    SteamUGCRequestUGCDetailsResult_t = 3402 {
+        m_details: {
+            m_nPublishedFileId,
+            m_eResult as u32,
+            m_eFileType as u32,
+            m_nCreatorAppID,
+            m_nConsumerAppID,
+            m_rgchTitle,
+            m_rgchDescription,
+            m_ulSteamIDOwner,
+            m_rtimeCreated,
+            m_rtimeUpdated,
+            m_rtimeAddedToUserList,
+            m_eVisibility as u32,
+            m_bBanned as bool,
+            m_bAcceptedForUse as bool,
+            m_bTagsTruncated as bool,
+            m_rgchTags,
+            m_hFile,
+            m_hPreviewFile,
+            m_pchFileName,
+            m_nFileSize,
+            m_nPreviewFileSize,
+            m_rgchURL,
+            m_unVotesUp,
+            m_unVotesDown,
+            m_flScore,
+            m_unNumChildren,
+        },
+        m_bCachedData as bool,
+    }
+    CreateItemResult_t = 3403 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_bUserNeedsToAcceptWorkshopLegalAgreement as bool,
+    }
+    SubmitItemUpdateResult_t = 3404 {
+        m_eResult as u32,
+        m_bUserNeedsToAcceptWorkshopLegalAgreement as bool,
+        m_nPublishedFileId,
+    }
+    ItemInstalled_t = 3405 {
+        m_unAppID,
+        m_nPublishedFileId,
+    }
+    DownloadItemResult_t = 3406 {
+        m_unAppID,
+        m_nPublishedFileId,
+        m_eResult as u32,
+    }
+    UserFavoriteItemsListChanged_t = 3407 {
+        m_nPublishedFileId,
+        m_eResult as u32,
+        m_bWasAddRequest as bool,
+    }
+    SetUserItemVoteResult_t = 3408 {
+        m_nPublishedFileId,
+        m_eResult as u32,
+        m_bVoteUp as bool,
+    }
+    GetUserItemVoteResult_t = 3409 {
+        m_nPublishedFileId,
+        m_eResult as u32,
+        m_bVotedUp as bool,
+        m_bVotedDown as bool,
+        m_bVoteSkipped as bool,
+    }
+    StartPlaytimeTrackingResult_t = 3410 {
+        m_eResult as u32,
+    }
+    StopPlaytimeTrackingResult_t = 3411 {
+        m_eResult as u32,
+    }
+    AddUGCDependencyResult_t = 3412 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_nChildPublishedFileId,
+    }
+    RemoveUGCDependencyResult_t = 3413 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_nChildPublishedFileId,
+    }
+    AddAppDependencyResult_t = 3414 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_nAppID,
+    }
+    RemoveAppDependencyResult_t = 3415 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_nAppID,
+    }
+    GetAppDependenciesResult_t = 3416 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+        m_rgAppIDs,
+        m_nNumAppDependencies,
+        m_nTotalNumAppDependencies,
+    }
+    DeleteItemResult_t = 3417 {
+        m_eResult as u32,
+        m_nPublishedFileId,
+    }
+    UserSubscribedItemsListChanged_t = 3418 {
+        m_nAppID,
+    }
+    WorkshopEULAStatus_t = 3420 {
+        m_eResult as u32,
+        m_nAppID,
+        m_unVersion,
+        m_rtAction,
+        m_bAccepted as bool,
+        m_bNeedsAction as bool,
+    }
+    SteamAppInstalled_t = 3901 {
+        m_nAppID,
+        m_iInstallFolderIndex,
+    }
+    SteamAppUninstalled_t = 3902 {
+        m_nAppID,
+        m_iInstallFolderIndex,
+    }
+    HTML_BrowserReady_t = 4501 {
+        unBrowserHandle,
+    }
+    HTML_NeedsPaint_t = 4502 {
+        unBrowserHandle,
+        pBGRA,
+        unWide,
+        unTall,
+        unUpdateX,
+        unUpdateY,
+        unUpdateWide,
+        unUpdateTall,
+        unScrollX,
+        unScrollY,
+        flPageScale,
+        unPageSerial,
+    }
+    HTML_StartRequest_t = 4503 {
+        unBrowserHandle,
+        pchURL,
+        pchTarget,
+        pchPostData,
+        bIsRedirect as bool,
+    }
+    HTML_CloseBrowser_t = 4504 {
+        unBrowserHandle,
+    }
+    HTML_URLChanged_t = 4505 {
+        unBrowserHandle,
+        pchURL,
+        pchPostData,
+        bIsRedirect as bool,
+        pchPageTitle,
+        bNewNavigation as bool,
+    }
+    HTML_FinishedRequest_t = 4506 {
+        unBrowserHandle,
+        pchURL,
+        pchPageTitle,
+    }
+    HTML_OpenLinkInNewTab_t = 4507 {
+        unBrowserHandle,
+        pchURL,
+    }
+    HTML_ChangedTitle_t = 4508 {
+        unBrowserHandle,
+        pchTitle,
+    }
+    HTML_SearchResults_t = 4509 {
+        unBrowserHandle,
+        unResults,
+        unCurrentMatch,
+    }
+    HTML_CanGoBackAndForward_t = 4510 {
+        unBrowserHandle,
+        bCanGoBack as bool,
+        bCanGoForward as bool,
+    }
+    HTML_HorizontalScroll_t = 4511 {
+        unBrowserHandle,
+        unScrollMax,
+        unScrollCurrent,
+        flPageScale,
+        bVisible as bool,
+        unPageSize,
+    }
+    HTML_VerticalScroll_t = 4512 {
+        unBrowserHandle,
+        unScrollMax,
+        unScrollCurrent,
+        flPageScale,
+        bVisible as bool,
+        unPageSize,
+    }
+    HTML_LinkAtPosition_t = 4513 {
+        unBrowserHandle,
+        x,
+        y,
+        pchURL,
+        bInput as bool,
+        bLiveLink as bool,
+    }
+    HTML_JSAlert_t = 4514 {
+        unBrowserHandle,
+        pchMessage,
+    }
+    HTML_JSConfirm_t = 4515 {
+        unBrowserHandle,
+        pchMessage,
+    }
+    HTML_FileOpenDialog_t = 4516 {
+        unBrowserHandle,
+        pchTitle,
+        pchInitialFile,
+    }
+    HTML_NewWindow_t = 4521 {
+        unBrowserHandle,
+        pchURL,
+        unX,
+        unY,
+        unWide,
+        unTall,
+        unNewWindow_BrowserHandle_IGNORE,
+    }
+    HTML_SetCursor_t = 4522 {
+        unBrowserHandle,
+        eMouseCursor,
+    }
+    HTML_StatusText_t = 4523 {
+        unBrowserHandle,
+        pchMsg,
+    }
+    HTML_ShowToolTip_t = 4524 {
+        unBrowserHandle,
+        pchMsg,
+    }
+    HTML_UpdateToolTip_t = 4525 {
+        unBrowserHandle,
+        pchMsg,
+    }
+    HTML_HideToolTip_t = 4526 {
+        unBrowserHandle,
+    }
+    HTML_BrowserRestarted_t = 4527 {
+        unBrowserHandle,
+        unOldBrowserHandle,
+    }
+    SteamInventoryResultReady_t = 4700 {
+        m_handle,
+        m_result as u32,
+    }
+    SteamInventoryFullUpdate_t = 4701 {
+        m_handle,
+    }
+    SteamInventoryDefinitionUpdate_t = 4702 {}
+    SteamInventoryEligiblePromoItemDefIDs_t = 4703 {
+        m_result as u32,
+        m_steamID,
+        m_numEligiblePromoItemDefs,
+        m_bCachedData as bool,
+    }
+    SteamInventoryStartPurchaseResult_t = 4704 {
+        m_result as u32,
+        m_ulOrderID,
+        m_ulTransID,
+    }
+    SteamInventoryRequestPricesResult_t = 4705 {
+        m_result as u32,
+        m_rgchCurrency,
+    }
+    GetVideoURLResult_t = 4611 {
+        m_eResult as u32,
+        m_unVideoAppID,
+        m_rgchURL,
+    }
+    GetOPFSettingsResult_t = 4624 {
+        m_eResult as u32,
+        m_unVideoAppID,
+    }
+    SteamParentalSettingsChanged_t = 5001 {}
+    SteamRemotePlaySessionConnected_t = 5701 {
+        m_unSessionID,
+    }
+    SteamRemotePlaySessionDisconnected_t = 5702 {
+        m_unSessionID,
+    }
+    SteamNetworkingMessagesSessionRequest_t = 1251 {
+        m_identityRemote,
+    }
+    SteamNetworkingMessagesSessionFailed_t = 1252 {
+        m_info: {
+            m_identityRemote,
+            m_nUserData,
+            m_hListenSocket,
+            m_addrRemote,
+            m_idPOPRemote,
+            m_idPOPRelay,
+            m_eState as i32,
+            m_eEndReason,
+            m_szEndDebug,
+            m_szConnectionDescription,
+            m_nFlags,
+        },
+    }
+    SteamNetConnectionStatusChangedCallback_t = 1221 {
+        m_hConn,
+        m_info: {
+            m_identityRemote,
+            m_nUserData,
+            m_hListenSocket,
+            m_addrRemote,
+            m_idPOPRemote,
+            m_idPOPRelay,
+            m_eState as i32,
+            m_eEndReason,
+            m_szEndDebug,
+            m_szConnectionDescription,
+            m_nFlags,
+        },
+        m_eOldState as i32,
+    }
+    SteamNetAuthenticationStatus_t = 1222 {
+        m_eAvail as i32,
+        m_debugMsg,
+    }
+    SteamRelayNetworkStatus_t = 1281 {
+        m_eAvail as i32,
+        m_bPingMeasurementInProgress,
+        m_eAvailNetworkConfig as i32,
+        m_eAvailAnyRelay as i32,
+        m_debugMsg,
+    }
+    GSClientApprove_t = 201 {
+        m_SteamID,
+        m_OwnerSteamID,
+    }
+    GSClientDeny_t = 202 {
+        m_SteamID,
+        m_eDenyReason as u32,
+        m_rgchOptionalText,
+    }
+    GSClientKick_t = 203 {
+        m_SteamID,
+        m_eDenyReason as u32,
+    }
+    GSClientAchievementStatus_t = 206 {
+        m_SteamID,
+        m_pchAchievement,
+        m_bUnlocked as bool,
+    }
+    GSPolicyResponse_t = 115 {
+        m_bSecure,
+    }
+    GSGameplayStats_t = 207 {
+        m_eResult as u32,
+        m_nRank,
+        m_unTotalConnects,
+        m_unTotalMinutesPlayed,
+    }
+    GSClientGroupStatus_t = 208 {
+        m_SteamIDUser,
+        m_SteamIDGroup,
+        m_bMember as bool,
+        m_bOfficer as bool,
+    }
+    GSReputation_t = 209 {
+        m_eResult as u32,
+        m_unReputationScore,
+        m_bBanned as bool,
+        m_unBannedIP,
+        m_usBannedPort,
+        m_ulBannedGameID,
+        m_unBanExpires,
+    }
+    AssociateWithClanResult_t = 210 {
+        m_eResult as u32,
+    }
+    ComputeNewPlayerCompatibilityResult_t = 211 {
+        m_eResult as u32,
+        m_cPlayersThatDontLikeCandidate,
+        m_cPlayersThatCandidateDoesntLike,
+        m_cClanPlayersThatDontLikeCandidate,
+        m_SteamIDCandidate,
+    }
+    GSStatsReceived_t = 1800 {
+        m_eResult as u32,
+        m_steamIDUser,
+    }
+    GSStatsStored_t = 1801 {
+        m_eResult as u32,
+        m_steamIDUser,
+    }
+    GSStatsUnloaded_t = 1108 {
+        m_steamIDUser,
+    }
+}
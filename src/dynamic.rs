@@ -0,0 +1,124 @@
+//! Exports for functions the original library has but the bindings don't
+//! describe, so konigsberg doesn't know their signatures (`dynamic_exports.rs`,
+//! generated by `konigsberg-gen` from a reference library).
+//!
+//! Each is a trampoline that jumps to the original function without touching
+//! its arguments. The first call loads plugins and resolves it through
+//! [`konigsberg_core::ffi::resolve_export`], so export hooks and the
+//! `on_missing` policy apply as usual; `default` makes it return zero.
+
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::c_void,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+#[repr(C)]
+struct Dynamic {
+    /// What the trampoline jumps to, null until resolved. Has to come first,
+    /// the trampolines read it from the start of the struct.
+    func: AtomicPtr<c_void>,
+    name: &'static str,
+    resolved: OnceCell<usize>,
+}
+
+impl Dynamic {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            func: AtomicPtr::new(std::ptr::null_mut()),
+            name,
+            resolved: OnceCell::new(),
+        }
+    }
+}
+
+macro_rules! dynamic_exports {
+    ($($name:ident),* $(,)?) => {$(
+        #[allow(non_snake_case)]
+        mod $name {
+            static ENTRY: super::Dynamic = super::Dynamic::new(stringify!($name));
+
+            #[unsafe(naked)]
+            #[no_mangle]
+            pub unsafe extern "C" fn $name() {
+                std::arch::naked_asm!(
+                    "mov r11, qword ptr [rip + {entry}]",
+                    "test r11, r11",
+                    "jz 2f",
+                    "jmp r11",
+                    "2:",
+                    "lea r11, [rip + {entry}]",
+                    "jmp {lazy}",
+                    entry = sym ENTRY,
+                    lazy = sym super::lazy,
+                )
+            }
+        }
+    )*};
+}
+
+/// Resolve the [`Dynamic`] in r11 and jump to it, keeping every register
+/// the System V ABI passes arguments in.
+#[unsafe(naked)]
+unsafe extern "C" fn lazy() {
+    std::arch::naked_asm!(
+        "push rdi",
+        "push rsi",
+        "push rdx",
+        "push rcx",
+        "push r8",
+        "push r9",
+        // vector register count for variadic functions
+        "push rax",
+        "sub rsp, 128",
+        "movdqu xmmword ptr [rsp], xmm0",
+        "movdqu xmmword ptr [rsp + 16], xmm1",
+        "movdqu xmmword ptr [rsp + 32], xmm2",
+        "movdqu xmmword ptr [rsp + 48], xmm3",
+        "movdqu xmmword ptr [rsp + 64], xmm4",
+        "movdqu xmmword ptr [rsp + 80], xmm5",
+        "movdqu xmmword ptr [rsp + 96], xmm6",
+        "movdqu xmmword ptr [rsp + 112], xmm7",
+        "mov rdi, r11",
+        "call {resolve}",
+        "mov r11, rax",
+        "movdqu xmm0, xmmword ptr [rsp]",
+        "movdqu xmm1, xmmword ptr [rsp + 16]",
+        "movdqu xmm2, xmmword ptr [rsp + 32]",
+        "movdqu xmm3, xmmword ptr [rsp + 48]",
+        "movdqu xmm4, xmmword ptr [rsp + 64]",
+        "movdqu xmm5, xmmword ptr [rsp + 80]",
+        "movdqu xmm6, xmmword ptr [rsp + 96]",
+        "movdqu xmm7, xmmword ptr [rsp + 112]",
+        "add rsp, 128",
+        "pop rax",
+        "pop r9",
+        "pop r8",
+        "pop rcx",
+        "pop rdx",
+        "pop rsi",
+        "pop rdi",
+        "jmp r11",
+        resolve = sym resolve,
+    )
+}
+
+extern "C" fn resolve(entry: &Dynamic) -> *mut c_void {
+    konigsberg_core::plugin::load();
+    let func = *entry.resolved.get_or_init(|| {
+        konigsberg_core::ffi::resolve_export::<unsafe extern "C" fn()>(entry.name, &[entry.name])
+            .map_or(missing as *const () as usize, |func| func as usize)
+    }) as *mut c_void;
+    entry.func.store(func, Ordering::Release);
+    func
+}
+
+unsafe extern "C" fn missing() -> usize {
+    0
+}
+
+#[cfg(feature = "rebuild-reexports")]
+include!(concat!(env!("OUT_DIR"), "/dynamic_exports.rs"));
+
+#[cfg(not(feature = "rebuild-reexports"))]
+include!("dynamic_exports.rs");
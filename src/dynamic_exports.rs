@@ -0,0 +1,21 @@
+#[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+dynamic_exports!(
+    GetHSteamPipe,
+    GetHSteamUser,
+    SteamAPI_InitAnonymousUser,
+    SteamAPI_SetBreakpadAppID,
+    SteamAPI_UseBreakpadCrashHandler,
+    SteamClient,
+    SteamGameServer_GetIPCCallCount,
+    SteamRealPath,
+);
+#[cfg(target_os = "macos")]
+dynamic_exports!(
+    GetHSteamPipe,
+    GetHSteamUser,
+    SteamAPI_InitAnonymousUser,
+    SteamAPI_SetBreakpadAppID,
+    SteamAPI_UseBreakpadCrashHandler,
+    SteamClient,
+    SteamGameServer_GetIPCCallCount,
+);
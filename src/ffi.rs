@@ -4,112 +4,57 @@
     improper_ctypes_definitions
 )]
 
-use once_cell::sync::OnceCell;
-use std::os::raw::{c_char, c_void};
+use konigsberg_core::{import, reexport};
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int, c_void},
+};
 use steamworks_sys::*;
 
-macro_rules! reexport {
-    (fn $name:ident($( $arg:ident : $type:ty ),*) $(-> $ret:ty)?) => {
-        #[no_mangle]
-        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
-            static CELL: OnceCell<libloading::Symbol<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = OnceCell::new();
-            let sym = CELL.get_or_init(|| {
-                sym(stringify!($name)).expect(&format!("failed to load symbol: {}", stringify!($name)))
-            });
-            sym($( $arg ),*)
-        }
-    };
-    ($link:literal, fn $name:ident($( $arg:ident : $type:ty ),*) $(-> $ret:ty)?) => {
-        #[export_name = $link]
-        #[no_mangle]
-        pub unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
-            static CELL: OnceCell<libloading::Symbol<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = OnceCell::new();
-            let sym = CELL.get_or_init(|| {
-                sym($link)
-                    .or_else(|_| sym(&$link[1..]))
-                    .or_else(|_| sym(stringify!($name)))
-                    .or_else(|_| sym(Box::leak(Box::new("\x01".to_owned() + $link)).as_str()))
-                    .expect(&format!("failed to load symbol: {}", $link))
-            });
-            sym($( $arg ),*)
-        }
-    };
-}
-macro_rules! import {
-    (fn $name:ident($( $arg:ident : $type:ty ),*) $(-> $ret:ty)?) => {
-        pub(crate) unsafe extern "C" fn $name($( $arg : $type),*) $(-> $ret)? {
-            static CELL: OnceCell<libloading::Symbol<unsafe extern "C" fn($($type),*) $(-> $ret)?>> = OnceCell::new();
-            let sym = CELL.get_or_init(|| {
-                sym(stringify!($name)).expect(&format!("failed to load symbol: {}", stringify!($name)))
-            });
-            sym($( $arg ),*)
-        }
-    };
-}
-
-unsafe fn lib() -> &'static libloading::Library {
-    static CELL: OnceCell<libloading::Library> = OnceCell::new();
-    CELL.get_or_init(|| {
-        unsafe {
-            #[cfg(target_os = "macos")]
-            {
-                libloading::Library::new("./libsteam_api.orig.dylib")
-                    .or_else(|_| libloading::Library::new("./libsteam_api_orig.dylib"))
-                    .or_else(|_| libloading::Library::new("libsteam_api.orig.dylib"))
-                    .or_else(|_| libloading::Library::new("libsteam_api_orig.dylib"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig.dylib"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig.dylib"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig"))
-            }
-            #[cfg(all(target_os = "windows", not(target_pointer_width = "64")))]
-            {
-                libloading::Library::new("./steam_api.orig.dll")
-                    .or_else(|_| libloading::Library::new("./steam_api_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig"))
-            }
-            #[cfg(all(target_os = "windows", target_pointer_width = "64"))]
-            {
-                libloading::Library::new("./steam_api64.orig.dll")
-                    .or_else(|_| libloading::Library::new("./steam_api64_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api64.orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api64_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api64.orig"))
-                    .or_else(|_| libloading::Library::new("steam_api64_orig"))
-                    .or_else(|_| libloading::Library::new("./steam_api.orig.dll"))
-                    .or_else(|_| libloading::Library::new("./steam_api_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig.dll"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig"))
-            }
-            #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
-            {
-                libloading::Library::new("./libsteam_api.orig.so")
-                    .or_else(|_| libloading::Library::new("./libsteam_api_orig.so"))
-                    .or_else(|_| libloading::Library::new("libsteam_api.orig.so"))
-                    .or_else(|_| libloading::Library::new("libsteam_api_orig.so"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig.so"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig.so"))
-                    .or_else(|_| libloading::Library::new("steam_api.orig"))
-                    .or_else(|_| libloading::Library::new("steam_api_orig"))
-            }
-        }
-        .expect("failed to load steam api lib")
-    })
-}
-
-unsafe fn sym<T>(name: &str) -> Result<libloading::Symbol<T>, libloading::Error> {
-    lib().get(name.as_bytes())
+/// Register `func` to be called in place of `symbol` if it's missing from
+/// the original library and its `on_missing` policy is `fallback`.
+///
+/// `func` must have the same signature as `symbol`, and must be registered
+/// before `symbol` is first called. Returns false if either argument is null.
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_register_fallback(
+    symbol: *const c_char,
+    func: *const c_void,
+) -> bool {
+    if symbol.is_null() || func.is_null() {
+        return false;
+    }
+    konigsberg_core::ffi::register_fallback(&CStr::from_ptr(symbol).to_string_lossy(), func);
+    true
 }
 
-import!(fn SteamInternal_FindOrCreateUserInterface(hSteamUser: HSteamUser, pszVersion: *const c_char) -> *mut c_void);
+import!(fn SteamInternal_FindOrCreateUserInterface(hSteamUser: HSteamUser, #[str] pszVersion: *const c_char) -> *mut c_void);
+import!(fn SteamAPI_Init() -> bool);
+// not in the bindings, but still exported by the library
+import!(fn SteamAPI_InitSafe() -> bool);
+import!(fn SteamInternal_GameServer_Init(#[ipv4] unIP: uint32, usLegacySteamPort: uint16, usGamePort: uint16, usQueryPort: uint16, eServerMode: EServerMode, #[str] pchVersionString: *const c_char) -> bool);
+import!(fn SteamGameServer_InitSafe(#[ipv4] unIP: uint32, usSteamPort: uint16, usGamePort: uint16, usQueryPort: uint16, eServerMode: EServerMode, #[str] pchVersionString: *const c_char) -> bool);
+import!(fn SteamAPI_ISteamUser_GetAuthSessionTicket(self_: *mut ISteamUser, #[out_buf(cbMaxTicket)] pTicket: *mut c_void, cbMaxTicket: c_int, #[out] pcbTicket: *mut uint32) -> HAuthTicket);
+import!(fn SteamAPI_ISteamUser_CancelAuthTicket(self_: *mut ISteamUser, hAuthTicket: HAuthTicket));
+import!(fn SteamAPI_ISteamUser_BeginAuthSession(self_: *mut ISteamUser, #[buf(cbAuthTicket)] pAuthTicket: *const c_void, cbAuthTicket: c_int, #[steam_id] steamID: uint64_steamid) -> EBeginAuthSessionResult);
+import!(fn SteamAPI_ISteamUser_EndAuthSession(self_: *mut ISteamUser, #[steam_id] steamID: uint64_steamid));
+import!(fn SteamAPI_ISteamGameServer_GetAuthSessionTicket(self_: *mut ISteamGameServer, #[out_buf(cbMaxTicket)] pTicket: *mut c_void, cbMaxTicket: c_int, #[out] pcbTicket: *mut uint32) -> HAuthTicket);
+import!(fn SteamAPI_ISteamGameServer_CancelAuthTicket(self_: *mut ISteamGameServer, hAuthTicket: HAuthTicket));
+import!(fn SteamAPI_ISteamGameServer_BeginAuthSession(self_: *mut ISteamGameServer, #[buf(cbAuthTicket)] pAuthTicket: *const c_void, cbAuthTicket: c_int, #[steam_id] steamID: uint64_steamid) -> EBeginAuthSessionResult);
+import!(fn SteamAPI_ISteamGameServer_EndAuthSession(self_: *mut ISteamGameServer, #[steam_id] steamID: uint64_steamid));
+import!(fn SteamAPI_Shutdown());
+import!(fn SteamGameServer_Shutdown());
 import!(fn SteamAPI_SteamApps_v008() -> *mut ISteamApps);
 // future proof?
 import!(fn SteamAPI_SteamApps_v009() -> *mut ISteamApps);
+import!(fn SteamAPI_RegisterCallback(pCallback: *mut CCallbackBase, iCallback: c_int));
+import!(fn SteamAPI_UnregisterCallback(pCallback: *mut CCallbackBase));
+import!(fn SteamAPI_RegisterCallResult(pCallback: *mut CCallbackBase, hAPICall: SteamAPICall_t));
+import!(fn SteamAPI_UnregisterCallResult(pCallback: *mut CCallbackBase, hAPICall: SteamAPICall_t));
+import!(fn SteamAPI_ManualDispatch_GetNextCallback(hSteamPipe: HSteamPipe, pCallbackMsg: *mut CallbackMsg_t) -> bool);
+import!(fn SteamAPI_ManualDispatch_FreeLastCallback(hSteamPipe: HSteamPipe));
+import!(fn SteamAPI_ManualDispatch_GetAPICallResult(hSteamPipe: HSteamPipe, hSteamAPICall: SteamAPICall_t, #[out_buf(cubCallback)] pCallback: *mut c_void, cubCallback: c_int, iCallbackExpected: c_int, #[out] pbFailed: *mut bool) -> bool);
+import!(fn SteamAPI_ISteamUtils_GetAPICallResult(self_: *mut ISteamUtils, hSteamAPICall: SteamAPICall_t, #[out_buf(cubCallback)] pCallback: *mut c_void, cubCallback: c_int, iCallbackExpected: c_int, #[out] pbFailed: *mut bool) -> bool);
 
 #[cfg(feature = "rebuild-reexports")]
 include!(concat!(env!("OUT_DIR"), "/reexports.rs"));
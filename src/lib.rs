@@ -1,482 +1,277 @@
-use once_cell::sync::OnceCell;
+//! A drop-in `steam_api` that forwards everything to the original library,
+//! built on [`konigsberg_core`].
+
+use konigsberg_core::{
+    callbacks, calls,
+    hooks::{self, HookFn, VtableHook},
+    interface::{Interface, InterfaceKind},
+    inventory, lint, threads, vtable_method,
+};
 use std::{
-    collections::{HashMap, HashSet},
-    ffi::{c_char, c_void, CStr},
-    sync::{Mutex, RwLock},
+    ffi::{c_char, c_int, c_void, CStr, OsStr},
+    sync::atomic::{AtomicBool, Ordering},
 };
 use steamworks_sys::{
-    AppId_t, CSteamID, EUserHasLicenseForAppResult, HSteamPipe, HSteamUser, ISteamApps,
+    AppId_t, CCallbackBase, CSteamID, CallbackMsg_t, EBeginAuthSessionResult, EServerMode,
+    EUserHasLicenseForAppResult, HAuthTicket, HSteamPipe, HSteamUser, ISteamApps, ISteamGameServer,
+    ISteamUser, ISteamUtils, SteamAPICall_t,
 };
 
+#[cfg(all(unix, target_arch = "x86_64"))]
+mod dynamic;
 mod ffi;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-enum Interface {
-    Apps(u8),
-    Client(u8),
-    User(u8),
+/// Still reachable from here for crates that depend on konigsberg as a
+/// library, as it was before the split into `konigsberg-core`.
+pub use konigsberg_core::interface;
+
+/// Name and fields of every callback struct, generated from the bindings.
+#[allow(non_camel_case_types)]
+mod callback_types {
+    use konigsberg_core::{callback_types, callbacks::CallbackType};
+    use steamworks_sys::*;
+
+    #[cfg(feature = "rebuild-reexports")]
+    pub(crate) static ALL: &[CallbackType] =
+        include!(concat!(env!("OUT_DIR"), "/callback_types.rs"));
+
+    #[cfg(not(feature = "rebuild-reexports"))]
+    pub(crate) static ALL: &[CallbackType] = include!("callback_types.rs");
+}
+
+/// Vtable slot of each interface method, generated from the bindings.
+#[allow(non_snake_case, non_upper_case_globals, dead_code)]
+mod slots {
+    #[cfg(feature = "rebuild-reexports")]
+    include!(concat!(env!("OUT_DIR"), "/vtable_slots.rs"));
+
+    #[cfg(not(feature = "rebuild-reexports"))]
+    include!("vtable_slots.rs");
 }
 
-fn str_ver(ver: &str) -> Option<u8> {
-    if ver.len() < 3 {
-        return None;
+vtable_method! {
+    type AppFn = fn(AppId_t) -> bool;
+}
+
+/// Still calls whatever was in the slot before, so anything else hooking it
+/// sees the call, but the app is owned regardless.
+unsafe fn app_common(this: *mut c_void, app_id: AppId_t, method: &str) -> bool {
+    if let Some(func) = hooks::original::<AppFn>(this, method) {
+        vtable_method!(call func(this, app_id));
     }
-    ver[ver.len() - 3..].parse::<u8>().ok()
-}
-
-unsafe fn parse_ver(ver: *const c_char) -> Option<Interface> {
-    let ver = CStr::from_ptr(ver).to_str().ok()?;
-    if ver.starts_with("STEAMAPPS_INTERFACE_VERSION") {
-        Some(Interface::Apps(str_ver(ver)?))
-    } else if ver.starts_with("SteamUser") {
-        Some(Interface::User(str_ver(ver)?))
-    } else if ver.starts_with("SteamClient") {
-        Some(Interface::Client(str_ver(ver)?))
-    } else {
-        None
+    true
+}
+
+vtable_method! {
+    unsafe fn b_is_subscribed_app(this: *mut c_void, app_id: AppId_t) -> bool {
+        app_common(this, app_id, "ISteamApps::BIsSubscribedApp")
+    }
+}
+vtable_method! {
+    unsafe fn b_is_dlc_installed(this: *mut c_void, app_id: AppId_t) -> bool {
+        app_common(this, app_id, "ISteamApps::BIsDlcInstalled")
     }
 }
 
-#[allow(clippy::missing_safety_doc)]
-#[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-unsafe extern "C" fn b_is_dlc_installed(_this: *mut c_void, _app_id: AppId_t) -> bool {
-    true
+vtable_method! {
+    type LicenseFn = fn(CSteamID, AppId_t) -> EUserHasLicenseForAppResult;
 }
-#[allow(clippy::missing_safety_doc)]
-#[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-unsafe extern "fastcall" fn b_is_dlc_installed(
-    _this: *mut c_void,
-    _edx: usize,
-    _app_id: AppId_t,
-) -> bool {
-    true
+
+vtable_method! {
+    unsafe fn user_has_license_for_app(
+        this: *mut c_void,
+        steam_id: CSteamID,
+        app_id: AppId_t,
+    ) -> EUserHasLicenseForAppResult {
+        if let Some(func) = hooks::original::<LicenseFn>(this, "ISteamUser::UserHasLicenseForApp") {
+            vtable_method!(call func(this, steam_id, app_id));
+        }
+        EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense
+    }
 }
-#[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-#[allow(clippy::missing_safety_doc, improper_ctypes_definitions)]
-unsafe extern "C" fn user_has_license_for_app(
-    _this: *mut c_void,
-    _steam_id: CSteamID,
-    _app_id: AppId_t,
-) -> EUserHasLicenseForAppResult {
-    EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense
-}
-#[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-#[allow(clippy::missing_safety_doc, improper_ctypes_definitions)]
-unsafe extern "fastcall" fn user_has_license_for_app(
-    _this: *mut c_void,
-    _edx: usize,
-    _steam_id: CSteamID,
-    _app_id: AppId_t,
-) -> EUserHasLicenseForAppResult {
-    EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense
-}
-
-#[derive(Copy, Clone)]
-struct OrigSteamClientFns {
-    #[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-    generic: Option<
-        unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void,
-    >,
-    #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-    generic: Option<
-        unsafe extern "fastcall" fn(
-            *mut c_void,
-            usize,
-            HSteamUser,
-            HSteamPipe,
-            *const c_char,
-        ) -> *mut c_void,
-    >,
-    #[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-    apps: unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void,
-    #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-    apps: unsafe extern "fastcall" fn(
-        *mut c_void,
-        usize,
-        HSteamUser,
-        HSteamPipe,
-        *const c_char,
-    ) -> *mut c_void,
-    #[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-    user: unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void,
-    #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-    user: unsafe extern "fastcall" fn(
-        *mut c_void,
-        usize,
-        HSteamUser,
-        HSteamPipe,
-        *const c_char,
-    ) -> *mut c_void,
-}
-
-#[derive(Copy, Clone)]
-enum SteamClientFn {
-    Generic,
-    Apps,
-    User,
-}
-
-#[allow(clippy::type_complexity)]
-static ORIG_CLIENT_FNS: OnceCell<RwLock<HashMap<usize, OrigSteamClientFns>>> = OnceCell::new();
 
-unsafe fn steam_client_common(
-    this: *mut c_void,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-    field: SteamClientFn,
-) -> *mut c_void {
-    patch_ptr(
-        parse_ver(ver),
-        ORIG_CLIENT_FNS
-            .get()
-            .and_then(|rwlock| {
-                rwlock.read().ok().and_then(|lock| {
-                    lock.get(&(*(this as *mut usize)))
-                        .and_then(|val| match field {
-                            SteamClientFn::Apps => Some(val.apps),
-                            SteamClientFn::User => Some(val.user),
-                            SteamClientFn::Generic => val.generic,
-                        })
-                })
-            })
-            .map(|func| {
-                func(
-                    this,
-                    #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                    0,
-                    steam_user,
-                    steam_pipe,
-                    ver,
-                )
-            })
-            .unwrap_or(std::ptr::null_mut()),
-    )
+vtable_method! {
+    type GetAuthSessionTicketFn = fn(*mut c_void, c_int, *mut u32) -> HAuthTicket;
+}
+vtable_method! {
+    type BeginAuthSessionFn = fn(*const c_void, c_int, CSteamID) -> EBeginAuthSessionResult;
+}
+vtable_method! {
+    type EndAuthSessionFn = fn(CSteamID);
+}
+vtable_method! {
+    type CancelAuthTicketFn = fn(HAuthTicket);
 }
 
-#[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "C" fn get_i_steam_generic_interface(
-    this: *mut c_void,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::Generic)
+// Only hooked with lint checks on, for games that use `ISteamUser` directly
+// rather than through the flat API.
+vtable_method! {
+    unsafe fn get_auth_session_ticket(
+        this: *mut c_void,
+        ticket: *mut c_void,
+        max: c_int,
+        len: *mut u32,
+    ) -> HAuthTicket {
+        let Some(func) =
+            hooks::original::<GetAuthSessionTicketFn>(this, "ISteamUser::GetAuthSessionTicket")
+        else {
+            return steamworks_sys::k_HAuthTicketInvalid;
+        };
+        let ret = vtable_method!(call func(this, ticket, max, len));
+        lint::auth_ticket(false, ret);
+        ret
+    }
 }
-#[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "fastcall" fn get_i_steam_generic_interface(
-    this: *mut c_void,
-    _edx: usize,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::Generic)
+vtable_method! {
+    unsafe fn begin_auth_session(
+        this: *mut c_void,
+        ticket: *const c_void,
+        len: c_int,
+        steam_id: CSteamID,
+    ) -> EBeginAuthSessionResult {
+        let Some(func) = hooks::original::<BeginAuthSessionFn>(this, "ISteamUser::BeginAuthSession")
+        else {
+            return EBeginAuthSessionResult::k_EBeginAuthSessionResultInvalidTicket;
+        };
+        let ret = vtable_method!(call func(this, ticket, len, steam_id));
+        lint::begin_auth_session(false, steam_id.m_steamid.m_unAll64Bits, ret);
+        ret
+    }
 }
-#[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "C" fn get_i_steam_user(
-    this: *mut c_void,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::User)
+vtable_method! {
+    unsafe fn end_auth_session(this: *mut c_void, steam_id: CSteamID) {
+        if let Some(func) = hooks::original::<EndAuthSessionFn>(this, "ISteamUser::EndAuthSession") {
+            vtable_method!(call func(this, steam_id));
+        }
+        lint::end_auth_session(false, steam_id.m_steamid.m_unAll64Bits);
+    }
 }
-#[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "fastcall" fn get_i_steam_user(
-    this: *mut c_void,
-    _edx: usize,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::User)
+vtable_method! {
+    unsafe fn cancel_auth_ticket(this: *mut c_void, ticket: HAuthTicket) {
+        if let Some(func) = hooks::original::<CancelAuthTicketFn>(this, "ISteamUser::CancelAuthTicket") {
+            vtable_method!(call func(this, ticket));
+        }
+        lint::cancel_auth_ticket(false, ticket);
+    }
 }
-#[cfg(any(not(target_os = "windows"), not(target_pointer_width = "32")))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "C" fn get_i_steam_apps(
-    this: *mut c_void,
-    steam_user: HSteamUser,
-    steam_pipe: HSteamPipe,
-    ver: *const c_char,
-) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::Apps)
+
+vtable_method! {
+    type GetInterfaceFn = fn(HSteamUser, HSteamPipe, *const c_char) -> *mut c_void;
 }
-#[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-#[allow(clippy::missing_safety_doc)]
-unsafe extern "fastcall" fn get_i_steam_apps(
+
+unsafe fn steam_client_common(
     this: *mut c_void,
-    _edx: usize,
     steam_user: HSteamUser,
     steam_pipe: HSteamPipe,
     ver: *const c_char,
+    method: &'static str,
 ) -> *mut c_void {
-    steam_client_common(this, steam_user, steam_pipe, ver, SteamClientFn::Apps)
+    inventory::record_ptr(ver, method);
+    patch_ptr(
+        Interface::from_ptr(ver),
+        hooks::original::<GetInterfaceFn>(this, method)
+            .map(|func| vtable_method!(call func(this, steam_user, steam_pipe, ver)))
+            .unwrap_or(std::ptr::null_mut()),
+    )
 }
 
-unsafe fn patch2(object: *mut c_void, offsets: &[(usize, *mut c_void)], pre_hook: impl FnOnce()) {
-    static PATCH_DONE: OnceCell<Mutex<HashSet<usize>>> = OnceCell::new();
-
-    if offsets.is_empty() {
-        return;
+vtable_method! {
+    unsafe fn get_i_steam_generic_interface(
+        this: *mut c_void,
+        steam_user: HSteamUser,
+        steam_pipe: HSteamPipe,
+        ver: *const c_char,
+    ) -> *mut c_void {
+        steam_client_common(
+            this,
+            steam_user,
+            steam_pipe,
+            ver,
+            "ISteamClient::GetISteamGenericInterface",
+        )
     }
-
-    let patch_done = PATCH_DONE.get_or_init(Default::default);
-    let vtable = *(object as *mut *mut usize);
-    let mut lock = patch_done.lock().unwrap();
-    if lock.contains(&(vtable as usize)) {
-        return;
+}
+vtable_method! {
+    unsafe fn get_i_steam_user(
+        this: *mut c_void,
+        steam_user: HSteamUser,
+        steam_pipe: HSteamPipe,
+        ver: *const c_char,
+    ) -> *mut c_void {
+        steam_client_common(this, steam_user, steam_pipe, ver, "ISteamClient::GetISteamUser")
     }
-
-    pre_hook();
-
-    for (offset, func) in offsets.iter().copied() {
-        let p_func = vtable.add(offset) as *mut *mut c_void;
-        let _handle = region::protect_with_handle(
-            p_func,
-            std::mem::size_of::<usize>(),
-            region::Protection::READ_WRITE_EXECUTE,
-        )
-        .expect("mprotect failed");
-        *p_func = func;
+}
+vtable_method! {
+    unsafe fn get_i_steam_apps(
+        this: *mut c_void,
+        steam_user: HSteamUser,
+        steam_pipe: HSteamPipe,
+        ver: *const c_char,
+    ) -> *mut c_void {
+        steam_client_common(this, steam_user, steam_pipe, ver, "ISteamClient::GetISteamApps")
     }
-
-    lock.insert(vtable as usize);
 }
-unsafe fn patch(object: *mut c_void, offsets: &[(usize, *mut c_void)]) {
-    patch2(object, offsets, || {});
+
+macro_rules! hook {
+    ($interface:ident, $versions:expr, $slot:expr, $method:literal, $func:ident) => {
+        VtableHook {
+            interface: InterfaceKind::$interface,
+            versions: $versions,
+            slot: $slot,
+            method: $method,
+            func: HookFn($func as *const c_void),
+        }
+    };
 }
 
+/// Every vtable slot konigsberg replaces, by interface version. Slots for the
+/// versions in the bindings come from [`slots`], older ones were worked out
+/// by hand.
+#[rustfmt::skip]
+static VTABLE_HOOKS: &[VtableHook] = &[
+    hook!(Apps, 2..=slots::ISteamApps::VERSION - 1, 6, "ISteamApps::BIsSubscribedApp", b_is_subscribed_app),
+    hook!(Apps, slots::ISteamApps::VERSION..=u8::MAX, slots::ISteamApps::BIsSubscribedApp, "ISteamApps::BIsSubscribedApp", b_is_subscribed_app),
+    hook!(Apps, 3..=slots::ISteamApps::VERSION - 1, 7, "ISteamApps::BIsDlcInstalled", b_is_dlc_installed),
+    hook!(Apps, slots::ISteamApps::VERSION..=u8::MAX, slots::ISteamApps::BIsDlcInstalled, "ISteamApps::BIsDlcInstalled", b_is_dlc_installed),
+    hook!(User, 12..=12, 15, "ISteamUser::UserHasLicenseForApp", user_has_license_for_app),
+    hook!(User, 13..=14, 16, "ISteamUser::UserHasLicenseForApp", user_has_license_for_app),
+    hook!(User, 15..=slots::ISteamUser::VERSION - 1, 17, "ISteamUser::UserHasLicenseForApp", user_has_license_for_app),
+    hook!(User, slots::ISteamUser::VERSION..=u8::MAX, slots::ISteamUser::UserHasLicenseForApp, "ISteamUser::UserHasLicenseForApp", user_has_license_for_app),
+    hook!(Client, 6..=6, 6, "ISteamClient::GetISteamUser", get_i_steam_user),
+    hook!(Client, 7..=slots::ISteamClient::VERSION - 1, 5, "ISteamClient::GetISteamUser", get_i_steam_user),
+    hook!(Client, slots::ISteamClient::VERSION..=u8::MAX, slots::ISteamClient::GetISteamUser, "ISteamClient::GetISteamUser", get_i_steam_user),
+    hook!(Client, 6..=6, 16, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, 7..=7, 18, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, 8..=8, 15, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, 9..=11, 16, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, 12..=slots::ISteamClient::VERSION - 1, 15, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, slots::ISteamClient::VERSION..=u8::MAX, slots::ISteamClient::GetISteamApps, "ISteamClient::GetISteamApps", get_i_steam_apps),
+    hook!(Client, 7..=7, 14, "ISteamClient::GetISteamGenericInterface", get_i_steam_generic_interface),
+    hook!(Client, 8..=11, 13, "ISteamClient::GetISteamGenericInterface", get_i_steam_generic_interface),
+    hook!(Client, 12..=slots::ISteamClient::VERSION - 1, 12, "ISteamClient::GetISteamGenericInterface", get_i_steam_generic_interface),
+    hook!(Client, slots::ISteamClient::VERSION..=u8::MAX, slots::ISteamClient::GetISteamGenericInterface, "ISteamClient::GetISteamGenericInterface", get_i_steam_generic_interface),
+];
+
+/// Hooks only registered with [`lint`] checks on. Auth methods moved around
+/// too much between versions to bother with older ones.
+#[rustfmt::skip]
+static LINT_HOOKS: &[VtableHook] = &[
+    hook!(User, slots::ISteamUser::VERSION..=slots::ISteamUser::VERSION, slots::ISteamUser::GetAuthSessionTicket, "ISteamUser::GetAuthSessionTicket", get_auth_session_ticket),
+    hook!(User, slots::ISteamUser::VERSION..=slots::ISteamUser::VERSION, slots::ISteamUser::BeginAuthSession, "ISteamUser::BeginAuthSession", begin_auth_session),
+    hook!(User, slots::ISteamUser::VERSION..=slots::ISteamUser::VERSION, slots::ISteamUser::EndAuthSession, "ISteamUser::EndAuthSession", end_auth_session),
+    hook!(User, slots::ISteamUser::VERSION..=slots::ISteamUser::VERSION, slots::ISteamUser::CancelAuthTicket, "ISteamUser::CancelAuthTicket", cancel_auth_ticket),
+];
+
 unsafe fn patch_ptr(ver: Option<Interface>, ret: *mut c_void) -> *mut c_void {
-    if !ret.is_null() {
-        if let Some(ver) = ver {
-            match ver {
-                Interface::Apps(..=1) => {}
-                Interface::Apps(n @ 2..) => {
-                    let mut patches = vec![
-                        // this is issubscribedapp, same sig as isdlcinstalled
-                        (
-                            6,
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                b_is_dlc_installed
-                                    as unsafe extern "C" fn(*mut c_void, AppId_t) -> bool,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                b_is_dlc_installed
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        AppId_t,
-                                    )
-                                        -> bool,
-                            )),
-                        ),
-                    ];
-                    if n >= 3 {
-                        patches.push((
-                            7,
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                b_is_dlc_installed
-                                    as unsafe extern "C" fn(*mut c_void, AppId_t) -> bool,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                b_is_dlc_installed
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        AppId_t,
-                                    )
-                                        -> bool,
-                            )),
-                        ));
-                    }
-                    patch(ret, &patches);
-                }
-                Interface::User(..=11) => {}
-                Interface::User(n) => {
-                    patch(
-                        ret,
-                        &[(
-                            match n {
-                                ..=12 => 15,
-                                13..=14 => 16,
-                                15.. => 17,
-                            },
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                user_has_license_for_app
-                                    as unsafe extern "C" fn(
-                                        *mut c_void,
-                                        CSteamID,
-                                        AppId_t,
-                                    )
-                                        -> EUserHasLicenseForAppResult,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                user_has_license_for_app
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        CSteamID,
-                                        AppId_t,
-                                    )
-                                        -> EUserHasLicenseForAppResult,
-                            )),
-                        )],
-                    );
-                }
-                Interface::Client(..=5) => {}
-                Interface::Client(n) => {
-                    let offset_apps = match n {
-                        ..=6 => 16,
-                        7 => 18,
-                        8 => 15,
-                        9..=11 => 16,
-                        12.. => 15,
-                    };
-                    let offset_user = match n {
-                        ..=6 => 6,
-                        7.. => 5,
-                    };
-                    let offset_generic = match n {
-                        ..=6 => None,
-                        7 => Some(14),
-                        8..=11 => Some(13),
-                        12.. => Some(12),
-                    };
-
-                    let mut patches = vec![
-                        (
-                            offset_apps,
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                get_i_steam_apps
-                                    as unsafe extern "C" fn(
-                                        *mut c_void,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                get_i_steam_apps
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                        ),
-                        (
-                            offset_user,
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                get_i_steam_user
-                                    as unsafe extern "C" fn(
-                                        *mut c_void,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                get_i_steam_user
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                        ),
-                    ];
-                    if let Some(offset_generic) = offset_generic {
-                        patches.push((
-                            offset_generic,
-                            #[cfg(any(
-                                not(target_os = "windows"),
-                                not(target_pointer_width = "32")
-                            ))]
-                            std::mem::transmute(Some(
-                                get_i_steam_generic_interface
-                                    as unsafe extern "C" fn(
-                                        *mut c_void,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                            #[cfg(all(target_os = "windows", target_pointer_width = "32"))]
-                            std::mem::transmute(Some(
-                                get_i_steam_generic_interface
-                                    as unsafe extern "fastcall" fn(
-                                        *mut c_void,
-                                        usize,
-                                        HSteamUser,
-                                        HSteamPipe,
-                                        *const c_char,
-                                    )
-                                        -> *mut c_void,
-                            )),
-                        ));
-                    }
-                    patch2(ret, &patches, || {
-                        let vtable = *(ret as *mut *mut usize);
-                        let mut lock = ORIG_CLIENT_FNS
-                            .get_or_init(Default::default)
-                            .write()
-                            .unwrap();
-                        let get = |ofs| vtable.add(ofs);
-                        lock.insert(
-                            vtable as usize,
-                            OrigSteamClientFns {
-                                generic: offset_generic
-                                    .map(get)
-                                    .map(|x| std::mem::transmute::<_, Option<_>>(x).unwrap()),
-                                apps: std::mem::transmute::<_, Option<_>>(get(offset_apps))
-                                    .unwrap(),
-                                user: std::mem::transmute::<_, Option<_>>(get(offset_user))
-                                    .unwrap(),
-                            },
-                        );
-                    });
-                }
-            }
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| {
+        hooks::register(VTABLE_HOOKS.iter().cloned());
+        if lint::enabled() {
+            hooks::register(LINT_HOOKS.iter().cloned());
         }
+    });
+    if let (false, Some(ver)) = (ret.is_null(), ver) {
+        hooks::patch(ver, ret);
     }
     ret
 }
@@ -487,8 +282,9 @@ pub unsafe extern "C" fn SteamInternal_FindOrCreateUserInterface(
     user: HSteamUser,
     ver: *const c_char,
 ) -> *mut c_void {
+    inventory::record_ptr(ver, "SteamInternal_FindOrCreateUserInterface");
     patch_ptr(
-        parse_ver(ver),
+        Interface::from_ptr(ver),
         ffi::SteamInternal_FindOrCreateUserInterface(user, ver),
     )
 }
@@ -496,16 +292,390 @@ pub unsafe extern "C" fn SteamInternal_FindOrCreateUserInterface(
 #[allow(non_snake_case, clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn SteamAPI_SteamApps_v008() -> *mut ISteamApps {
-    patch_ptr(
-        Some(Interface::Apps(8)),
-        ffi::SteamAPI_SteamApps_v008() as *mut c_void,
-    ) as *mut ISteamApps
+    let ver = Interface::new(InterfaceKind::Apps, 8);
+    inventory::record(ver, "SteamAPI_SteamApps_v008");
+    patch_ptr(Some(ver), ffi::SteamAPI_SteamApps_v008() as *mut c_void) as *mut ISteamApps
 }
 #[allow(non_snake_case, clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn SteamAPI_SteamApps_v009() -> *mut ISteamApps {
-    patch_ptr(
-        Some(Interface::Apps(9)),
-        ffi::SteamAPI_SteamApps_v009() as *mut c_void,
-    ) as *mut ISteamApps
+    let ver = Interface::new(InterfaceKind::Apps, 9);
+    inventory::record(ver, "SteamAPI_SteamApps_v009");
+    patch_ptr(Some(ver), ffi::SteamAPI_SteamApps_v009() as *mut c_void) as *mut ISteamApps
+}
+
+/// Whether the client is initialized, so `SteamGameServer_Shutdown` knows
+/// if its interfaces may still be in use.
+static CLIENT: AtomicBool = AtomicBool::new(false);
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_Init() -> bool {
+    let ret = ffi::SteamAPI_Init();
+    CLIENT.fetch_or(ret, Ordering::Relaxed);
+    lint::init(false, ret);
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_InitSafe() -> bool {
+    let ret = ffi::SteamAPI_InitSafe();
+    CLIENT.fetch_or(ret, Ordering::Relaxed);
+    lint::init(false, ret);
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamInternal_GameServer_Init(
+    ip: u32,
+    legacy_port: u16,
+    game_port: u16,
+    query_port: u16,
+    mode: EServerMode,
+    version: *const c_char,
+) -> bool {
+    let ret =
+        ffi::SteamInternal_GameServer_Init(ip, legacy_port, game_port, query_port, mode, version);
+    lint::init(true, ret);
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamGameServer_InitSafe(
+    ip: u32,
+    steam_port: u16,
+    game_port: u16,
+    query_port: u16,
+    mode: EServerMode,
+    version: *const c_char,
+) -> bool {
+    let ret = ffi::SteamGameServer_InitSafe(ip, steam_port, game_port, query_port, mode, version);
+    lint::init(true, ret);
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_GetAuthSessionTicket(
+    user: *mut ISteamUser,
+    ticket: *mut c_void,
+    max: c_int,
+    len: *mut u32,
+) -> HAuthTicket {
+    let ret = ffi::SteamAPI_ISteamUser_GetAuthSessionTicket(user, ticket, max, len);
+    lint::auth_ticket(false, ret);
+    ret
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_CancelAuthTicket(
+    user: *mut ISteamUser,
+    ticket: HAuthTicket,
+) {
+    ffi::SteamAPI_ISteamUser_CancelAuthTicket(user, ticket);
+    lint::cancel_auth_ticket(false, ticket);
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_BeginAuthSession(
+    user: *mut ISteamUser,
+    ticket: *const c_void,
+    len: c_int,
+    steam_id: u64,
+) -> EBeginAuthSessionResult {
+    let ret = ffi::SteamAPI_ISteamUser_BeginAuthSession(user, ticket, len, steam_id);
+    lint::begin_auth_session(false, steam_id, ret);
+    ret
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUser_EndAuthSession(user: *mut ISteamUser, steam_id: u64) {
+    ffi::SteamAPI_ISteamUser_EndAuthSession(user, steam_id);
+    lint::end_auth_session(false, steam_id);
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_GetAuthSessionTicket(
+    server: *mut ISteamGameServer,
+    ticket: *mut c_void,
+    max: c_int,
+    len: *mut u32,
+) -> HAuthTicket {
+    let ret = ffi::SteamAPI_ISteamGameServer_GetAuthSessionTicket(server, ticket, max, len);
+    lint::auth_ticket(true, ret);
+    ret
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_CancelAuthTicket(
+    server: *mut ISteamGameServer,
+    ticket: HAuthTicket,
+) {
+    ffi::SteamAPI_ISteamGameServer_CancelAuthTicket(server, ticket);
+    lint::cancel_auth_ticket(true, ticket);
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_BeginAuthSession(
+    server: *mut ISteamGameServer,
+    ticket: *const c_void,
+    len: c_int,
+    steam_id: u64,
+) -> EBeginAuthSessionResult {
+    let ret = ffi::SteamAPI_ISteamGameServer_BeginAuthSession(server, ticket, len, steam_id);
+    lint::begin_auth_session(true, steam_id, ret);
+    ret
+}
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamGameServer_EndAuthSession(
+    server: *mut ISteamGameServer,
+    steam_id: u64,
+) {
+    ffi::SteamAPI_ISteamGameServer_EndAuthSession(server, steam_id);
+    lint::end_auth_session(true, steam_id);
+}
+
+/// Registers [`callback_types::ALL`] the first time a callback is seen.
+fn register_callback_types() {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| callbacks::register_types(callback_types::ALL));
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_RegisterCallback(callback: *mut CCallbackBase, id: c_int) {
+    register_callback_types();
+    callbacks::register_callback(callback, id);
+    ffi::SteamAPI_RegisterCallback(callback, id)
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_UnregisterCallback(callback: *mut CCallbackBase) {
+    ffi::SteamAPI_UnregisterCallback(callback);
+    callbacks::unregister_callback(callback);
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_RegisterCallResult(
+    callback: *mut CCallbackBase,
+    call: SteamAPICall_t,
+) {
+    register_callback_types();
+    callbacks::register_call_result(callback, call);
+    ffi::SteamAPI_RegisterCallResult(callback, call)
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_UnregisterCallResult(
+    callback: *mut CCallbackBase,
+    call: SteamAPICall_t,
+) {
+    ffi::SteamAPI_UnregisterCallResult(callback, call);
+    callbacks::unregister_call_result(callback, call);
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_GetNextCallback(
+    pipe: HSteamPipe,
+    msg: *mut CallbackMsg_t,
+) -> bool {
+    register_callback_types();
+    let ret = ffi::SteamAPI_ManualDispatch_GetNextCallback(pipe, msg);
+    if ret {
+        callbacks::next_callback(pipe, msg);
+    }
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_FreeLastCallback(pipe: HSteamPipe) {
+    callbacks::free_last_callback(pipe);
+    ffi::SteamAPI_ManualDispatch_FreeLastCallback(pipe)
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ManualDispatch_GetAPICallResult(
+    pipe: HSteamPipe,
+    call: SteamAPICall_t,
+    data: *mut c_void,
+    size: c_int,
+    id: c_int,
+    failed: *mut bool,
+) -> bool {
+    register_callback_types();
+    let ret = ffi::SteamAPI_ManualDispatch_GetAPICallResult(pipe, call, data, size, id, failed);
+    if ret {
+        callbacks::api_call_result(call, id, data, size, !failed.is_null() && *failed);
+    }
+    ret
+}
+
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_ISteamUtils_GetAPICallResult(
+    utils: *mut ISteamUtils,
+    call: SteamAPICall_t,
+    data: *mut c_void,
+    size: c_int,
+    id: c_int,
+    failed: *mut bool,
+) -> bool {
+    register_callback_types();
+    let ret = ffi::SteamAPI_ISteamUtils_GetAPICallResult(utils, call, data, size, id, failed);
+    if ret {
+        callbacks::api_call_result(call, id, data, size, !failed.is_null() && *failed);
+    }
+    ret
+}
+
+/// Unhooks everything while the interfaces are still alive, so a later
+/// `SteamAPI_Init` starts from clean vtables.
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamAPI_Shutdown() {
+    inventory::shutdown();
+    callbacks::shutdown();
+    calls::shutdown();
+    threads::shutdown();
+    hooks::restore();
+    callbacks::restore();
+    CLIENT.store(false, Ordering::Relaxed);
+    ffi::SteamAPI_Shutdown();
+    lint::shutdown(false);
+}
+
+/// Unhooks everything like `SteamAPI_Shutdown` if the client isn't
+/// initialized, otherwise leaves the hooks alone since its interfaces may
+/// still be in use.
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn SteamGameServer_Shutdown() {
+    inventory::shutdown();
+    callbacks::shutdown();
+    calls::shutdown();
+    threads::shutdown();
+    if !CLIENT.load(Ordering::Relaxed) {
+        hooks::restore();
+        callbacks::restore();
+    }
+    ffi::SteamGameServer_Shutdown();
+    lint::shutdown(true);
+}
+
+/// `path` as a path, `None` if it's null, or an error if it isn't one on
+/// this platform.
+unsafe fn c_path<'a>(path: *const c_char) -> Result<Option<&'a OsStr>, ()> {
+    if path.is_null() {
+        return Ok(None);
+    }
+    let path = CStr::from_ptr(path);
+    #[cfg(unix)]
+    let path = <OsStr as std::os::unix::ffi::OsStrExt>::from_bytes(path.to_bytes());
+    #[cfg(not(unix))]
+    let path = OsStr::new(path.to_str().map_err(|_| ())?);
+    Ok(Some(path))
+}
+
+/// Write the interface inventory, every interface version the game asked
+/// for, to `path`, or to the configured path if `path` is null. Returns
+/// whether it was written. Interfaces are only recorded while the inventory
+/// is enabled in the config.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_write_inventory(path: *const c_char) -> bool {
+    c_path(path).is_ok_and(inventory::write)
+}
+
+/// Write the callback report, with the registrations and deliveries of each
+/// callback id, to `path`, or to the configured path if `path` is null.
+/// Returns whether it was written. Callbacks are only recorded while the
+/// report is enabled in the config.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_write_callbacks(path: *const c_char) -> bool {
+    c_path(path).is_ok_and(callbacks::write)
+}
+
+/// Write the API call report, with the handles each function issued and
+/// how long their results took, to `path`, or to the configured path if
+/// `path` is null. Returns whether it was written. Calls are only recorded
+/// while the report is enabled in the config.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_write_calls(path: *const c_char) -> bool {
+    c_path(path).is_ok_and(calls::write)
+}
+
+/// Write the thread report, with the calls each thread made into Steam and
+/// the ones into an interface another thread was already inside, to
+/// `path`, or to the configured path if `path` is null. Returns whether it
+/// was written. Threads are only recorded while the report is enabled in
+/// the config.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_write_threads(path: *const c_char) -> bool {
+    c_path(path).is_ok_and(threads::write)
+}
+
+/// Write the lint report, with the Steamworks integration bugs the checks
+/// found, to `path`, or to the configured path if `path` is null. Returns
+/// whether it was written. Calls are only checked while the checks are
+/// enabled in the config.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn konigsberg_write_lint(path: *const c_char) -> bool {
+    c_path(path).is_ok_and(lint::write)
+}
+
+/// Check every hooked vtable slot and return how many were replaced after
+/// konigsberg hooked them. Each replacement is logged the first time it's
+/// found.
+#[no_mangle]
+pub extern "C" fn konigsberg_check_hooks() -> usize {
+    hooks::check()
+}
+
+/// Unhook when konigsberg is unloaded, so nothing points into its code once
+/// it's gone, and write the lint report one last time.
+#[cfg(all(unix, not(target_os = "macos")))]
+#[used]
+#[link_section = ".fini_array"]
+static UNLOAD: unsafe extern "C" fn() = {
+    unsafe extern "C" fn unload() {
+        hooks::restore();
+        callbacks::restore();
+        lint::exit();
+    }
+    unload
+};
+#[cfg(windows)]
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "system" fn DllMain(
+    _module: *mut c_void,
+    reason: u32,
+    reserved: *mut c_void,
+) -> i32 {
+    const DLL_PROCESS_DETACH: u32 = 0;
+    if reason == DLL_PROCESS_DETACH {
+        // a non-null `reserved` means the whole process is exiting, and
+        // nothing's left to call into the hooks
+        if reserved.is_null() {
+            hooks::restore();
+            callbacks::restore();
+        }
+        lint::exit();
+    }
+    1
 }
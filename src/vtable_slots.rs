@@ -0,0 +1,938 @@
+pub(crate) mod ISteamClient {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamClient020";
+    pub(crate) const VERSION: u8 = 20;
+    pub(crate) const CreateSteamPipe: usize = 0;
+    pub(crate) const BReleaseSteamPipe: usize = 1;
+    pub(crate) const ConnectToGlobalUser: usize = 2;
+    pub(crate) const CreateLocalUser: usize = 3;
+    pub(crate) const ReleaseUser: usize = 4;
+    pub(crate) const GetISteamUser: usize = 5;
+    pub(crate) const GetISteamGameServer: usize = 6;
+    pub(crate) const SetLocalIPBinding: usize = 7;
+    pub(crate) const GetISteamFriends: usize = 8;
+    pub(crate) const GetISteamUtils: usize = 9;
+    pub(crate) const GetISteamMatchmaking: usize = 10;
+    pub(crate) const GetISteamMatchmakingServers: usize = 11;
+    pub(crate) const GetISteamGenericInterface: usize = 12;
+    pub(crate) const GetISteamUserStats: usize = 13;
+    pub(crate) const GetISteamGameServerStats: usize = 14;
+    pub(crate) const GetISteamApps: usize = 15;
+    pub(crate) const GetISteamNetworking: usize = 16;
+    pub(crate) const GetISteamRemoteStorage: usize = 17;
+    pub(crate) const GetISteamScreenshots: usize = 18;
+    pub(crate) const GetISteamGameSearch: usize = 19;
+    pub(crate) const GetIPCCallCount: usize = 21;
+    pub(crate) const SetWarningMessageHook: usize = 22;
+    pub(crate) const BShutdownIfAllPipesClosed: usize = 23;
+    pub(crate) const GetISteamHTTP: usize = 24;
+    pub(crate) const GetISteamController: usize = 26;
+    pub(crate) const GetISteamUGC: usize = 27;
+    pub(crate) const GetISteamAppList: usize = 28;
+    pub(crate) const GetISteamMusic: usize = 29;
+    pub(crate) const GetISteamMusicRemote: usize = 30;
+    pub(crate) const GetISteamHTMLSurface: usize = 31;
+    pub(crate) const GetISteamInventory: usize = 35;
+    pub(crate) const GetISteamVideo: usize = 36;
+    pub(crate) const GetISteamParentalSettings: usize = 37;
+    pub(crate) const GetISteamInput: usize = 38;
+    pub(crate) const GetISteamParties: usize = 39;
+    pub(crate) const GetISteamRemotePlay: usize = 40;
+}
+pub(crate) mod ISteamUser {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamUser021";
+    pub(crate) const VERSION: u8 = 21;
+    pub(crate) const GetHSteamUser: usize = 0;
+    pub(crate) const BLoggedOn: usize = 1;
+    pub(crate) const GetSteamID: usize = 2;
+    pub(crate) const InitiateGameConnection_DEPRECATED: usize = 3;
+    pub(crate) const TerminateGameConnection_DEPRECATED: usize = 4;
+    pub(crate) const TrackAppUsageEvent: usize = 5;
+    pub(crate) const GetUserDataFolder: usize = 6;
+    pub(crate) const StartVoiceRecording: usize = 7;
+    pub(crate) const StopVoiceRecording: usize = 8;
+    pub(crate) const GetAvailableVoice: usize = 9;
+    pub(crate) const GetVoice: usize = 10;
+    pub(crate) const DecompressVoice: usize = 11;
+    pub(crate) const GetVoiceOptimalSampleRate: usize = 12;
+    pub(crate) const GetAuthSessionTicket: usize = 13;
+    pub(crate) const BeginAuthSession: usize = 14;
+    pub(crate) const EndAuthSession: usize = 15;
+    pub(crate) const CancelAuthTicket: usize = 16;
+    pub(crate) const UserHasLicenseForApp: usize = 17;
+    pub(crate) const BIsBehindNAT: usize = 18;
+    pub(crate) const AdvertiseGame: usize = 19;
+    pub(crate) const RequestEncryptedAppTicket: usize = 20;
+    pub(crate) const GetEncryptedAppTicket: usize = 21;
+    pub(crate) const GetGameBadgeLevel: usize = 22;
+    pub(crate) const GetPlayerSteamLevel: usize = 23;
+    pub(crate) const RequestStoreAuthURL: usize = 24;
+    pub(crate) const BIsPhoneVerified: usize = 25;
+    pub(crate) const BIsTwoFactorEnabled: usize = 26;
+    pub(crate) const BIsPhoneIdentifying: usize = 27;
+    pub(crate) const BIsPhoneRequiringVerification: usize = 28;
+    pub(crate) const GetMarketEligibility: usize = 29;
+    pub(crate) const GetDurationControl: usize = 30;
+    pub(crate) const BSetDurationControlOnlineState: usize = 31;
+}
+pub(crate) mod ISteamFriends {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamFriends017";
+    pub(crate) const VERSION: u8 = 17;
+    pub(crate) const GetPersonaName: usize = 0;
+    pub(crate) const SetPersonaName: usize = 1;
+    pub(crate) const GetPersonaState: usize = 2;
+    pub(crate) const GetFriendCount: usize = 3;
+    pub(crate) const GetFriendByIndex: usize = 4;
+    pub(crate) const GetFriendRelationship: usize = 5;
+    pub(crate) const GetFriendPersonaState: usize = 6;
+    pub(crate) const GetFriendPersonaName: usize = 7;
+    pub(crate) const GetFriendGamePlayed: usize = 8;
+    pub(crate) const GetFriendPersonaNameHistory: usize = 9;
+    pub(crate) const GetFriendSteamLevel: usize = 10;
+    pub(crate) const GetPlayerNickname: usize = 11;
+    pub(crate) const GetFriendsGroupCount: usize = 12;
+    pub(crate) const GetFriendsGroupIDByIndex: usize = 13;
+    pub(crate) const GetFriendsGroupName: usize = 14;
+    pub(crate) const GetFriendsGroupMembersCount: usize = 15;
+    pub(crate) const GetFriendsGroupMembersList: usize = 16;
+    pub(crate) const HasFriend: usize = 17;
+    pub(crate) const GetClanCount: usize = 18;
+    pub(crate) const GetClanByIndex: usize = 19;
+    pub(crate) const GetClanName: usize = 20;
+    pub(crate) const GetClanTag: usize = 21;
+    pub(crate) const GetClanActivityCounts: usize = 22;
+    pub(crate) const DownloadClanActivityCounts: usize = 23;
+    pub(crate) const GetFriendCountFromSource: usize = 24;
+    pub(crate) const GetFriendFromSourceByIndex: usize = 25;
+    pub(crate) const IsUserInSource: usize = 26;
+    pub(crate) const SetInGameVoiceSpeaking: usize = 27;
+    pub(crate) const ActivateGameOverlay: usize = 28;
+    pub(crate) const ActivateGameOverlayToUser: usize = 29;
+    pub(crate) const ActivateGameOverlayToWebPage: usize = 30;
+    pub(crate) const ActivateGameOverlayToStore: usize = 31;
+    pub(crate) const SetPlayedWith: usize = 32;
+    pub(crate) const ActivateGameOverlayInviteDialog: usize = 33;
+    pub(crate) const GetSmallFriendAvatar: usize = 34;
+    pub(crate) const GetMediumFriendAvatar: usize = 35;
+    pub(crate) const GetLargeFriendAvatar: usize = 36;
+    pub(crate) const RequestUserInformation: usize = 37;
+    pub(crate) const RequestClanOfficerList: usize = 38;
+    pub(crate) const GetClanOwner: usize = 39;
+    pub(crate) const GetClanOfficerCount: usize = 40;
+    pub(crate) const GetClanOfficerByIndex: usize = 41;
+    pub(crate) const GetUserRestrictions: usize = 42;
+    pub(crate) const SetRichPresence: usize = 43;
+    pub(crate) const ClearRichPresence: usize = 44;
+    pub(crate) const GetFriendRichPresence: usize = 45;
+    pub(crate) const GetFriendRichPresenceKeyCount: usize = 46;
+    pub(crate) const GetFriendRichPresenceKeyByIndex: usize = 47;
+    pub(crate) const RequestFriendRichPresence: usize = 48;
+    pub(crate) const InviteUserToGame: usize = 49;
+    pub(crate) const GetCoplayFriendCount: usize = 50;
+    pub(crate) const GetCoplayFriend: usize = 51;
+    pub(crate) const GetFriendCoplayTime: usize = 52;
+    pub(crate) const GetFriendCoplayGame: usize = 53;
+    pub(crate) const JoinClanChatRoom: usize = 54;
+    pub(crate) const LeaveClanChatRoom: usize = 55;
+    pub(crate) const GetClanChatMemberCount: usize = 56;
+    pub(crate) const GetChatMemberByIndex: usize = 57;
+    pub(crate) const SendClanChatMessage: usize = 58;
+    pub(crate) const GetClanChatMessage: usize = 59;
+    pub(crate) const IsClanChatAdmin: usize = 60;
+    pub(crate) const IsClanChatWindowOpenInSteam: usize = 61;
+    pub(crate) const OpenClanChatWindowInSteam: usize = 62;
+    pub(crate) const CloseClanChatWindowInSteam: usize = 63;
+    pub(crate) const SetListenForFriendsMessages: usize = 64;
+    pub(crate) const ReplyToFriendMessage: usize = 65;
+    pub(crate) const GetFriendMessage: usize = 66;
+    pub(crate) const GetFollowerCount: usize = 67;
+    pub(crate) const IsFollowing: usize = 68;
+    pub(crate) const EnumerateFollowingList: usize = 69;
+    pub(crate) const IsClanPublic: usize = 70;
+    pub(crate) const IsClanOfficialGameGroup: usize = 71;
+    pub(crate) const GetNumChatsWithUnreadPriorityMessages: usize = 72;
+    pub(crate) const ActivateGameOverlayRemotePlayTogetherInviteDialog: usize = 73;
+    pub(crate) const RegisterProtocolInOverlayBrowser: usize = 74;
+    pub(crate) const ActivateGameOverlayInviteDialogConnectString: usize = 75;
+}
+pub(crate) mod ISteamUtils {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamUtils010";
+    pub(crate) const VERSION: u8 = 10;
+    pub(crate) const GetSecondsSinceAppActive: usize = 0;
+    pub(crate) const GetSecondsSinceComputerActive: usize = 1;
+    pub(crate) const GetConnectedUniverse: usize = 2;
+    pub(crate) const GetServerRealTime: usize = 3;
+    pub(crate) const GetIPCountry: usize = 4;
+    pub(crate) const GetImageSize: usize = 5;
+    pub(crate) const GetImageRGBA: usize = 6;
+    pub(crate) const GetCurrentBatteryPower: usize = 8;
+    pub(crate) const GetAppID: usize = 9;
+    pub(crate) const SetOverlayNotificationPosition: usize = 10;
+    pub(crate) const IsAPICallCompleted: usize = 11;
+    pub(crate) const GetAPICallFailureReason: usize = 12;
+    pub(crate) const GetIPCCallCount: usize = 15;
+    pub(crate) const SetWarningMessageHook: usize = 16;
+    pub(crate) const IsOverlayEnabled: usize = 17;
+    pub(crate) const BOverlayNeedsPresent: usize = 18;
+    pub(crate) const CheckFileSignature: usize = 19;
+    pub(crate) const ShowGamepadTextInput: usize = 20;
+    pub(crate) const GetEnteredGamepadTextLength: usize = 21;
+    pub(crate) const GetEnteredGamepadTextInput: usize = 22;
+    pub(crate) const GetSteamUILanguage: usize = 23;
+    pub(crate) const IsSteamRunningInVR: usize = 24;
+    pub(crate) const SetOverlayNotificationInset: usize = 25;
+    pub(crate) const IsSteamInBigPictureMode: usize = 26;
+    pub(crate) const StartVRDashboard: usize = 27;
+    pub(crate) const IsVRHeadsetStreamingEnabled: usize = 28;
+    pub(crate) const SetVRHeadsetStreamingEnabled: usize = 29;
+    pub(crate) const IsSteamChinaLauncher: usize = 30;
+    pub(crate) const InitFilterText: usize = 31;
+    pub(crate) const FilterText: usize = 32;
+    pub(crate) const GetIPv6ConnectivityState: usize = 33;
+    pub(crate) const IsSteamRunningOnSteamDeck: usize = 34;
+    pub(crate) const ShowFloatingGamepadTextInput: usize = 35;
+    pub(crate) const SetGameLauncherMode: usize = 36;
+    pub(crate) const DismissFloatingGamepadTextInput: usize = 37;
+}
+pub(crate) mod ISteamMatchmaking {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamMatchMaking009";
+    pub(crate) const VERSION: u8 = 9;
+    pub(crate) const GetFavoriteGameCount: usize = 0;
+    pub(crate) const GetFavoriteGame: usize = 1;
+    pub(crate) const AddFavoriteGame: usize = 2;
+    pub(crate) const RemoveFavoriteGame: usize = 3;
+    pub(crate) const RequestLobbyList: usize = 4;
+    pub(crate) const AddRequestLobbyListStringFilter: usize = 5;
+    pub(crate) const AddRequestLobbyListNumericalFilter: usize = 6;
+    pub(crate) const AddRequestLobbyListNearValueFilter: usize = 7;
+    pub(crate) const AddRequestLobbyListFilterSlotsAvailable: usize = 8;
+    pub(crate) const AddRequestLobbyListDistanceFilter: usize = 9;
+    pub(crate) const AddRequestLobbyListResultCountFilter: usize = 10;
+    pub(crate) const AddRequestLobbyListCompatibleMembersFilter: usize = 11;
+    pub(crate) const GetLobbyByIndex: usize = 12;
+    pub(crate) const CreateLobby: usize = 13;
+}
+pub(crate) mod ISteamMatchmakingServerListResponse {
+    pub(crate) const ServerResponded: usize = 0;
+    pub(crate) const ServerFailedToRespond: usize = 1;
+    pub(crate) const RefreshComplete: usize = 2;
+}
+pub(crate) mod ISteamMatchmakingPingResponse {
+    pub(crate) const ServerResponded: usize = 0;
+    pub(crate) const ServerFailedToRespond: usize = 1;
+}
+pub(crate) mod ISteamMatchmakingPlayersResponse {
+    pub(crate) const AddPlayerToList: usize = 0;
+    pub(crate) const PlayersFailedToRespond: usize = 1;
+    pub(crate) const PlayersRefreshComplete: usize = 2;
+}
+pub(crate) mod ISteamMatchmakingRulesResponse {
+    pub(crate) const RulesResponded: usize = 0;
+    pub(crate) const RulesFailedToRespond: usize = 1;
+    pub(crate) const RulesRefreshComplete: usize = 2;
+}
+pub(crate) mod ISteamMatchmakingServers {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamMatchMakingServers002";
+    pub(crate) const VERSION: u8 = 2;
+    pub(crate) const RequestInternetServerList: usize = 0;
+    pub(crate) const RequestLANServerList: usize = 1;
+    pub(crate) const RequestFriendsServerList: usize = 2;
+    pub(crate) const RequestFavoritesServerList: usize = 3;
+    pub(crate) const RequestHistoryServerList: usize = 4;
+    pub(crate) const RequestSpectatorServerList: usize = 5;
+    pub(crate) const ReleaseRequest: usize = 6;
+    pub(crate) const GetServerDetails: usize = 7;
+    pub(crate) const CancelQuery: usize = 8;
+    pub(crate) const RefreshQuery: usize = 9;
+    pub(crate) const IsRefreshing: usize = 10;
+    pub(crate) const GetServerCount: usize = 11;
+    pub(crate) const RefreshServer: usize = 12;
+    pub(crate) const PingServer: usize = 13;
+    pub(crate) const PlayerDetails: usize = 14;
+    pub(crate) const ServerRules: usize = 15;
+    pub(crate) const CancelServerQuery: usize = 16;
+}
+pub(crate) mod ISteamGameSearch {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamMatchGameSearch001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const AddGameSearchParams: usize = 0;
+    pub(crate) const SearchForGameWithLobby: usize = 1;
+    pub(crate) const SearchForGameSolo: usize = 2;
+    pub(crate) const AcceptGame: usize = 3;
+    pub(crate) const DeclineGame: usize = 4;
+    pub(crate) const RetrieveConnectionDetails: usize = 5;
+    pub(crate) const EndGameSearch: usize = 6;
+    pub(crate) const SetGameHostParams: usize = 7;
+    pub(crate) const SetConnectionDetails: usize = 8;
+    pub(crate) const RequestPlayersForGame: usize = 9;
+    pub(crate) const HostConfirmGameStart: usize = 10;
+    pub(crate) const CancelRequestPlayersForGame: usize = 11;
+    pub(crate) const SubmitPlayerResult: usize = 12;
+    pub(crate) const EndGame: usize = 13;
+}
+pub(crate) mod ISteamParties {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamParties002";
+    pub(crate) const VERSION: u8 = 2;
+    pub(crate) const GetNumActiveBeacons: usize = 0;
+    pub(crate) const GetBeaconByIndex: usize = 1;
+    pub(crate) const GetBeaconDetails: usize = 2;
+    pub(crate) const JoinParty: usize = 3;
+    pub(crate) const GetNumAvailableBeaconLocations: usize = 4;
+    pub(crate) const GetAvailableBeaconLocations: usize = 5;
+    pub(crate) const CreateBeacon: usize = 6;
+    pub(crate) const OnReservationCompleted: usize = 7;
+    pub(crate) const CancelReservation: usize = 8;
+    pub(crate) const ChangeNumOpenSlots: usize = 9;
+    pub(crate) const DestroyBeacon: usize = 10;
+}
+pub(crate) mod ISteamRemoteStorage {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMREMOTESTORAGE_INTERFACE_VERSION016";
+    pub(crate) const VERSION: u8 = 16;
+    pub(crate) const FileWrite: usize = 0;
+    pub(crate) const FileRead: usize = 1;
+    pub(crate) const FileWriteAsync: usize = 2;
+    pub(crate) const FileReadAsync: usize = 3;
+    pub(crate) const FileReadAsyncComplete: usize = 4;
+    pub(crate) const FileForget: usize = 5;
+    pub(crate) const FileDelete: usize = 6;
+    pub(crate) const FileShare: usize = 7;
+    pub(crate) const SetSyncPlatforms: usize = 8;
+    pub(crate) const FileWriteStreamOpen: usize = 9;
+    pub(crate) const FileWriteStreamWriteChunk: usize = 10;
+    pub(crate) const FileWriteStreamClose: usize = 11;
+    pub(crate) const FileWriteStreamCancel: usize = 12;
+    pub(crate) const FileExists: usize = 13;
+    pub(crate) const FilePersisted: usize = 14;
+    pub(crate) const GetFileSize: usize = 15;
+    pub(crate) const GetFileTimestamp: usize = 16;
+    pub(crate) const GetSyncPlatforms: usize = 17;
+    pub(crate) const GetFileCount: usize = 18;
+    pub(crate) const GetFileNameAndSize: usize = 19;
+    pub(crate) const GetQuota: usize = 20;
+    pub(crate) const IsCloudEnabledForAccount: usize = 21;
+    pub(crate) const IsCloudEnabledForApp: usize = 22;
+    pub(crate) const SetCloudEnabledForApp: usize = 23;
+    pub(crate) const UGCDownload: usize = 24;
+    pub(crate) const GetUGCDownloadProgress: usize = 25;
+    pub(crate) const GetUGCDetails: usize = 26;
+    pub(crate) const UGCRead: usize = 27;
+    pub(crate) const GetCachedUGCCount: usize = 28;
+    pub(crate) const GetCachedUGCHandle: usize = 29;
+    pub(crate) const PublishWorkshopFile: usize = 30;
+    pub(crate) const CreatePublishedFileUpdateRequest: usize = 31;
+    pub(crate) const UpdatePublishedFileFile: usize = 32;
+    pub(crate) const UpdatePublishedFilePreviewFile: usize = 33;
+    pub(crate) const UpdatePublishedFileTitle: usize = 34;
+    pub(crate) const UpdatePublishedFileDescription: usize = 35;
+    pub(crate) const UpdatePublishedFileVisibility: usize = 36;
+    pub(crate) const UpdatePublishedFileTags: usize = 37;
+    pub(crate) const CommitPublishedFileUpdate: usize = 38;
+    pub(crate) const GetPublishedFileDetails: usize = 39;
+    pub(crate) const DeletePublishedFile: usize = 40;
+    pub(crate) const EnumerateUserPublishedFiles: usize = 41;
+    pub(crate) const SubscribePublishedFile: usize = 42;
+    pub(crate) const EnumerateUserSubscribedFiles: usize = 43;
+    pub(crate) const UnsubscribePublishedFile: usize = 44;
+    pub(crate) const UpdatePublishedFileSetChangeDescription: usize = 45;
+    pub(crate) const GetPublishedItemVoteDetails: usize = 46;
+    pub(crate) const UpdateUserPublishedItemVote: usize = 47;
+    pub(crate) const GetUserPublishedItemVoteDetails: usize = 48;
+    pub(crate) const EnumerateUserSharedWorkshopFiles: usize = 49;
+    pub(crate) const PublishVideo: usize = 50;
+    pub(crate) const SetUserPublishedFileAction: usize = 51;
+    pub(crate) const EnumeratePublishedFilesByUserAction: usize = 52;
+    pub(crate) const EnumeratePublishedWorkshopFiles: usize = 53;
+    pub(crate) const UGCDownloadToLocation: usize = 54;
+    pub(crate) const GetLocalFileChangeCount: usize = 55;
+    pub(crate) const GetLocalFileChange: usize = 56;
+    pub(crate) const BeginFileWriteBatch: usize = 57;
+    pub(crate) const EndFileWriteBatch: usize = 58;
+}
+pub(crate) mod ISteamUserStats {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMUSERSTATS_INTERFACE_VERSION012";
+    pub(crate) const VERSION: u8 = 12;
+    pub(crate) const RequestCurrentStats: usize = 0;
+    pub(crate) const GetStatInt32: usize = 1;
+    pub(crate) const GetStatFloat: usize = 2;
+    pub(crate) const SetStatInt32: usize = 3;
+    pub(crate) const SetStatFloat: usize = 4;
+    pub(crate) const UpdateAvgRateStat: usize = 5;
+    pub(crate) const GetAchievement: usize = 6;
+    pub(crate) const SetAchievement: usize = 7;
+    pub(crate) const ClearAchievement: usize = 8;
+    pub(crate) const GetAchievementAndUnlockTime: usize = 9;
+    pub(crate) const StoreStats: usize = 10;
+    pub(crate) const GetAchievementIcon: usize = 11;
+    pub(crate) const GetAchievementDisplayAttribute: usize = 12;
+    pub(crate) const IndicateAchievementProgress: usize = 13;
+    pub(crate) const GetNumAchievements: usize = 14;
+    pub(crate) const GetAchievementName: usize = 15;
+    pub(crate) const RequestUserStats: usize = 16;
+    pub(crate) const GetUserStatInt32: usize = 17;
+    pub(crate) const GetUserStatFloat: usize = 18;
+    pub(crate) const GetUserAchievement: usize = 19;
+    pub(crate) const GetUserAchievementAndUnlockTime: usize = 20;
+    pub(crate) const ResetAllStats: usize = 21;
+    pub(crate) const FindOrCreateLeaderboard: usize = 22;
+    pub(crate) const FindLeaderboard: usize = 23;
+    pub(crate) const GetLeaderboardName: usize = 24;
+    pub(crate) const GetLeaderboardEntryCount: usize = 25;
+    pub(crate) const GetLeaderboardSortMethod: usize = 26;
+    pub(crate) const GetLeaderboardDisplayType: usize = 27;
+    pub(crate) const DownloadLeaderboardEntries: usize = 28;
+    pub(crate) const DownloadLeaderboardEntriesForUsers: usize = 29;
+    pub(crate) const GetDownloadedLeaderboardEntry: usize = 30;
+    pub(crate) const UploadLeaderboardScore: usize = 31;
+    pub(crate) const AttachLeaderboardUGC: usize = 32;
+    pub(crate) const GetNumberOfCurrentPlayers: usize = 33;
+    pub(crate) const RequestGlobalAchievementPercentages: usize = 34;
+    pub(crate) const GetMostAchievedAchievementInfo: usize = 35;
+    pub(crate) const GetNextMostAchievedAchievementInfo: usize = 36;
+    pub(crate) const GetAchievementAchievedPercent: usize = 37;
+    pub(crate) const RequestGlobalStats: usize = 38;
+}
+pub(crate) mod ISteamApps {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMAPPS_INTERFACE_VERSION008";
+    pub(crate) const VERSION: u8 = 8;
+    pub(crate) const BIsSubscribed: usize = 0;
+    pub(crate) const BIsLowViolence: usize = 1;
+    pub(crate) const BIsCybercafe: usize = 2;
+    pub(crate) const BIsVACBanned: usize = 3;
+    pub(crate) const GetCurrentGameLanguage: usize = 4;
+    pub(crate) const GetAvailableGameLanguages: usize = 5;
+    pub(crate) const BIsSubscribedApp: usize = 6;
+    pub(crate) const BIsDlcInstalled: usize = 7;
+    pub(crate) const GetEarliestPurchaseUnixTime: usize = 8;
+    pub(crate) const BIsSubscribedFromFreeWeekend: usize = 9;
+    pub(crate) const GetDLCCount: usize = 10;
+    pub(crate) const BGetDLCDataByIndex: usize = 11;
+    pub(crate) const InstallDLC: usize = 12;
+    pub(crate) const UninstallDLC: usize = 13;
+    pub(crate) const RequestAppProofOfPurchaseKey: usize = 14;
+    pub(crate) const GetCurrentBetaName: usize = 15;
+    pub(crate) const MarkContentCorrupt: usize = 16;
+    pub(crate) const GetInstalledDepots: usize = 17;
+    pub(crate) const GetAppInstallDir: usize = 18;
+    pub(crate) const BIsAppInstalled: usize = 19;
+    pub(crate) const GetAppOwner: usize = 20;
+    pub(crate) const GetLaunchQueryParam: usize = 21;
+    pub(crate) const GetDlcDownloadProgress: usize = 22;
+    pub(crate) const GetAppBuildId: usize = 23;
+    pub(crate) const RequestAllProofOfPurchaseKeys: usize = 24;
+    pub(crate) const GetFileDetails: usize = 25;
+    pub(crate) const GetLaunchCommandLine: usize = 26;
+    pub(crate) const BIsSubscribedFromFamilySharing: usize = 27;
+    pub(crate) const BIsTimedTrial: usize = 28;
+}
+pub(crate) mod ISteamNetworking {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamNetworking006";
+    pub(crate) const VERSION: u8 = 6;
+    pub(crate) const SendP2PPacket: usize = 0;
+    pub(crate) const IsP2PPacketAvailable: usize = 1;
+    pub(crate) const ReadP2PPacket: usize = 2;
+    pub(crate) const AcceptP2PSessionWithUser: usize = 3;
+    pub(crate) const CloseP2PSessionWithUser: usize = 4;
+    pub(crate) const CloseP2PChannelWithUser: usize = 5;
+    pub(crate) const GetP2PSessionState: usize = 6;
+    pub(crate) const AllowP2PPacketRelay: usize = 7;
+    pub(crate) const CreateListenSocket: usize = 8;
+    pub(crate) const CreateP2PConnectionSocket: usize = 9;
+    pub(crate) const CreateConnectionSocket: usize = 10;
+    pub(crate) const DestroySocket: usize = 11;
+    pub(crate) const DestroyListenSocket: usize = 12;
+    pub(crate) const SendDataOnSocket: usize = 13;
+    pub(crate) const IsDataAvailableOnSocket: usize = 14;
+    pub(crate) const RetrieveDataFromSocket: usize = 15;
+    pub(crate) const IsDataAvailable: usize = 16;
+    pub(crate) const RetrieveData: usize = 17;
+    pub(crate) const GetSocketInfo: usize = 18;
+    pub(crate) const GetListenSocketInfo: usize = 19;
+    pub(crate) const GetSocketConnectionType: usize = 20;
+    pub(crate) const GetMaxPacketSize: usize = 21;
+}
+pub(crate) mod ISteamScreenshots {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMSCREENSHOTS_INTERFACE_VERSION003";
+    pub(crate) const VERSION: u8 = 3;
+    pub(crate) const WriteScreenshot: usize = 0;
+    pub(crate) const AddScreenshotToLibrary: usize = 1;
+    pub(crate) const TriggerScreenshot: usize = 2;
+    pub(crate) const HookScreenshots: usize = 3;
+    pub(crate) const SetLocation: usize = 4;
+    pub(crate) const TagUser: usize = 5;
+    pub(crate) const TagPublishedFile: usize = 6;
+    pub(crate) const IsScreenshotsHooked: usize = 7;
+    pub(crate) const AddVRScreenshotToLibrary: usize = 8;
+}
+pub(crate) mod ISteamMusic {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMMUSIC_INTERFACE_VERSION001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const BIsEnabled: usize = 0;
+    pub(crate) const BIsPlaying: usize = 1;
+    pub(crate) const GetPlaybackStatus: usize = 2;
+    pub(crate) const Play: usize = 3;
+    pub(crate) const Pause: usize = 4;
+    pub(crate) const PlayPrevious: usize = 5;
+    pub(crate) const PlayNext: usize = 6;
+    pub(crate) const SetVolume: usize = 7;
+    pub(crate) const GetVolume: usize = 8;
+}
+pub(crate) mod ISteamMusicRemote {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMMUSICREMOTE_INTERFACE_VERSION001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const RegisterSteamMusicRemote: usize = 0;
+    pub(crate) const DeregisterSteamMusicRemote: usize = 1;
+    pub(crate) const BIsCurrentMusicRemote: usize = 2;
+    pub(crate) const BActivationSuccess: usize = 3;
+    pub(crate) const SetDisplayName: usize = 4;
+    pub(crate) const SetPNGIcon_64x64: usize = 5;
+    pub(crate) const EnablePlayPrevious: usize = 6;
+    pub(crate) const EnablePlayNext: usize = 7;
+    pub(crate) const EnableShuffled: usize = 8;
+    pub(crate) const EnableLooped: usize = 9;
+    pub(crate) const EnableQueue: usize = 10;
+    pub(crate) const EnablePlaylists: usize = 11;
+    pub(crate) const UpdatePlaybackStatus: usize = 12;
+    pub(crate) const UpdateShuffled: usize = 13;
+    pub(crate) const UpdateLooped: usize = 14;
+    pub(crate) const UpdateVolume: usize = 15;
+    pub(crate) const CurrentEntryWillChange: usize = 16;
+    pub(crate) const CurrentEntryIsAvailable: usize = 17;
+    pub(crate) const UpdateCurrentEntryText: usize = 18;
+    pub(crate) const UpdateCurrentEntryElapsedSeconds: usize = 19;
+    pub(crate) const UpdateCurrentEntryCoverArt: usize = 20;
+    pub(crate) const CurrentEntryDidChange: usize = 21;
+    pub(crate) const QueueWillChange: usize = 22;
+    pub(crate) const ResetQueueEntries: usize = 23;
+    pub(crate) const SetQueueEntry: usize = 24;
+    pub(crate) const SetCurrentQueueEntry: usize = 25;
+    pub(crate) const QueueDidChange: usize = 26;
+    pub(crate) const PlaylistWillChange: usize = 27;
+    pub(crate) const ResetPlaylistEntries: usize = 28;
+    pub(crate) const SetPlaylistEntry: usize = 29;
+    pub(crate) const SetCurrentPlaylistEntry: usize = 30;
+    pub(crate) const PlaylistDidChange: usize = 31;
+}
+pub(crate) mod ISteamHTTP {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMHTTP_INTERFACE_VERSION003";
+    pub(crate) const VERSION: u8 = 3;
+    pub(crate) const CreateHTTPRequest: usize = 0;
+    pub(crate) const SetHTTPRequestContextValue: usize = 1;
+    pub(crate) const SetHTTPRequestNetworkActivityTimeout: usize = 2;
+    pub(crate) const SetHTTPRequestHeaderValue: usize = 3;
+    pub(crate) const SetHTTPRequestGetOrPostParameter: usize = 4;
+    pub(crate) const SendHTTPRequest: usize = 5;
+    pub(crate) const SendHTTPRequestAndStreamResponse: usize = 6;
+    pub(crate) const DeferHTTPRequest: usize = 7;
+    pub(crate) const PrioritizeHTTPRequest: usize = 8;
+    pub(crate) const GetHTTPResponseHeaderSize: usize = 9;
+    pub(crate) const GetHTTPResponseHeaderValue: usize = 10;
+    pub(crate) const GetHTTPResponseBodySize: usize = 11;
+    pub(crate) const GetHTTPResponseBodyData: usize = 12;
+    pub(crate) const GetHTTPStreamingResponseBodyData: usize = 13;
+    pub(crate) const ReleaseHTTPRequest: usize = 14;
+    pub(crate) const GetHTTPDownloadProgressPct: usize = 15;
+    pub(crate) const SetHTTPRequestRawPostBody: usize = 16;
+    pub(crate) const CreateCookieContainer: usize = 17;
+    pub(crate) const ReleaseCookieContainer: usize = 18;
+    pub(crate) const SetCookie: usize = 19;
+    pub(crate) const SetHTTPRequestCookieContainer: usize = 20;
+    pub(crate) const SetHTTPRequestUserAgentInfo: usize = 21;
+    pub(crate) const SetHTTPRequestRequiresVerifiedCertificate: usize = 22;
+    pub(crate) const SetHTTPRequestAbsoluteTimeoutMS: usize = 23;
+    pub(crate) const GetHTTPRequestWasTimedOut: usize = 24;
+}
+pub(crate) mod ISteamInput {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamInput006";
+    pub(crate) const VERSION: u8 = 6;
+    pub(crate) const Init: usize = 0;
+    pub(crate) const Shutdown: usize = 1;
+    pub(crate) const SetInputActionManifestFilePath: usize = 2;
+    pub(crate) const RunFrame: usize = 3;
+    pub(crate) const BWaitForData: usize = 4;
+    pub(crate) const BNewDataAvailable: usize = 5;
+    pub(crate) const GetConnectedControllers: usize = 6;
+    pub(crate) const EnableDeviceCallbacks: usize = 7;
+    pub(crate) const EnableActionEventCallbacks: usize = 8;
+    pub(crate) const GetActionSetHandle: usize = 9;
+    pub(crate) const ActivateActionSet: usize = 10;
+    pub(crate) const GetCurrentActionSet: usize = 11;
+    pub(crate) const ActivateActionSetLayer: usize = 12;
+    pub(crate) const DeactivateActionSetLayer: usize = 13;
+    pub(crate) const DeactivateAllActionSetLayers: usize = 14;
+    pub(crate) const GetActiveActionSetLayers: usize = 15;
+    pub(crate) const GetDigitalActionHandle: usize = 16;
+    pub(crate) const GetDigitalActionData: usize = 17;
+    pub(crate) const GetDigitalActionOrigins: usize = 18;
+    pub(crate) const GetStringForDigitalActionName: usize = 19;
+    pub(crate) const GetAnalogActionHandle: usize = 20;
+    pub(crate) const GetAnalogActionData: usize = 21;
+    pub(crate) const GetAnalogActionOrigins: usize = 22;
+    pub(crate) const GetGlyphPNGForActionOrigin: usize = 23;
+    pub(crate) const GetGlyphSVGForActionOrigin: usize = 24;
+    pub(crate) const GetGlyphForActionOrigin_Legacy: usize = 25;
+    pub(crate) const GetStringForActionOrigin: usize = 26;
+    pub(crate) const GetStringForAnalogActionName: usize = 27;
+    pub(crate) const StopAnalogActionMomentum: usize = 28;
+    pub(crate) const GetMotionData: usize = 29;
+    pub(crate) const TriggerVibration: usize = 30;
+    pub(crate) const TriggerVibrationExtended: usize = 31;
+    pub(crate) const TriggerSimpleHapticEvent: usize = 32;
+    pub(crate) const SetLEDColor: usize = 33;
+    pub(crate) const Legacy_TriggerHapticPulse: usize = 34;
+    pub(crate) const Legacy_TriggerRepeatedHapticPulse: usize = 35;
+    pub(crate) const ShowBindingPanel: usize = 36;
+    pub(crate) const GetInputTypeForHandle: usize = 37;
+    pub(crate) const GetControllerForGamepadIndex: usize = 38;
+    pub(crate) const GetGamepadIndexForController: usize = 39;
+    pub(crate) const GetStringForXboxOrigin: usize = 40;
+    pub(crate) const GetGlyphForXboxOrigin: usize = 41;
+    pub(crate) const GetActionOriginFromXboxOrigin: usize = 42;
+    pub(crate) const TranslateActionOrigin: usize = 43;
+    pub(crate) const GetDeviceBindingRevision: usize = 44;
+    pub(crate) const GetRemotePlaySessionID: usize = 45;
+    pub(crate) const GetSessionInputConfigurationSettings: usize = 46;
+}
+pub(crate) mod ISteamController {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamController008";
+    pub(crate) const VERSION: u8 = 8;
+    pub(crate) const Init: usize = 0;
+    pub(crate) const Shutdown: usize = 1;
+    pub(crate) const RunFrame: usize = 2;
+    pub(crate) const GetConnectedControllers: usize = 3;
+    pub(crate) const GetActionSetHandle: usize = 4;
+    pub(crate) const ActivateActionSet: usize = 5;
+    pub(crate) const GetCurrentActionSet: usize = 6;
+    pub(crate) const ActivateActionSetLayer: usize = 7;
+    pub(crate) const DeactivateActionSetLayer: usize = 8;
+    pub(crate) const DeactivateAllActionSetLayers: usize = 9;
+    pub(crate) const GetActiveActionSetLayers: usize = 10;
+    pub(crate) const GetDigitalActionHandle: usize = 11;
+    pub(crate) const GetDigitalActionData: usize = 12;
+    pub(crate) const GetDigitalActionOrigins: usize = 13;
+    pub(crate) const GetAnalogActionHandle: usize = 14;
+    pub(crate) const GetAnalogActionData: usize = 15;
+    pub(crate) const GetAnalogActionOrigins: usize = 16;
+    pub(crate) const GetGlyphForActionOrigin: usize = 17;
+    pub(crate) const GetStringForActionOrigin: usize = 18;
+    pub(crate) const StopAnalogActionMomentum: usize = 19;
+    pub(crate) const GetMotionData: usize = 20;
+    pub(crate) const TriggerHapticPulse: usize = 21;
+    pub(crate) const TriggerRepeatedHapticPulse: usize = 22;
+    pub(crate) const TriggerVibration: usize = 23;
+    pub(crate) const SetLEDColor: usize = 24;
+    pub(crate) const ShowBindingPanel: usize = 25;
+    pub(crate) const GetInputTypeForHandle: usize = 26;
+    pub(crate) const GetControllerForGamepadIndex: usize = 27;
+    pub(crate) const GetGamepadIndexForController: usize = 28;
+    pub(crate) const GetStringForXboxOrigin: usize = 29;
+    pub(crate) const GetGlyphForXboxOrigin: usize = 30;
+    pub(crate) const GetActionOriginFromXboxOrigin: usize = 31;
+    pub(crate) const TranslateActionOrigin: usize = 32;
+    pub(crate) const GetControllerBindingRevision: usize = 33;
+}
+pub(crate) mod ISteamUGC {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMUGC_INTERFACE_VERSION016";
+    pub(crate) const VERSION: u8 = 16;
+    pub(crate) const CreateQueryUserUGCRequest: usize = 0;
+    pub(crate) const CreateQueryAllUGCRequestPage: usize = 1;
+    pub(crate) const CreateQueryAllUGCRequestCursor: usize = 2;
+    pub(crate) const CreateQueryUGCDetailsRequest: usize = 3;
+    pub(crate) const SendQueryUGCRequest: usize = 4;
+    pub(crate) const GetQueryUGCResult: usize = 5;
+    pub(crate) const GetQueryUGCNumTags: usize = 6;
+    pub(crate) const GetQueryUGCTag: usize = 7;
+    pub(crate) const GetQueryUGCTagDisplayName: usize = 8;
+    pub(crate) const GetQueryUGCPreviewURL: usize = 9;
+    pub(crate) const GetQueryUGCMetadata: usize = 10;
+    pub(crate) const GetQueryUGCChildren: usize = 11;
+    pub(crate) const GetQueryUGCStatistic: usize = 12;
+    pub(crate) const GetQueryUGCNumAdditionalPreviews: usize = 13;
+    pub(crate) const GetQueryUGCAdditionalPreview: usize = 14;
+    pub(crate) const GetQueryUGCNumKeyValueTags: usize = 15;
+    pub(crate) const GetQueryUGCKeyValueTag: usize = 16;
+    pub(crate) const GetQueryFirstUGCKeyValueTag: usize = 17;
+    pub(crate) const ReleaseQueryUGCRequest: usize = 18;
+    pub(crate) const AddRequiredTag: usize = 19;
+    pub(crate) const AddRequiredTagGroup: usize = 20;
+    pub(crate) const AddExcludedTag: usize = 21;
+    pub(crate) const SetReturnOnlyIDs: usize = 22;
+    pub(crate) const SetReturnKeyValueTags: usize = 23;
+    pub(crate) const SetReturnLongDescription: usize = 24;
+    pub(crate) const SetReturnMetadata: usize = 25;
+    pub(crate) const SetReturnChildren: usize = 26;
+    pub(crate) const SetReturnAdditionalPreviews: usize = 27;
+    pub(crate) const SetReturnTotalOnly: usize = 28;
+    pub(crate) const SetReturnPlaytimeStats: usize = 29;
+    pub(crate) const SetLanguage: usize = 30;
+    pub(crate) const SetAllowCachedResponse: usize = 31;
+    pub(crate) const SetCloudFileNameFilter: usize = 32;
+    pub(crate) const SetMatchAnyTag: usize = 33;
+    pub(crate) const SetSearchText: usize = 34;
+    pub(crate) const SetRankedByTrendDays: usize = 35;
+    pub(crate) const SetTimeCreatedDateRange: usize = 36;
+    pub(crate) const SetTimeUpdatedDateRange: usize = 37;
+    pub(crate) const AddRequiredKeyValueTag: usize = 38;
+    pub(crate) const RequestUGCDetails: usize = 39;
+    pub(crate) const CreateItem: usize = 40;
+    pub(crate) const StartItemUpdate: usize = 41;
+    pub(crate) const SetItemTitle: usize = 42;
+    pub(crate) const SetItemDescription: usize = 43;
+    pub(crate) const SetItemUpdateLanguage: usize = 44;
+    pub(crate) const SetItemMetadata: usize = 45;
+    pub(crate) const SetItemVisibility: usize = 46;
+    pub(crate) const SetItemTags: usize = 47;
+    pub(crate) const SetItemContent: usize = 48;
+    pub(crate) const SetItemPreview: usize = 49;
+    pub(crate) const SetAllowLegacyUpload: usize = 50;
+    pub(crate) const RemoveAllItemKeyValueTags: usize = 51;
+    pub(crate) const RemoveItemKeyValueTags: usize = 52;
+    pub(crate) const AddItemKeyValueTag: usize = 53;
+    pub(crate) const AddItemPreviewFile: usize = 54;
+    pub(crate) const AddItemPreviewVideo: usize = 55;
+    pub(crate) const UpdateItemPreviewFile: usize = 56;
+    pub(crate) const UpdateItemPreviewVideo: usize = 57;
+    pub(crate) const RemoveItemPreview: usize = 58;
+    pub(crate) const SubmitItemUpdate: usize = 59;
+    pub(crate) const GetItemUpdateProgress: usize = 60;
+    pub(crate) const SetUserItemVote: usize = 61;
+    pub(crate) const GetUserItemVote: usize = 62;
+    pub(crate) const AddItemToFavorites: usize = 63;
+    pub(crate) const RemoveItemFromFavorites: usize = 64;
+    pub(crate) const SubscribeItem: usize = 65;
+    pub(crate) const UnsubscribeItem: usize = 66;
+    pub(crate) const GetNumSubscribedItems: usize = 67;
+    pub(crate) const GetSubscribedItems: usize = 68;
+    pub(crate) const GetItemState: usize = 69;
+    pub(crate) const GetItemInstallInfo: usize = 70;
+    pub(crate) const GetItemDownloadInfo: usize = 71;
+    pub(crate) const DownloadItem: usize = 72;
+    pub(crate) const BInitWorkshopForGameServer: usize = 73;
+    pub(crate) const SuspendDownloads: usize = 74;
+    pub(crate) const StartPlaytimeTracking: usize = 75;
+    pub(crate) const StopPlaytimeTracking: usize = 76;
+    pub(crate) const StopPlaytimeTrackingForAllItems: usize = 77;
+    pub(crate) const AddDependency: usize = 78;
+    pub(crate) const RemoveDependency: usize = 79;
+    pub(crate) const AddAppDependency: usize = 80;
+    pub(crate) const RemoveAppDependency: usize = 81;
+    pub(crate) const GetAppDependencies: usize = 82;
+    pub(crate) const DeleteItem: usize = 83;
+    pub(crate) const ShowWorkshopEULA: usize = 84;
+    pub(crate) const GetWorkshopEULAStatus: usize = 85;
+}
+pub(crate) mod ISteamAppList {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMAPPLIST_INTERFACE_VERSION001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const GetNumInstalledApps: usize = 0;
+    pub(crate) const GetInstalledApps: usize = 1;
+    pub(crate) const GetAppName: usize = 2;
+    pub(crate) const GetAppInstallDir: usize = 3;
+    pub(crate) const GetAppBuildId: usize = 4;
+}
+pub(crate) mod ISteamHTMLSurface {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMHTMLSURFACE_INTERFACE_VERSION_005";
+    pub(crate) const VERSION: u8 = 5;
+}
+pub(crate) mod ISteamInventory {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMINVENTORY_INTERFACE_V003";
+    pub(crate) const VERSION: u8 = 3;
+    pub(crate) const GetResultStatus: usize = 0;
+    pub(crate) const GetResultItems: usize = 1;
+    pub(crate) const GetResultItemProperty: usize = 2;
+    pub(crate) const GetResultTimestamp: usize = 3;
+    pub(crate) const CheckResultSteamID: usize = 4;
+    pub(crate) const DestroyResult: usize = 5;
+    pub(crate) const GetAllItems: usize = 6;
+    pub(crate) const GetItemsByID: usize = 7;
+    pub(crate) const SerializeResult: usize = 8;
+    pub(crate) const DeserializeResult: usize = 9;
+    pub(crate) const GenerateItems: usize = 10;
+    pub(crate) const GrantPromoItems: usize = 11;
+    pub(crate) const AddPromoItem: usize = 12;
+    pub(crate) const AddPromoItems: usize = 13;
+    pub(crate) const ConsumeItem: usize = 14;
+    pub(crate) const ExchangeItems: usize = 15;
+    pub(crate) const TransferItemQuantity: usize = 16;
+    pub(crate) const SendItemDropHeartbeat: usize = 17;
+    pub(crate) const TriggerItemDrop: usize = 18;
+    pub(crate) const TradeItems: usize = 19;
+    pub(crate) const LoadItemDefinitions: usize = 20;
+    pub(crate) const GetItemDefinitionIDs: usize = 21;
+    pub(crate) const GetItemDefinitionProperty: usize = 22;
+    pub(crate) const RequestEligiblePromoItemDefinitionsIDs: usize = 23;
+    pub(crate) const GetEligiblePromoItemDefinitionIDs: usize = 24;
+    pub(crate) const StartPurchase: usize = 25;
+    pub(crate) const RequestPrices: usize = 26;
+    pub(crate) const GetNumItemsWithPrices: usize = 27;
+    pub(crate) const GetItemsWithPrices: usize = 28;
+    pub(crate) const GetItemPrice: usize = 29;
+    pub(crate) const StartUpdateProperties: usize = 30;
+    pub(crate) const RemoveProperty: usize = 31;
+    pub(crate) const SetPropertyString: usize = 32;
+    pub(crate) const SetPropertyBool: usize = 33;
+    pub(crate) const SetPropertyInt64: usize = 34;
+    pub(crate) const SetPropertyFloat: usize = 35;
+    pub(crate) const SubmitUpdateProperties: usize = 36;
+    pub(crate) const InspectItem: usize = 37;
+}
+pub(crate) mod ISteamVideo {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMVIDEO_INTERFACE_V002";
+    pub(crate) const VERSION: u8 = 2;
+    pub(crate) const GetVideoURL: usize = 0;
+    pub(crate) const IsBroadcasting: usize = 1;
+    pub(crate) const GetOPFSettings: usize = 2;
+    pub(crate) const GetOPFStringForApp: usize = 3;
+}
+pub(crate) mod ISteamParentalSettings {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMPARENTALSETTINGS_INTERFACE_VERSION001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const BIsParentalLockEnabled: usize = 0;
+    pub(crate) const BIsParentalLockLocked: usize = 1;
+    pub(crate) const BIsAppBlocked: usize = 2;
+    pub(crate) const BIsAppInBlockList: usize = 3;
+    pub(crate) const BIsFeatureBlocked: usize = 4;
+    pub(crate) const BIsFeatureInBlockList: usize = 5;
+}
+pub(crate) mod ISteamRemotePlay {
+    pub(crate) const INTERFACE_VERSION: &str = "STEAMREMOTEPLAY_INTERFACE_VERSION001";
+    pub(crate) const VERSION: u8 = 1;
+    pub(crate) const GetSessionCount: usize = 0;
+    pub(crate) const GetSessionID: usize = 1;
+    pub(crate) const GetSessionSteamID: usize = 2;
+    pub(crate) const GetSessionClientName: usize = 3;
+    pub(crate) const GetSessionClientFormFactor: usize = 4;
+    pub(crate) const BGetSessionClientResolution: usize = 5;
+}
+pub(crate) mod ISteamNetworkingMessages {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamNetworkingMessages002";
+    pub(crate) const VERSION: u8 = 2;
+    pub(crate) const SendMessageToUser: usize = 0;
+    pub(crate) const ReceiveMessagesOnChannel: usize = 1;
+    pub(crate) const AcceptSessionWithUser: usize = 2;
+    pub(crate) const CloseSessionWithUser: usize = 3;
+    pub(crate) const CloseChannelWithUser: usize = 4;
+    pub(crate) const GetSessionConnectionInfo: usize = 5;
+}
+pub(crate) mod ISteamNetworkingSockets {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamNetworkingSockets012";
+    pub(crate) const VERSION: u8 = 12;
+    pub(crate) const CreateListenSocketIP: usize = 0;
+    pub(crate) const ConnectByIPAddress: usize = 1;
+    pub(crate) const CreateListenSocketP2P: usize = 2;
+    pub(crate) const ConnectP2P: usize = 3;
+    pub(crate) const AcceptConnection: usize = 4;
+    pub(crate) const CloseConnection: usize = 5;
+    pub(crate) const CloseListenSocket: usize = 6;
+    pub(crate) const SetConnectionUserData: usize = 7;
+    pub(crate) const GetConnectionUserData: usize = 8;
+    pub(crate) const SetConnectionName: usize = 9;
+    pub(crate) const GetConnectionName: usize = 10;
+    pub(crate) const SendMessageToConnection: usize = 11;
+    pub(crate) const SendMessages: usize = 12;
+    pub(crate) const FlushMessagesOnConnection: usize = 13;
+    pub(crate) const ReceiveMessagesOnConnection: usize = 14;
+    pub(crate) const GetConnectionInfo: usize = 15;
+    pub(crate) const GetConnectionRealTimeStatus: usize = 16;
+    pub(crate) const GetDetailedConnectionStatus: usize = 17;
+    pub(crate) const GetListenSocketAddress: usize = 18;
+    pub(crate) const CreateSocketPair: usize = 19;
+    pub(crate) const ConfigureConnectionLanes: usize = 20;
+    pub(crate) const GetIdentity: usize = 21;
+    pub(crate) const InitAuthentication: usize = 22;
+    pub(crate) const GetAuthenticationStatus: usize = 23;
+    pub(crate) const CreatePollGroup: usize = 24;
+    pub(crate) const DestroyPollGroup: usize = 25;
+    pub(crate) const SetConnectionPollGroup: usize = 26;
+    pub(crate) const ReceiveMessagesOnPollGroup: usize = 27;
+    pub(crate) const ReceivedRelayAuthTicket: usize = 28;
+    pub(crate) const FindRelayAuthTicketForServer: usize = 29;
+    pub(crate) const ConnectToHostedDedicatedServer: usize = 30;
+    pub(crate) const GetHostedDedicatedServerPort: usize = 31;
+    pub(crate) const GetHostedDedicatedServerPOPID: usize = 32;
+    pub(crate) const GetHostedDedicatedServerAddress: usize = 33;
+    pub(crate) const CreateHostedDedicatedServerListenSocket: usize = 34;
+    pub(crate) const GetGameCoordinatorServerLogin: usize = 35;
+    pub(crate) const ConnectP2PCustomSignaling: usize = 36;
+    pub(crate) const ReceivedP2PCustomSignal: usize = 37;
+    pub(crate) const GetCertificateRequest: usize = 38;
+    pub(crate) const SetCertificate: usize = 39;
+    pub(crate) const ResetIdentity: usize = 40;
+    pub(crate) const RunCallbacks: usize = 41;
+    pub(crate) const BeginAsyncRequestFakeIP: usize = 42;
+    pub(crate) const GetFakeIP: usize = 43;
+    pub(crate) const CreateListenSocketP2PFakeIP: usize = 44;
+    pub(crate) const GetRemoteFakeIPForConnection: usize = 45;
+    pub(crate) const CreateFakeUDPPort: usize = 46;
+}
+pub(crate) mod ISteamNetworkingUtils {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamNetworkingUtils004";
+    pub(crate) const VERSION: u8 = 4;
+    pub(crate) const AllocateMessage: usize = 0;
+    pub(crate) const GetRelayNetworkStatus: usize = 1;
+    pub(crate) const GetLocalPingLocation: usize = 2;
+    pub(crate) const EstimatePingTimeBetweenTwoLocations: usize = 3;
+    pub(crate) const EstimatePingTimeFromLocalHost: usize = 4;
+    pub(crate) const ConvertPingLocationToString: usize = 5;
+    pub(crate) const ParsePingLocationString: usize = 6;
+    pub(crate) const CheckPingDataUpToDate: usize = 7;
+    pub(crate) const GetPingToDataCenter: usize = 8;
+    pub(crate) const GetDirectPingToPOP: usize = 9;
+    pub(crate) const GetPOPCount: usize = 10;
+    pub(crate) const GetPOPList: usize = 11;
+    pub(crate) const GetLocalTimestamp: usize = 12;
+    pub(crate) const SetDebugOutputFunction: usize = 13;
+    pub(crate) const GetIPv4FakeIPType: usize = 14;
+    pub(crate) const GetRealIdentityForFakeIP: usize = 15;
+    pub(crate) const GetConfigValueInfo: usize = 18;
+    pub(crate) const IterateGenericEditableConfigValues: usize = 19;
+    pub(crate) const SteamNetworkingIPAddr_ToString: usize = 20;
+    pub(crate) const SteamNetworkingIPAddr_ParseString: usize = 21;
+    pub(crate) const SteamNetworkingIPAddr_GetFakeIPType: usize = 22;
+    pub(crate) const SteamNetworkingIdentity_ToString: usize = 23;
+    pub(crate) const SteamNetworkingIdentity_ParseString: usize = 24;
+}
+pub(crate) mod ISteamGameServer {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamGameServer014";
+    pub(crate) const VERSION: u8 = 14;
+    pub(crate) const SetProduct: usize = 1;
+    pub(crate) const SetGameDescription: usize = 2;
+    pub(crate) const SetModDir: usize = 3;
+    pub(crate) const SetDedicatedServer: usize = 4;
+    pub(crate) const LogOn: usize = 5;
+    pub(crate) const LogOnAnonymous: usize = 6;
+    pub(crate) const LogOff: usize = 7;
+    pub(crate) const BLoggedOn: usize = 8;
+    pub(crate) const BSecure: usize = 9;
+    pub(crate) const GetSteamID: usize = 10;
+    pub(crate) const WasRestartRequested: usize = 11;
+    pub(crate) const SetMaxPlayerCount: usize = 12;
+    pub(crate) const SetBotPlayerCount: usize = 13;
+    pub(crate) const SetServerName: usize = 14;
+    pub(crate) const SetMapName: usize = 15;
+    pub(crate) const SetPasswordProtected: usize = 16;
+    pub(crate) const SetSpectatorPort: usize = 17;
+    pub(crate) const SetSpectatorServerName: usize = 18;
+    pub(crate) const ClearAllKeyValues: usize = 19;
+    pub(crate) const SetKeyValue: usize = 20;
+    pub(crate) const SetGameTags: usize = 21;
+    pub(crate) const SetGameData: usize = 22;
+    pub(crate) const SetRegion: usize = 23;
+    pub(crate) const SetAdvertiseServerActive: usize = 24;
+    pub(crate) const GetAuthSessionTicket: usize = 25;
+    pub(crate) const BeginAuthSession: usize = 26;
+    pub(crate) const EndAuthSession: usize = 27;
+    pub(crate) const CancelAuthTicket: usize = 28;
+    pub(crate) const UserHasLicenseForApp: usize = 29;
+    pub(crate) const RequestUserGroupStatus: usize = 30;
+    pub(crate) const GetGameplayStats: usize = 31;
+    pub(crate) const GetServerReputation: usize = 32;
+}
+pub(crate) mod ISteamGameServerStats {
+    pub(crate) const INTERFACE_VERSION: &str = "SteamGameServerStats001";
+    pub(crate) const VERSION: u8 = 1;
+}
+pub(crate) mod ISteamNetworkingFakeUDPPort {
+    pub(crate) const DestroyFakeUDPPort: usize = 0;
+    pub(crate) const SendMessageToFakeIP: usize = 1;
+    pub(crate) const ReceiveMessages: usize = 2;
+    pub(crate) const ScheduleCleanup: usize = 3;
+}
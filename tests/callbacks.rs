@@ -0,0 +1,415 @@
+//! The callback report sees deliveries through registered `CCallbackBase`
+//! objects and through manual dispatch, without getting in the game's way.
+use once_cell::sync::OnceCell;
+use std::{
+    cell::{Cell, RefCell},
+    ffi::{c_char, c_int, c_void},
+    path::PathBuf,
+    sync::Mutex,
+    time::Duration,
+};
+use steamworks_sys::{
+    CCallbackBase, CallbackMsg_t, ESteamNetworkingConnectionState, ESteamNetworkingIdentityType,
+    HSteamPipe, LobbyEnter_t, SteamAPICall_t, SteamNetConnectionStatusChangedCallback_t,
+};
+
+mod common;
+use common::*;
+
+type RegisterFn = unsafe extern "C" fn(*mut CCallbackBase, c_int);
+type UnregisterFn = unsafe extern "C" fn(*mut CCallbackBase);
+type RegisterCallResultFn = unsafe extern "C" fn(*mut CCallbackBase, SteamAPICall_t);
+type PostFn = unsafe extern "C" fn(c_int, *const c_void, c_int);
+type CompleteFn = unsafe extern "C" fn(SteamAPICall_t, c_int, *const c_void, c_int, bool);
+type NextFn = unsafe extern "C" fn(HSteamPipe, *mut CallbackMsg_t) -> bool;
+type FreeFn = unsafe extern "C" fn(HSteamPipe);
+type CallResultFn =
+    unsafe extern "C" fn(HSteamPipe, SteamAPICall_t, *mut c_void, c_int, c_int, *mut bool) -> bool;
+type WriteFn = unsafe extern "C" fn(*const c_char) -> bool;
+
+/// The mock delivers its whole queue to whichever thread asks, so tests
+/// take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Turn the report and tracing on before konigsberg is first called in this
+/// process.
+fn report_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("callbacks");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(trace_file());
+        std::env::set_var("KONIGSBERG_CALLBACKS", &path);
+        std::env::set_var("KONIGSBERG_TRACE", trace_file());
+        libs();
+        path
+    })
+}
+
+fn trace_file() -> PathBuf {
+    build()
+        .dir
+        .join("callbacks")
+        .join(format!("{}-trace.jsonl", std::process::id()))
+}
+
+/// The trace line for the delivery of callback `id`.
+fn traced(id: c_int) -> String {
+    let trace = std::fs::read_to_string(trace_file()).unwrap();
+    let needle = format!("{{\"callback\":{id},");
+    let lines = trace
+        .lines()
+        .filter(|x| x.starts_with(&needle))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{trace}");
+    lines[0].to_owned()
+}
+
+fn report() -> String {
+    let path = report_file();
+    let path_c = cstr(path.to_str().unwrap());
+    assert!(unsafe { konigsberg::<WriteFn>("konigsberg_write_callbacks")(path_c.as_ptr()) });
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn line_for(report: &str, id: c_int) -> String {
+    let needle = format!("{{\"callback\":{id},");
+    let lines = report
+        .lines()
+        .filter(|x| x.starts_with(&needle))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{report}");
+    lines[0].to_owned()
+}
+
+fn field(line: &str, name: &str) -> String {
+    let start = line.find(&format!("\"{name}\":")).unwrap() + name.len() + 3;
+    let len = line[start..].find([',', '}']).unwrap();
+    line[start..start + len].to_owned()
+}
+
+/// A delivery to an object made by [`callback`]: the object, the first byte of the payload,
+/// and for call results whether it failed and the call.
+type Run = (usize, u8, Option<(bool, SteamAPICall_t)>);
+
+thread_local! {
+    static RUNS: RefCell<Vec<Run>> = const { RefCell::new(Vec::new()) };
+}
+
+unsafe extern "C" fn run(this: *mut CCallbackBase, param: *mut c_void) {
+    std::thread::sleep(Duration::from_millis(1));
+    RUNS.with(|x| {
+        x.borrow_mut()
+            .push((this as usize, *(param as *const u8), None))
+    });
+}
+
+unsafe extern "C" fn run_call_result(
+    this: *mut CCallbackBase,
+    param: *mut c_void,
+    failed: bool,
+    call: SteamAPICall_t,
+) {
+    RUNS.with(|x| {
+        x.borrow_mut()
+            .push((this as usize, *(param as *const u8), Some((failed, call))))
+    });
+}
+
+thread_local! {
+    static SIZE: Cell<c_int> = const { Cell::new(12) };
+}
+
+unsafe extern "C" fn size(_this: *mut CCallbackBase) -> c_int {
+    SIZE.with(Cell::get)
+}
+
+fn bytes<T>(value: &T) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(value as *const T as *const u8, std::mem::size_of::<T>()) }
+}
+
+/// A `CCallback`/`CCallResult` with the Itanium vtable layout.
+fn callback(id: c_int, flags: u8) -> (Box<CCallbackBase>, usize) {
+    let vtable: &'static [usize; 3] = Box::leak(Box::new([
+        run as *const () as usize,
+        run_call_result as *const () as usize,
+        size as *const () as usize,
+    ]));
+    let object = CCallbackBase {
+        vtable_: vtable.as_ptr() as *const _,
+        m_nCallbackFlags: flags,
+        m_iCallback: id,
+    };
+    (Box::new(object), vtable.as_ptr() as usize)
+}
+
+fn runs() -> Vec<Run> {
+    RUNS.with(|x| x.take())
+}
+
+#[test]
+fn observes_registered_callbacks() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let (mut object, vtable) = callback(0, 2);
+    let ptr = &mut *object as *mut CCallbackBase;
+    unsafe {
+        konigsberg::<RegisterFn>("SteamAPI_RegisterCallback")(ptr, 9101);
+        let post = mock::<PostFn>("mock_post_callback");
+        post(9101, [7u8; 12].as_ptr() as *const c_void, 12);
+        post(9101, [8u8; 12].as_ptr() as *const c_void, 12);
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks")();
+        assert_eq!(runs(), [(ptr as usize, 7, None), (ptr as usize, 8, None)]);
+        konigsberg::<UnregisterFn>("SteamAPI_UnregisterCallback")(ptr);
+    }
+    assert_eq!(object.vtable_ as usize, vtable);
+
+    let line = line_for(&report(), 9101);
+    assert_eq!(field(&line, "registered"), "1", "{line}");
+    assert_eq!(field(&line, "game_server"), "true", "{line}");
+    assert_eq!(field(&line, "delivered"), "2", "{line}");
+    assert_eq!(field(&line, "call_results"), "0", "{line}");
+    assert_eq!(field(&line, "size"), "12", "{line}");
+    assert_eq!(field(&line, "bytes"), "24", "{line}");
+    let max = field(&line, "max_handler_ns").parse::<u64>().unwrap();
+    assert!(max >= 1_000_000, "{line}");
+    let total = field(&line, "handler_ns").parse::<u64>().unwrap();
+    assert!(total >= 2 * 1_000_000, "{line}");
+}
+
+#[test]
+fn observes_call_results() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let (mut object, vtable) = callback(9102, 0);
+    let ptr = &mut *object as *mut CCallbackBase;
+    unsafe {
+        konigsberg::<RegisterCallResultFn>("SteamAPI_RegisterCallResult")(ptr, 77);
+        mock::<CompleteFn>("mock_complete_call")(
+            77,
+            9102,
+            [5u8; 8].as_ptr() as *const c_void,
+            8,
+            true,
+        );
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks")();
+    }
+    assert_eq!(runs(), [(ptr as usize, 5, Some((true, 77)))]);
+    // call results are one-shot, so it's back to its own vtable already
+    assert_eq!(object.vtable_ as usize, vtable);
+
+    let line = line_for(&report(), 9102);
+    assert_eq!(field(&line, "registered"), "0", "{line}");
+    assert_eq!(field(&line, "awaited"), "1", "{line}");
+    assert_eq!(field(&line, "delivered"), "0", "{line}");
+    assert_eq!(field(&line, "call_results"), "1", "{line}");
+    assert_eq!(field(&line, "io_failures"), "1", "{line}");
+    // what GetCallbackSizeBytes says, not what was delivered
+    assert_eq!(field(&line, "size"), "12", "{line}");
+
+    // not a callback the bindings know
+    let line = traced(9102);
+    assert!(
+        line.starts_with("{\"callback\":9102,\"call\":77,\"io_failure\":true,\"payload\":null,"),
+        "{line}"
+    );
+}
+
+#[test]
+fn delivers_to_objects_unhooked_mid_dispatch() {
+    type RunFn = unsafe extern "C" fn(*mut CCallbackBase, *mut c_void);
+    type RunCallResultFn =
+        unsafe extern "C" fn(*mut CCallbackBase, *mut c_void, bool, SteamAPICall_t);
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let (mut object, vtable) = callback(9105, 0);
+    let ptr = &mut *object as *mut CCallbackBase;
+    let mut param = [3u8; 12];
+    unsafe {
+        // Steam looked the wrapper up before the unregistration restored
+        // the vtable
+        konigsberg::<RegisterFn>("SteamAPI_RegisterCallback")(ptr, 9105);
+        let run = slot::<RunFn>(ptr as *mut c_void, 0);
+        konigsberg::<UnregisterFn>("SteamAPI_UnregisterCallback")(ptr);
+        assert_eq!(object.vtable_ as usize, vtable);
+        run(ptr, param.as_mut_ptr() as *mut c_void);
+
+        konigsberg::<RegisterCallResultFn>("SteamAPI_RegisterCallResult")(ptr, 79);
+        let run_call_result = slot::<RunCallResultFn>(ptr as *mut c_void, 1);
+        konigsberg::<RegisterCallResultFn>("SteamAPI_UnregisterCallResult")(ptr, 79);
+        assert_eq!(object.vtable_ as usize, vtable);
+        run_call_result(ptr, param.as_mut_ptr() as *mut c_void, false, 79);
+    }
+    assert_eq!(
+        runs(),
+        [
+            (ptr as usize, 3, None),
+            (ptr as usize, 3, Some((false, 79)))
+        ]
+    );
+}
+
+#[test]
+fn observes_manual_dispatch() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let mut msg = CallbackMsg_t {
+        m_hSteamUser: 0,
+        m_iCallback: 0,
+        m_pubParam: std::ptr::null_mut(),
+        m_cubParam: 0,
+    };
+    unsafe {
+        let next = konigsberg::<NextFn>("SteamAPI_ManualDispatch_GetNextCallback");
+        let free = konigsberg::<FreeFn>("SteamAPI_ManualDispatch_FreeLastCallback");
+        mock::<PostFn>("mock_post_callback")(9103, [1u8; 20].as_ptr() as *const c_void, 20);
+        assert!(next(1, &mut msg));
+        assert_eq!((msg.m_iCallback, msg.m_cubParam), (9103, 20));
+        std::thread::sleep(Duration::from_millis(1));
+        free(1);
+
+        mock::<CompleteFn>("mock_complete_call")(
+            78,
+            9104,
+            [2u8; 16].as_ptr() as *const c_void,
+            16,
+            false,
+        );
+        assert!(next(1, &mut msg));
+        assert_eq!(msg.m_iCallback, 703);
+        free(1);
+        let mut data = [0u8; 16];
+        let mut failed = true;
+        assert!(konigsberg::<CallResultFn>(
+            "SteamAPI_ManualDispatch_GetAPICallResult"
+        )(
+            1,
+            78,
+            data.as_mut_ptr() as *mut c_void,
+            16,
+            9104,
+            &mut failed,
+        ));
+        assert!(!failed);
+        assert_eq!(data, [2; 16]);
+        assert!(!next(1, &mut msg));
+    }
+
+    let report = report();
+    let line = line_for(&report, 9103);
+    assert_eq!(field(&line, "delivered"), "1", "{line}");
+    assert_eq!(field(&line, "size"), "20", "{line}");
+    let total = field(&line, "handler_ns").parse::<u64>().unwrap();
+    assert!(total >= 1_000_000, "{line}");
+    let line = line_for(&report, 9104);
+    assert_eq!(field(&line, "delivered"), "0", "{line}");
+    assert_eq!(field(&line, "call_results"), "1", "{line}");
+    assert_eq!(field(&line, "io_failures"), "0", "{line}");
+    assert_eq!(field(&line, "size"), "16", "{line}");
+}
+
+#[test]
+fn decodes_registered_callbacks() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let (mut object, _) = callback(0, 0);
+    let ptr = &mut *object as *mut CCallbackBase;
+    let mut lobby = unsafe { std::mem::zeroed::<LobbyEnter_t>() };
+    lobby.m_ulSteamIDLobby = 109775240917016740;
+    lobby.m_bLocked = true;
+    lobby.m_EChatRoomEnterResponse = 1;
+    SIZE.with(|x| x.set(std::mem::size_of::<LobbyEnter_t>() as c_int));
+    unsafe {
+        konigsberg::<RegisterFn>("SteamAPI_RegisterCallback")(ptr, 504);
+        let payload = bytes(&lobby);
+        mock::<PostFn>("mock_post_callback")(
+            504,
+            payload.as_ptr() as *const c_void,
+            payload.len() as c_int,
+        );
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks")();
+        konigsberg::<UnregisterFn>("SteamAPI_UnregisterCallback")(ptr);
+    }
+    SIZE.with(|x| x.set(12));
+    assert_eq!(runs().len(), 1);
+
+    let line = traced(504);
+    assert!(
+        line.starts_with(concat!(
+            "{\"callback\":504,\"name\":\"LobbyEnter_t\",\"payload\":",
+            "{\"m_ulSteamIDLobby\":109775240917016740,\"m_rgfChatPermissions\":0,",
+            "\"m_bLocked\":true,\"m_EChatRoomEnterResponse\":1},\"thread\":",
+        )),
+        "{line}"
+    );
+    let line = line_for(&report(), 504);
+    assert!(
+        line.starts_with("{\"callback\":504,\"name\":\"LobbyEnter_t\","),
+        "{line}"
+    );
+}
+
+#[test]
+fn decodes_nested_structs() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let mut status = unsafe { std::mem::zeroed::<SteamNetConnectionStatusChangedCallback_t>() };
+    status.m_hConn = 5;
+    status.m_info.m_identityRemote.m_eType =
+        ESteamNetworkingIdentityType::k_ESteamNetworkingIdentityType_SteamID;
+    status.m_info.m_identityRemote.m_cbSize = 8;
+    status.m_info.m_identityRemote.__bindgen_anon_1.m_steamID64 = 76561197960265730;
+    status.m_info.m_addrRemote.__bindgen_anon_1.m_ipv6 =
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 10, 0, 0, 7];
+    status.m_info.m_addrRemote.m_port = 27015;
+    status.m_info.m_eState =
+        ESteamNetworkingConnectionState::k_ESteamNetworkingConnectionState_Connected;
+    for (dst, src) in status
+        .m_info
+        .m_szConnectionDescription
+        .iter_mut()
+        .zip(b"#5 \"test\"")
+    {
+        *dst = *src as c_char;
+    }
+    let mut msg = unsafe { std::mem::zeroed::<CallbackMsg_t>() };
+    unsafe {
+        let mut payload = bytes(&status).to_vec();
+        // not a variant, which has to be shown without reading it as one
+        let old_state =
+            std::mem::offset_of!(SteamNetConnectionStatusChangedCallback_t, m_eOldState);
+        payload[old_state..old_state + 4].copy_from_slice(&1234i32.to_ne_bytes());
+        mock::<PostFn>("mock_post_callback")(
+            1221,
+            payload.as_ptr() as *const c_void,
+            payload.len() as c_int,
+        );
+        assert!(konigsberg::<NextFn>(
+            "SteamAPI_ManualDispatch_GetNextCallback"
+        )(1, &mut msg));
+        konigsberg::<FreeFn>("SteamAPI_ManualDispatch_FreeLastCallback")(1);
+    }
+
+    let line = traced(1221);
+    assert!(
+        line.contains(
+            "\"name\":\"SteamNetConnectionStatusChangedCallback_t\",\"payload\":{\"m_hConn\":5,\"m_info\":{\"m_identityRemote\":\"steamid:[U:1:2]\","
+        ),
+        "{line}"
+    );
+    assert!(
+        line.contains("\"m_addrRemote\":\"10.0.0.7:27015\","),
+        "{line}"
+    );
+    assert!(line.contains("\"m_eState\":3,"), "{line}");
+    assert!(
+        line.contains("\"m_szConnectionDescription\":\"#5 \\\"test\\\"\","),
+        "{line}"
+    );
+    assert!(
+        line.contains("},\"m_eOldState\":1234},\"thread\":"),
+        "{line}"
+    );
+}
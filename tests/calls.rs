@@ -0,0 +1,212 @@
+//! The API call report follows handles from the forwarder that issued them,
+//! or the `CCallResult` waiting for them, to their result.
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_int, c_void},
+    path::PathBuf,
+    sync::Mutex,
+};
+use steamworks_sys::{CCallbackBase, HSteamPipe, SteamAPICall_t};
+
+mod common;
+use common::*;
+
+type IssueFn = unsafe extern "C" fn(*mut c_void, *mut c_void, c_int) -> SteamAPICall_t;
+type CompleteFn = unsafe extern "C" fn(SteamAPICall_t, c_int, *const c_void, c_int, bool);
+type UtilsResultFn =
+    unsafe extern "C" fn(*mut c_void, SteamAPICall_t, *mut c_void, c_int, c_int, *mut bool) -> bool;
+type ManualResultFn =
+    unsafe extern "C" fn(HSteamPipe, SteamAPICall_t, *mut c_void, c_int, c_int, *mut bool) -> bool;
+type RegisterCallResultFn = unsafe extern "C" fn(*mut CCallbackBase, SteamAPICall_t);
+type WriteFn = unsafe extern "C" fn(*const c_char) -> bool;
+
+/// `EncryptedAppTicketResponse_t`, what `RequestEncryptedAppTicket` completes
+/// with.
+const TICKET_RESPONSE: c_int = 154;
+
+/// The mock delivers its whole queue to whichever thread asks, so tests
+/// take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Turn the report on before konigsberg is first called in this process.
+fn report_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("calls");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_CALLS", &path);
+        libs();
+        path
+    })
+}
+
+fn report() -> String {
+    let path = report_file();
+    let path_c = cstr(path.to_str().unwrap());
+    assert!(unsafe { konigsberg::<WriteFn>("konigsberg_write_calls")(path_c.as_ptr()) });
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn line_starting(report: &str, start: &str) -> String {
+    let lines = report
+        .lines()
+        .filter(|x| x.starts_with(start))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{report}");
+    lines[0].to_owned()
+}
+
+fn field(line: &str, name: &str) -> String {
+    let start = line.find(&format!("\"{name}\":")).unwrap() + name.len() + 3;
+    let len = line[start..].find([',', '}']).unwrap();
+    line[start..start + len].to_owned()
+}
+
+unsafe fn issue() -> SteamAPICall_t {
+    konigsberg::<IssueFn>("SteamAPI_ISteamUser_RequestEncryptedAppTicket")(
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        0,
+    )
+}
+
+unsafe fn complete(call: SteamAPICall_t, id: c_int, failed: bool) {
+    mock::<CompleteFn>("mock_complete_call")(
+        call,
+        id,
+        [3u8; 8].as_ptr() as *const c_void,
+        8,
+        failed,
+    );
+}
+
+#[test]
+fn follows_issued_handles_to_their_results() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let mut data = [0u8; 8];
+    let mut failed = true;
+    let (first, second, never) = unsafe {
+        let (first, second, never) = (issue(), issue(), issue());
+        complete(first, TICKET_RESPONSE, false);
+        complete(second, TICKET_RESPONSE, true);
+        assert!(konigsberg::<UtilsResultFn>(
+            "SteamAPI_ISteamUtils_GetAPICallResult"
+        )(
+            std::ptr::null_mut(),
+            first,
+            data.as_mut_ptr() as *mut c_void,
+            8,
+            TICKET_RESPONSE,
+            &mut failed,
+        ));
+        assert!(!failed);
+        // not the callback it completes with, so not a result
+        assert!(!konigsberg::<ManualResultFn>(
+            "SteamAPI_ManualDispatch_GetAPICallResult"
+        )(
+            1,
+            second,
+            data.as_mut_ptr() as *mut c_void,
+            8,
+            TICKET_RESPONSE + 1,
+            &mut failed,
+        ));
+        assert!(konigsberg::<ManualResultFn>(
+            "SteamAPI_ManualDispatch_GetAPICallResult"
+        )(
+            1,
+            second,
+            data.as_mut_ptr() as *mut c_void,
+            8,
+            TICKET_RESPONSE,
+            &mut failed,
+        ));
+        assert!(failed);
+        (first, second, never)
+    };
+    assert_ne!(first, second);
+
+    let line = line_starting(
+        &report(),
+        "{\"fn\":\"SteamAPI_ISteamUser_RequestEncryptedAppTicket\",",
+    );
+    assert_eq!(field(&line, "issued"), "3", "{line}");
+    assert_eq!(field(&line, "invalid"), "0", "{line}");
+    assert_eq!(field(&line, "completed"), "2", "{line}");
+    assert_eq!(field(&line, "failed"), "1", "{line}");
+    assert_ne!(field(&line, "mean_latency_ns"), "null", "{line}");
+    assert!(
+        line.contains(&format!("\"pending\":[{{\"call\":{never},\"age_ns\":")),
+        "{line}"
+    );
+}
+
+#[test]
+fn counts_invalid_handles() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    // the mock's stubs return zero, `k_uAPICallInvalid`
+    let call = unsafe {
+        konigsberg::<unsafe extern "C" fn(*mut c_void, *const c_char) -> SteamAPICall_t>(
+            "SteamAPI_ISteamUserStats_FindLeaderboard",
+        )(std::ptr::null_mut(), cstr("Feet Traveled").as_ptr())
+    };
+    assert_eq!(call, 0);
+    let line = line_starting(
+        &report(),
+        "{\"fn\":\"SteamAPI_ISteamUserStats_FindLeaderboard\",",
+    );
+    assert_eq!(field(&line, "issued"), "0", "{line}");
+    assert_eq!(field(&line, "invalid"), "1", "{line}");
+    assert!(line.ends_with("\"pending\":[]}"), "{line}");
+}
+
+unsafe extern "C" fn run(_this: *mut CCallbackBase, _param: *mut c_void) {}
+
+unsafe extern "C" fn run_call_result(
+    _this: *mut CCallbackBase,
+    _param: *mut c_void,
+    _failed: bool,
+    _call: SteamAPICall_t,
+) {
+}
+
+unsafe extern "C" fn size(_this: *mut CCallbackBase) -> c_int {
+    8
+}
+
+#[test]
+fn follows_handles_from_call_result_registrations() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    // a `CCallResult` with the Itanium vtable layout
+    let vtable: &'static [usize; 3] = Box::leak(Box::new([
+        run as *const () as usize,
+        run_call_result as *const () as usize,
+        size as *const () as usize,
+    ]));
+    let mut object = CCallbackBase {
+        vtable_: vtable.as_ptr() as *const _,
+        m_nCallbackFlags: 0,
+        m_iCallback: 9201,
+    };
+    unsafe {
+        // handles a game got from interface methods, not forwarders
+        let register = konigsberg::<RegisterCallResultFn>("SteamAPI_RegisterCallResult");
+        register(&mut object, 91);
+        register(&mut object, 92);
+        complete(91, 9201, true);
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks")();
+    }
+    let line = line_starting(&report(), "{\"fn\":null,\"callback\":9201,");
+    assert_eq!(field(&line, "issued"), "2", "{line}");
+    assert_eq!(field(&line, "completed"), "1", "{line}");
+    assert_eq!(field(&line, "failed"), "1", "{line}");
+    assert!(
+        line.contains("\"pending\":[{\"call\":92,\"age_ns\":"),
+        "{line}"
+    );
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use once_cell::sync::OnceCell;
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, c_void, CStr},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub struct Build {
+    pub dir: PathBuf,
+    pub konigsberg: PathBuf,
+    pub mock: PathBuf,
+    pub plugin: PathBuf,
+}
+
+/// Build konigsberg, the mock library and the example plugin.
+pub fn build() -> &'static Build {
+    static CELL: OnceCell<Build> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let exe = std::env::current_exe().unwrap();
+        let profile_dir = exe.parent().and_then(|x| x.parent()).unwrap();
+        let mut cmd = Command::new(env!("CARGO"));
+        cmd.args(["build", "-q", "-p", "konigsberg", "-p", "steam-api-mock"]);
+        cmd.args(["-p", "konigsberg-plugin-example"]);
+        if profile_dir.file_name().is_some_and(|x| x == "release") {
+            cmd.arg("--release");
+        }
+        let status = cmd.status().expect("failed to run cargo");
+        assert!(status.success(), "failed to build test libraries");
+        Build {
+            dir: profile_dir.join("konigsberg-test"),
+            konigsberg: profile_dir.join(format!("{DLL_PREFIX}konigsberg{DLL_SUFFIX}")),
+            mock: profile_dir.join(format!("{DLL_PREFIX}steam_api_mock{DLL_SUFFIX}")),
+            plugin: profile_dir.join(format!("{DLL_PREFIX}konigsberg_plugin_example{DLL_SUFFIX}")),
+        }
+    })
+}
+
+pub struct Libs {
+    pub konigsberg: libloading::Library,
+    pub mock: libloading::Library,
+}
+
+/// Load konigsberg with `KONIGSBERG_LIB` pointing it at the mock library.
+pub fn libs() -> &'static Libs {
+    static CELL: OnceCell<Libs> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let build = build();
+        std::env::set_var("KONIGSBERG_LIB", &build.mock);
+        load(&build.konigsberg, &build.mock)
+    })
+}
+
+/// Load konigsberg from `konigsberg`, expecting it to pick up the original
+/// library from `mock`.
+pub fn load(konigsberg: &Path, mock: &Path) -> Libs {
+    unsafe {
+        Libs {
+            konigsberg: libloading::Library::new(konigsberg).unwrap(),
+            mock: libloading::Library::new(mock).unwrap(),
+        }
+    }
+}
+
+pub unsafe fn konigsberg<T: Copy>(name: &str) -> T {
+    *libs().konigsberg.get::<T>(name.as_bytes()).unwrap()
+}
+
+pub unsafe fn mock<T: Copy>(name: &str) -> T {
+    *libs().mock.get::<T>(name.as_bytes()).unwrap()
+}
+
+/// The mock export last called on this thread.
+pub fn last_call() -> Option<String> {
+    unsafe {
+        let name = mock::<unsafe extern "C" fn() -> *const c_char>("mock_last_call")();
+        (!name.is_null()).then(|| CStr::from_ptr(name).to_str().unwrap().to_owned())
+    }
+}
+
+/// Read a function pointer out of an object's vtable.
+pub unsafe fn slot<T: Copy>(object: *mut c_void, index: usize) -> T {
+    assert!(!object.is_null());
+    let vtable = *(object as *const *const T);
+    *vtable.add(index)
+}
+
+/// Address of the function a vtable slot points to.
+pub unsafe fn slot_addr(object: *mut c_void, index: usize) -> usize {
+    slot::<usize>(object, index)
+}
+
+pub fn cstr(s: &str) -> std::ffi::CString {
+    std::ffi::CString::new(s).unwrap()
+}
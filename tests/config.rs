@@ -0,0 +1,79 @@
+use once_cell::sync::OnceCell;
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, c_void, CStr},
+    path::PathBuf,
+};
+
+mod common;
+use common::*;
+
+struct Setup {
+    libs: Libs,
+    shim_dir: PathBuf,
+}
+
+/// konigsberg in its own directory, with a `konigsberg.cfg` pointing at the
+/// mock library in a sibling directory.
+fn setup() -> &'static Setup {
+    static CELL: OnceCell<Setup> = OnceCell::new();
+    CELL.get_or_init(|| {
+        for var in ["KONIGSBERG_CONFIG", "KONIGSBERG_LIB", "KONIGSBERG_TRACE"] {
+            std::env::remove_var(var);
+        }
+        let build = build();
+        let dir = build.dir.join("config");
+        let _ = std::fs::remove_dir_all(&dir);
+        let shim_dir = dir.join("shim");
+        let orig_dir = dir.join("orig");
+        std::fs::create_dir_all(&shim_dir).unwrap();
+        std::fs::create_dir_all(&orig_dir).unwrap();
+        let shim = shim_dir.join(build.konigsberg.file_name().unwrap());
+        let mock = orig_dir.join(format!("{DLL_PREFIX}steam_api{DLL_SUFFIX}"));
+        std::fs::copy(&build.konigsberg, &shim).unwrap();
+        std::fs::copy(&build.mock, &mock).unwrap();
+        std::fs::write(
+            shim_dir.join("konigsberg.cfg"),
+            format!(
+                "# the first candidate doesn't exist\n\
+                 lib = ../orig/missing{DLL_SUFFIX}\n\
+                 lib = ../orig/{DLL_PREFIX}steam_api{DLL_SUFFIX}\n\
+                 trace = trace.jsonl  # next to the config\n"
+            ),
+        )
+        .unwrap();
+        Setup {
+            libs: load(&shim, &mock),
+            shim_dir,
+        }
+    })
+}
+
+#[test]
+fn original_library_is_found_through_config_file() {
+    let setup = setup();
+    let ver = cstr("STEAMAPPS_INTERFACE_VERSION008");
+    let apps = unsafe {
+        let find = setup
+            .libs
+            .konigsberg
+            .get::<unsafe extern "C" fn(i32, *const c_char) -> *mut c_void>(
+                b"SteamInternal_FindOrCreateUserInterface",
+            )
+            .unwrap();
+        find(1, ver.as_ptr())
+    };
+    assert!(!apps.is_null());
+    let last = unsafe {
+        let last_call = setup
+            .libs
+            .mock
+            .get::<unsafe extern "C" fn() -> *const c_char>(b"mock_last_call")
+            .unwrap();
+        CStr::from_ptr(last_call()).to_str().unwrap().to_owned()
+    };
+    assert_eq!(last, "SteamInternal_FindOrCreateUserInterface");
+
+    let trace = std::fs::read_to_string(setup.shim_dir.join("trace.jsonl")).unwrap();
+    assert!(trace.contains("\"fn\":\"SteamInternal_FindOrCreateUserInterface\""));
+}
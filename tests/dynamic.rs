@@ -0,0 +1,65 @@
+//! Exports konigsberg has no signature for, forwarded by trampolines.
+#![cfg(all(unix, target_arch = "x86_64"))]
+
+use std::ffi::{c_char, c_void};
+
+mod common;
+use common::*;
+
+#[test]
+fn forwards_functions_missing_from_the_bindings() {
+    unsafe {
+        let init_anonymous =
+            konigsberg::<unsafe extern "C" fn() -> bool>("SteamAPI_InitAnonymousUser");
+        for _ in 0..2 {
+            assert!(!init_anonymous());
+            assert_eq!(last_call().as_deref(), Some("SteamAPI_InitAnonymousUser"));
+        }
+    }
+}
+
+#[test]
+fn passes_arguments_through() {
+    type BreakpadFn = unsafe extern "C" fn(
+        *const c_char,
+        *const c_char,
+        *const c_char,
+        bool,
+        *mut c_void,
+        Option<unsafe extern "C" fn()>,
+    );
+    unsafe extern "C" fn callback() {}
+
+    unsafe {
+        let handler = konigsberg::<BreakpadFn>("SteamAPI_UseBreakpadCrashHandler");
+        let args = mock::<unsafe extern "C" fn(*mut [usize; 6])>("mock_breakpad_args");
+        // the first call goes through resolution, the second doesn't
+        for i in 0..2 {
+            handler(
+                (0x1000 + i) as *const c_char,
+                (0x2000 + i) as *const c_char,
+                (0x3000 + i) as *const c_char,
+                true,
+                (0x4000 + i) as *mut c_void,
+                Some(callback),
+            );
+            assert_eq!(
+                last_call().as_deref(),
+                Some("SteamAPI_UseBreakpadCrashHandler")
+            );
+            let mut out = [0; 6];
+            args(&mut out);
+            assert_eq!(
+                out,
+                [
+                    0x1000 + i,
+                    0x2000 + i,
+                    0x3000 + i,
+                    1,
+                    0x4000 + i,
+                    callback as *const () as usize
+                ]
+            );
+        }
+    }
+}
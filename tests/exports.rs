@@ -0,0 +1,49 @@
+mod common;
+
+use std::process::Command;
+
+#[test]
+fn diffs_exports_against_the_original() {
+    let build = common::build();
+    let output = Command::new(env!("CARGO_BIN_EXE_konigsberg-exports"))
+        .arg(&build.konigsberg)
+        .arg(&build.mock)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        concat!(
+            "not forwarded: mock_breakpad_args\n",
+            "not forwarded: mock_complete_call\n",
+            "not forwarded: mock_declared_slot\n",
+            "not forwarded: mock_last_call\n",
+            "not forwarded: mock_post_callback\n",
+            "not forwarded: mock_set_object\n",
+            "not in the original: SteamAPI_SteamApps_v009\n",
+        )
+    );
+}
+
+#[test]
+fn same_library_has_no_differences() {
+    let build = common::build();
+    let output = Command::new(env!("CARGO_BIN_EXE_konigsberg-exports"))
+        .arg(&build.mock)
+        .arg(&build.mock)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn unreadable_library_is_an_error() {
+    let build = common::build();
+    let output = Command::new(env!("CARGO_BIN_EXE_konigsberg-exports"))
+        .arg(&build.konigsberg)
+        .arg(build.dir.join("nonexistent.so"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
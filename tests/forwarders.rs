@@ -0,0 +1,33 @@
+//! Calls every `reexport!` forwarder and checks it reached the same-named
+//! symbol in the original library.
+#![allow(
+    non_snake_case,
+    non_camel_case_types,
+    clippy::missing_safety_doc,
+    improper_ctypes_definitions
+)]
+
+use steamworks_sys::*;
+
+mod common;
+
+include!("../mock/src/types.rs");
+
+macro_rules! reexport {
+    (fn $name:ident($( $(#[$($fmt:tt)*])? $arg:ident : $type:ty ),*) $(-> $(#[$($rfmt:tt)*])? $ret:ty)?) => {
+        reexport!(stringify!($name), fn $name($( $arg : $type ),*) $(-> $ret)?);
+    };
+    ($link:expr, fn $name:ident($( $(#[$($fmt:tt)*])? $arg:ident : $type:ty ),*) $(-> $(#[$($rfmt:tt)*])? $ret:ty)?) => {
+        #[test]
+        fn $name() {
+            unsafe {
+                common::konigsberg::<unsafe extern "C" fn($($type),*) $(-> $ret)?>($link)(
+                    $( std::mem::zeroed::<$type>() ),*
+                );
+            }
+            assert_eq!(common::last_call().as_deref(), Some(stringify!($name)));
+        }
+    };
+}
+
+include!("../src/reexports.rs");
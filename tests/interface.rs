@@ -0,0 +1,228 @@
+//! Property tests for the interface version parser. Inputs come from a
+//! seeded xorshift generator so failures are reproducible.
+use konigsberg_core::interface::{Interface, InterfaceKind};
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[test]
+fn parses_every_naming_scheme() {
+    for (name, kind, version) in [
+        ("STEAMAPPS_INTERFACE_VERSION008", InterfaceKind::Apps, 8),
+        ("SteamUser023", InterfaceKind::User, 23),
+        (
+            "SteamNetworkingSockets012",
+            InterfaceKind::NetworkingSockets,
+            12,
+        ),
+        ("STEAMUGC_INTERFACE_VERSION016", InterfaceKind::Ugc, 16),
+        (
+            "STEAMHTMLSURFACE_INTERFACE_VERSION_005",
+            InterfaceKind::HtmlSurface,
+            5,
+        ),
+        ("STEAMINVENTORY_INTERFACE_V003", InterfaceKind::Inventory, 3),
+        ("SteamMatchGameSearch001", InterfaceKind::GameSearch, 1),
+        (
+            "SteamMatchMakingServers002",
+            InterfaceKind::MatchMakingServers,
+            2,
+        ),
+        ("SteamMatchMaking009", InterfaceKind::MatchMaking, 9),
+        ("SteamUser9", InterfaceKind::User, 9),
+        ("SteamUser0021", InterfaceKind::User, 21),
+    ] {
+        assert_eq!(
+            Interface::parse(name.as_bytes()),
+            Some(Interface::new(kind, version)),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn rejects_malformed_names() {
+    for name in [
+        "",
+        "SteamUser",
+        "123",
+        "SteamUser256",
+        "SteamUser1000",
+        "steamuser021",
+        "SteamUser 021",
+        "SteamUser-21",
+        "SteamUser+21",
+        "SteamUser021\0",
+        "SteamUsers021",
+        "SteamUse021",
+        "XSteamUser021",
+        "SteamUser0x21",
+        "SteamUser\u{e9}021",
+        "\u{e9}01",
+    ] {
+        assert_eq!(Interface::parse(name.as_bytes()), None, "{name:?}");
+    }
+}
+
+#[test]
+fn knows_every_interface_in_the_bindings() {
+    let slots = include_str!("../src/vtable_slots.rs");
+    let names = slots
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("pub(crate) const INTERFACE_VERSION: &str = \"")
+        })
+        .map(|rest| rest.trim_end_matches("\";"))
+        .collect::<Vec<_>>();
+    assert!(!names.is_empty());
+    for name in names {
+        let interface = Interface::parse(name.as_bytes());
+        assert!(interface.is_some(), "{name}");
+        assert_eq!(interface.unwrap().to_string(), name);
+    }
+}
+
+#[test]
+fn prefixes_are_unambiguous() {
+    for (i, a) in InterfaceKind::ALL.iter().enumerate() {
+        for b in &InterfaceKind::ALL[i + 1..] {
+            assert_ne!(a.prefix(), b.prefix());
+        }
+        let prefix = a.prefix();
+        assert!(!prefix.ends_with(|c: char| c.is_ascii_digit()), "{prefix}");
+    }
+}
+
+#[test]
+fn round_trips_every_version() {
+    for &kind in InterfaceKind::ALL {
+        for version in 0..=u8::MAX {
+            let interface = Interface::new(kind, version);
+            let name = interface.to_string();
+            assert_eq!(Interface::parse(name.as_bytes()), Some(interface), "{name}");
+            let unpadded = format!("{}{version}", kind.prefix());
+            assert_eq!(Interface::parse(unpadded.as_bytes()), Some(interface));
+        }
+        for version in 256..=999 {
+            let name = format!("{}{version}", kind.prefix());
+            assert_eq!(Interface::parse(name.as_bytes()), None, "{name}");
+        }
+    }
+}
+
+/// Whatever parses is a known prefix followed by digits, and its canonical
+/// form parses back to the same interface.
+fn check(name: &[u8]) {
+    if let Some(interface) = Interface::parse(name) {
+        let prefix = interface.kind.prefix().as_bytes();
+        assert!(name.starts_with(prefix), "{name:?}");
+        assert!(
+            name[prefix.len()..].iter().all(u8::is_ascii_digit),
+            "{name:?}"
+        );
+        assert_eq!(
+            Interface::parse(interface.to_string().as_bytes()),
+            Some(interface)
+        );
+    }
+}
+
+#[test]
+fn random_bytes_never_panic() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..100_000 {
+        let len = rng.below(48);
+        let name = (0..len).map(|_| rng.next() as u8).collect::<Vec<_>>();
+        check(&name);
+    }
+}
+
+#[test]
+fn mutated_names_never_panic() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    // bytes that are likely to land on an edge: digits, separators, UTF-8
+    // lead and continuation bytes
+    let interesting = b"0123456789_V\xc3\xa9\xe2\x82\xac\xff\x00";
+    for _ in 0..100_000 {
+        let kind = InterfaceKind::ALL[rng.below(InterfaceKind::ALL.len())];
+        let mut name = Interface::new(kind, rng.next() as u8)
+            .to_string()
+            .into_bytes();
+        for _ in 0..rng.below(4) {
+            let byte = interesting[rng.below(interesting.len())];
+            let at = rng.below(name.len() + 1);
+            match rng.below(3) {
+                0 => name.insert(at, byte),
+                1 if at < name.len() => name[at] = byte,
+                _ => name.truncate(at),
+            }
+        }
+        check(&name);
+    }
+}
+
+#[test]
+fn knows_every_accessor_in_the_bindings() {
+    let files = [
+        include_str!("../src/reexports.rs"),
+        include_str!("../src/linux_windows_reexports.rs"),
+        include_str!("../src/macos_reexports.rs"),
+    ];
+    let accessors = files
+        .iter()
+        .flat_map(|file| file.lines())
+        .filter_map(|line| line.strip_prefix("reexport!(fn "))
+        .map(|rest| &rest[..rest.find('(').unwrap()])
+        .filter(|name| konigsberg_core::interface::is_accessor(name))
+        .collect::<Vec<_>>();
+    assert!(accessors.contains(&"SteamAPI_SteamUser_v021"));
+    for name in accessors {
+        assert!(Interface::from_accessor(name).is_some(), "{name}");
+    }
+    for (name, interface) in [
+        (
+            "SteamAPI_SteamMatchmaking_v009",
+            Some((InterfaceKind::MatchMaking, 9)),
+        ),
+        (
+            "SteamAPI_SteamGameServerHTTP_v003",
+            Some((InterfaceKind::Http, 3)),
+        ),
+        (
+            "SteamAPI_SteamGameServer_v014",
+            Some((InterfaceKind::GameServer, 14)),
+        ),
+        (
+            "SteamAPI_SteamNetworkingSockets_SteamAPI_v012",
+            Some((InterfaceKind::NetworkingSockets, 12)),
+        ),
+        ("SteamAPI_SteamUser_v", None),
+        ("SteamAPI_SteamNope_v001", None),
+        ("SteamAPI_ISteamUser_GetSteamID", None),
+    ] {
+        assert_eq!(
+            Interface::from_accessor(name),
+            interface.map(|(kind, version)| Interface::new(kind, version)),
+            "{name}"
+        );
+    }
+    assert!(!konigsberg_core::interface::is_accessor(
+        "SteamAPI_ISteamUser_BLoggedOn"
+    ));
+    assert!(!konigsberg_core::interface::is_accessor(
+        "SteamAPI_SteamUser_v"
+    ));
+}
@@ -0,0 +1,121 @@
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_void},
+    path::PathBuf,
+};
+use steamworks_sys::{HSteamPipe, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type GetInterfaceFn =
+    unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void;
+type WriteFn = unsafe extern "C" fn(*const c_char) -> bool;
+
+/// Turn the inventory on before konigsberg is first called in this process.
+fn inventory_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("inventory");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_INVENTORY", &path);
+        libs();
+        path
+    })
+}
+
+fn find_or_create(ver: &str) -> *mut c_void {
+    inventory_file();
+    unsafe {
+        konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr(ver).as_ptr(),
+        )
+    }
+}
+
+fn line_for(report: &str, name: &str) -> String {
+    let needle = format!("{{\"interface\":\"{name}\",");
+    let lines = report
+        .lines()
+        .filter(|x| x.starts_with(&needle))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{report}");
+    lines[0].to_owned()
+}
+
+#[test]
+fn records_every_source_once_per_version() {
+    find_or_create("SteamUser021");
+    find_or_create("SteamUser21");
+    find_or_create("SteamNotAnInterface001");
+    let client = find_or_create("SteamClient020");
+    unsafe {
+        konigsberg::<unsafe extern "C" fn() -> *mut c_void>("SteamAPI_SteamUser_v021")();
+        konigsberg::<unsafe extern "C" fn() -> *mut c_void>("SteamAPI_SteamGameServerUGC_v016")();
+        konigsberg::<unsafe extern "C" fn() -> *mut c_void>("SteamAPI_SteamApps_v008")();
+        slot::<GetInterfaceFn>(client, 12)(
+            client,
+            1,
+            1,
+            cstr("STEAMHTMLSURFACE_INTERFACE_VERSION_005").as_ptr(),
+        );
+    }
+
+    let path = build().dir.join("inventory").join("on-demand.jsonl");
+    let path_c = cstr(path.to_str().unwrap());
+    assert!(unsafe { konigsberg::<WriteFn>("konigsberg_write_inventory")(path_c.as_ptr()) });
+    let report = std::fs::read_to_string(&path).unwrap();
+
+    let user = line_for(&report, "SteamUser021");
+    assert!(user.contains("\"kind\":\"User\",\"version\":21,"), "{user}");
+    assert!(user.contains("\"calls\":3,"), "{user}");
+    assert!(
+        user.contains(
+            "\"via\":[\"SteamInternal_FindOrCreateUserInterface\",\"SteamAPI_SteamUser_v021\"]"
+        ),
+        "{user}"
+    );
+    let unknown = line_for(&report, "SteamNotAnInterface001");
+    assert!(
+        unknown.contains("\"kind\":null,\"version\":null,"),
+        "{unknown}"
+    );
+    let ugc = line_for(&report, "STEAMUGC_INTERFACE_VERSION016");
+    assert!(
+        ugc.contains("\"SteamAPI_SteamGameServerUGC_v016\""),
+        "{ugc}"
+    );
+    let apps = line_for(&report, "STEAMAPPS_INTERFACE_VERSION008");
+    assert!(apps.contains("\"SteamAPI_SteamApps_v008\""), "{apps}");
+    let html = line_for(&report, "STEAMHTMLSURFACE_INTERFACE_VERSION_005");
+    assert!(
+        html.contains("\"via\":[\"ISteamClient::GetISteamGenericInterface\"]"),
+        "{html}"
+    );
+
+    let first_seen = |line: &str| -> u64 {
+        let rest = &line[line.find("\"first_seen_ms\":").unwrap() + 16..];
+        rest[..rest.find(',').unwrap()].parse().unwrap()
+    };
+    assert!(first_seen(&user) <= first_seen(&html));
+}
+
+#[test]
+fn shutdown_writes_the_report() {
+    find_or_create("SteamUtils010");
+    unsafe { konigsberg::<unsafe extern "C" fn()>("SteamAPI_Shutdown")() };
+    assert_eq!(last_call().as_deref(), Some("SteamAPI_Shutdown"));
+    let report = std::fs::read_to_string(inventory_file()).unwrap();
+    let utils = line_for(&report, "SteamUtils010");
+    assert!(
+        utils.contains("\"kind\":\"Utils\",\"version\":10,"),
+        "{utils}"
+    );
+
+    unsafe { konigsberg::<unsafe extern "C" fn()>("SteamGameServer_Shutdown")() };
+    assert_eq!(last_call().as_deref(), Some("SteamGameServer_Shutdown"));
+}
@@ -0,0 +1,301 @@
+//! Lint checks flag calls made while Steam isn't running, late callbacks,
+//! auth tickets and sessions left open and threads that never release
+//! their memory.
+use once_cell::sync::OnceCell;
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, c_int, c_void},
+    path::PathBuf,
+    sync::Mutex,
+    time::Duration,
+};
+use steamworks_sys::{CSteamID, EBeginAuthSessionResult, EServerMode, HAuthTicket, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type GetSteamIdFn = unsafe extern "C" fn(*mut c_void) -> u64;
+type GetTicketFn = unsafe extern "C" fn(*mut c_void, *mut c_void, c_int, *mut u32) -> HAuthTicket;
+type CancelTicketFn = unsafe extern "C" fn(*mut c_void, HAuthTicket);
+type BeginSessionFn =
+    unsafe extern "C" fn(*mut c_void, *const c_void, c_int, u64) -> EBeginAuthSessionResult;
+type EndSessionFn = unsafe extern "C" fn(*mut c_void, u64);
+type BeginSessionMethod =
+    unsafe extern "C" fn(*mut c_void, *const c_void, c_int, CSteamID) -> EBeginAuthSessionResult;
+type GameServerInitFn =
+    unsafe extern "C" fn(u32, u16, u16, u16, EServerMode, *const c_char) -> bool;
+type WriteFn = unsafe extern "C" fn(*const c_char) -> bool;
+
+/// `[U:1:2]` and `[U:1:3]`.
+const PLAYER: u64 = 0x0110_0001_0000_0002;
+const OTHER_PLAYER: u64 = 0x0110_0001_0000_0003;
+
+/// Init and shutdown change what every test sees, so tests take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Turn the checks on before konigsberg is first called in this process.
+fn report_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("lint");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_LINT", &path);
+        std::env::set_var("KONIGSBERG_LINT_CALLBACK_GAP", "20");
+        libs();
+        path
+    })
+}
+
+fn report() -> String {
+    let path = report_file();
+    let path_c = cstr(path.to_str().unwrap());
+    assert!(unsafe { konigsberg::<WriteFn>("konigsberg_write_lint")(path_c.as_ptr()) });
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn lint(report: &str, name: &str) -> String {
+    let start = format!("{{\"lint\":\"{name}\",");
+    let lines = report
+        .lines()
+        .filter(|x| x.starts_with(&start))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{report}");
+    lines[0].to_owned()
+}
+
+fn field(line: &str, name: &str) -> String {
+    let start = line.find(&format!("\"{name}\":")).unwrap() + name.len() + 3;
+    let len = line[start..].find([',', '}']).unwrap();
+    line[start..start + len].to_owned()
+}
+
+unsafe fn get_steam_id() {
+    konigsberg::<GetSteamIdFn>("SteamAPI_ISteamUser_GetSteamID")(std::ptr::null_mut());
+}
+
+#[test]
+fn flags_calls_while_steam_isnt_running() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    unsafe {
+        get_steam_id();
+        // doesn't need Steam running
+        konigsberg::<unsafe extern "C" fn() -> *const c_char>("SteamAPI_GetSteamInstallPath")();
+        assert!(konigsberg::<unsafe extern "C" fn() -> bool>(
+            "SteamAPI_Init"
+        )());
+        get_steam_id();
+        let run_callbacks = konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks");
+        run_callbacks();
+        std::thread::sleep(Duration::from_millis(50));
+        run_callbacks();
+        // stopped running them before shutting down
+        std::thread::sleep(Duration::from_millis(50));
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_Shutdown")();
+        get_steam_id();
+        get_steam_id();
+        konigsberg::<unsafe extern "C" fn(*mut c_void) -> *const c_char>(
+            "SteamAPI_ISteamFriends_GetPersonaName",
+        )(std::ptr::null_mut());
+
+        // running again, through the older entry points
+        assert!(konigsberg::<unsafe extern "C" fn() -> bool>(
+            "SteamAPI_InitSafe"
+        )());
+        assert_eq!(last_call().as_deref(), Some("SteamAPI_InitSafe"));
+        get_steam_id();
+        assert!(konigsberg::<GameServerInitFn>("SteamGameServer_InitSafe")(
+            0,
+            0,
+            27015,
+            27016,
+            EServerMode::eServerModeNoAuthentication,
+            cstr("1.0").as_ptr(),
+        ));
+        assert_eq!(last_call().as_deref(), Some("SteamGameServer_InitSafe"));
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_Shutdown")();
+        // the game server is still running
+        get_steam_id();
+        konigsberg::<unsafe extern "C" fn()>("SteamGameServer_Shutdown")();
+    }
+    let report = report();
+
+    let line = lint(&report, "before_init");
+    assert_eq!(field(&line, "init_seen"), "true", "{line}");
+    assert_eq!(
+        field(&line, "SteamAPI_ISteamUser_GetSteamID"),
+        "1",
+        "{line}"
+    );
+    assert!(!line.contains("SteamAPI_GetSteamInstallPath"), "{line}");
+
+    let line = lint(&report, "after_shutdown");
+    assert_eq!(
+        field(&line, "SteamAPI_ISteamUser_GetSteamID"),
+        "2",
+        "{line}"
+    );
+    assert_eq!(
+        field(&line, "SteamAPI_ISteamFriends_GetPersonaName"),
+        "1",
+        "{line}"
+    );
+
+    let line = lint(&report, "callback_gap");
+    assert_eq!(field(&line, "count"), "2", "{line}");
+    assert_eq!(field(&line, "threshold_ms"), "20", "{line}");
+    assert!(
+        field(&line, "max_gap_ms").parse::<u64>().unwrap() >= 50,
+        "{line}"
+    );
+}
+
+/// A game server without the client, through its own copy of konigsberg so
+/// nothing the other tests did is in its report.
+#[test]
+fn game_server_init_alone_isnt_flagged() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let path = report_file().with_extension("game_server.jsonl");
+    let lib = path.with_file_name(format!(
+        "{DLL_PREFIX}lint_game_server_{}{DLL_SUFFIX}",
+        std::process::id()
+    ));
+    std::fs::copy(&build().konigsberg, &lib).unwrap();
+    let konigsberg = unsafe { libloading::Library::new(&lib).unwrap() };
+    let report = unsafe {
+        for name in ["SteamInternal_GameServer_Init", "SteamGameServer_InitSafe"] {
+            assert!(
+                konigsberg.get::<GameServerInitFn>(name.as_bytes()).unwrap()(
+                    0,
+                    0,
+                    27015,
+                    27016,
+                    EServerMode::eServerModeNoAuthentication,
+                    cstr("1.0").as_ptr(),
+                )
+            );
+            konigsberg
+                .get::<unsafe extern "C" fn()>(b"SteamGameServer_Shutdown")
+                .unwrap()();
+        }
+        let path_c = cstr(path.to_str().unwrap());
+        assert!(konigsberg.get::<WriteFn>(b"konigsberg_write_lint").unwrap()(path_c.as_ptr()));
+        std::fs::read_to_string(&path).unwrap()
+    };
+    for name in ["before_init", "after_shutdown"] {
+        let line = lint(&report, name);
+        assert_eq!(field(&line, "count"), "0", "{line}");
+    }
+}
+
+#[test]
+fn tracks_auth_tickets_and_sessions() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let mut ticket = [0u8; 16];
+    let mut len = 0;
+    let (cancelled, forgotten, forgotten_by_method, server_ticket) = unsafe {
+        let get_ticket = konigsberg::<GetTicketFn>("SteamAPI_ISteamUser_GetAuthSessionTicket");
+        let cancelled = get_ticket(std::ptr::null_mut(), ticket.as_mut_ptr() as _, 16, &mut len);
+        let forgotten = get_ticket(std::ptr::null_mut(), ticket.as_mut_ptr() as _, 16, &mut len);
+        konigsberg::<CancelTicketFn>("SteamAPI_ISteamUser_CancelAuthTicket")(
+            std::ptr::null_mut(),
+            cancelled,
+        );
+        let server_ticket = konigsberg::<GetTicketFn>(
+            "SteamAPI_ISteamGameServer_GetAuthSessionTicket",
+        )(std::ptr::null_mut(), ticket.as_mut_ptr() as _, 16, &mut len);
+        konigsberg::<CancelTicketFn>("SteamAPI_ISteamGameServer_CancelAuthTicket")(
+            std::ptr::null_mut(),
+            server_ticket,
+        );
+
+        let begin = konigsberg::<BeginSessionFn>("SteamAPI_ISteamGameServer_BeginAuthSession");
+        begin(std::ptr::null_mut(), ticket.as_ptr() as _, 4, PLAYER);
+        begin(std::ptr::null_mut(), ticket.as_ptr() as _, 4, OTHER_PLAYER);
+        konigsberg::<EndSessionFn>("SteamAPI_ISteamGameServer_EndAuthSession")(
+            std::ptr::null_mut(),
+            OTHER_PLAYER,
+        );
+
+        // games using `ISteamUser` directly
+        let user = konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr("SteamUser021").as_ptr(),
+        );
+        let forgotten_by_method =
+            slot::<GetTicketFn>(user, 13)(user, ticket.as_mut_ptr() as _, 16, &mut len);
+        assert_eq!(
+            last_call().as_deref(),
+            Some("ISteamUser::GetAuthSessionTicket")
+        );
+        let mut steam_id: CSteamID = std::mem::zeroed();
+        steam_id.m_steamid.m_unAll64Bits = PLAYER;
+        slot::<BeginSessionMethod>(user, 14)(user, ticket.as_ptr() as _, 4, steam_id);
+        (cancelled, forgotten, forgotten_by_method, server_ticket)
+    };
+    let report = report();
+
+    let line = lint(&report, "auth_ticket_not_cancelled");
+    for ticket in [forgotten, forgotten_by_method] {
+        assert!(
+            line.contains(&format!("{{\"ticket\":{ticket},\"game_server\":false}}")),
+            "{line}"
+        );
+    }
+    assert!(
+        !line.contains(&format!("{{\"ticket\":{cancelled},\"game_server\":false}}")),
+        "{line}"
+    );
+    assert!(
+        !line.contains(&format!(
+            "{{\"ticket\":{server_ticket},\"game_server\":true}}"
+        )),
+        "{line}"
+    );
+
+    let line = lint(&report, "auth_session_not_ended");
+    assert!(
+        line.contains("{\"steam_id\":\"[U:1:2]\",\"game_server\":false}"),
+        "{line}"
+    );
+    assert!(
+        line.contains("{\"steam_id\":\"[U:1:2]\",\"game_server\":true}"),
+        "{line}"
+    );
+    assert!(!line.contains("[U:1:3]"), "{line}");
+}
+
+/// Makes `calls` forwarded calls on a new thread, then releases its memory
+/// if `release`.
+fn worker(calls: usize, release: bool) {
+    std::thread::spawn(move || unsafe {
+        let path =
+            konigsberg::<unsafe extern "C" fn() -> *const c_char>("SteamAPI_GetSteamInstallPath");
+        for _ in 0..calls {
+            path();
+        }
+        if release {
+            konigsberg::<unsafe extern "C" fn()>("SteamAPI_ReleaseCurrentThreadMemory")();
+        }
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn flags_threads_that_dont_release_their_memory() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    // without an init, the first thread to call in counts as the main one
+    unsafe { get_steam_id() };
+    // far more calls than any test thread makes, to tell them apart
+    worker(42, false);
+    worker(43, true);
+    let line = lint(&report(), "thread_memory_not_released");
+    assert!(line.contains(",\"calls\":42}"), "{line}");
+    assert!(!line.contains(",\"calls\":43}"), "{line}");
+}
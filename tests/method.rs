@@ -0,0 +1,100 @@
+//! Methods declared with `vtable_method!` can be put in a vtable and called
+//! through it with the target's calling convention.
+use konigsberg_core::vtable_method;
+use std::{
+    cell::Cell,
+    ffi::{c_char, c_void},
+};
+use steamworks_sys::{AppId_t, CSteamID};
+
+vtable_method! {
+    type AppFn = fn(AppId_t) -> bool;
+}
+
+vtable_method! {
+    type ManyFn = fn(u8, CSteamID, u64, *const c_char, f32, u16) -> u64;
+}
+
+thread_local! {
+    static THIS: Cell<*mut c_void> = const { Cell::new(std::ptr::null_mut()) };
+}
+
+vtable_method! {
+    unsafe fn is_even(this: *mut c_void, app_id: AppId_t) -> bool {
+        THIS.with(|x| x.set(this));
+        app_id.is_multiple_of(2)
+    }
+}
+
+vtable_method! {
+    unsafe fn many(
+        this: *mut c_void,
+        a: u8,
+        steam_id: CSteamID,
+        b: u64,
+        s: *const c_char,
+        f: f32,
+        c: u16,
+    ) -> u64 {
+        THIS.with(|x| x.set(this));
+        assert_eq!(std::ffi::CStr::from_ptr(s).to_str(), Ok("konigsberg"));
+        assert_eq!(f, 1.5);
+        u64::from(a) + steam_id.m_steamid.m_unAll64Bits + b + u64::from(c)
+    }
+}
+
+/// An object whose vtable has `func` in slot 2, like a C++ one.
+#[repr(C)]
+struct Object {
+    /// Only read through [`Object::this`].
+    #[allow(dead_code)]
+    vtable: Box<[usize; 4]>,
+}
+
+impl Object {
+    fn new(func: usize) -> Box<Self> {
+        Box::new(Self {
+            vtable: Box::new([0, 0, func, 0]),
+        })
+    }
+
+    fn this(&mut self) -> *mut c_void {
+        self as *mut Self as *mut c_void
+    }
+
+    unsafe fn slot<F: Copy>(&mut self) -> F {
+        let vtable = *(self.this() as *const *const usize);
+        std::mem::transmute_copy(&*vtable.add(2))
+    }
+}
+
+#[test]
+fn hooks_coerce_to_their_type() {
+    let func: AppFn = is_even;
+    let mut object = Object::new(func as usize);
+    let this = object.this();
+    unsafe {
+        let func = object.slot::<AppFn>();
+        assert!(vtable_method!(call func(this, 480)));
+        assert!(!vtable_method!(call func(this, 481)));
+    }
+    assert_eq!(THIS.with(Cell::get), this);
+}
+
+#[test]
+fn arguments_arrive_in_order() {
+    let func: ManyFn = many;
+    let mut object = Object::new(func as usize);
+    let this = object.this();
+    let steam_id = CSteamID {
+        m_steamid: steamworks_sys::CSteamID_SteamID_t {
+            m_unAll64Bits: 76561197960287930,
+        },
+    };
+    let ret = unsafe {
+        let func = object.slot::<ManyFn>();
+        vtable_method!(call func(this, 1, steam_id, 1 << 40, c"konigsberg".as_ptr(), 1.5, 7))
+    };
+    assert_eq!(ret, 1 + 76561197960287930 + (1 << 40) + 7);
+    assert_eq!(THIS.with(Cell::get), this);
+}
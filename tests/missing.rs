@@ -0,0 +1,165 @@
+//! `SteamAPI_SteamApps_v009` is forwarded but the mock doesn't export it, so
+//! it stands in for a symbol missing from the original library. Each policy
+//! runs in a child process, since the config is read once per process.
+use std::{
+    ffi::{c_char, c_void},
+    process::{Command, Output},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+mod common;
+use common::*;
+
+type AccessorFn = unsafe extern "C" fn() -> *mut c_void;
+
+/// Run the test named `child` in a new process with `config` as its config
+/// file.
+fn run_child(child: &str, config: &str, lib: Option<&str>) -> Output {
+    let dir = build().dir.join("missing");
+    std::fs::create_dir_all(&dir).unwrap();
+    let config_path = dir.join(format!("{child}.cfg"));
+    std::fs::write(&config_path, config).unwrap();
+    let mut cmd = Command::new(std::env::current_exe().unwrap());
+    cmd.args([child, "--exact", "--nocapture", "--test-threads=1"])
+        .env("KONIGSBERG_CHILD", "1")
+        .env("KONIGSBERG_CONFIG", &config_path)
+        .env_remove("KONIGSBERG_LOG");
+    if let Some(lib) = lib {
+        cmd.env("KONIGSBERG_CHILD_LIB", lib);
+    }
+    cmd.output().unwrap()
+}
+
+fn is_child() -> bool {
+    std::env::var_os("KONIGSBERG_CHILD").is_some()
+}
+
+fn diagnostics(output: &Output, needle: &str) -> Vec<String> {
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter(|x| x.starts_with("konigsberg: ") && x.contains(needle))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn call_v009_twice() -> [*mut c_void; 2] {
+    unsafe {
+        let accessor = konigsberg::<AccessorFn>("SteamAPI_SteamApps_v009");
+        [accessor(), accessor()]
+    }
+}
+
+#[test]
+fn child_default() {
+    if is_child() {
+        assert_eq!(call_v009_twice(), [std::ptr::null_mut(); 2]);
+    }
+}
+
+#[test]
+fn missing_symbol_returns_default() {
+    let output = run_child(
+        "child_default",
+        "on_missing = abort\non_missing.SteamAPI_SteamApps_v009 = default\n",
+        None,
+    );
+    assert!(output.status.success(), "{output:?}");
+    let lines = diagnostics(&output, "SteamAPI_SteamApps_v009");
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].contains("returning a default value"), "{lines:?}");
+    assert!(lines[0].contains("steam_api_mock"), "{lines:?}");
+}
+
+static FALLBACK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn fallback() -> *mut c_void {
+    FALLBACK_CALLS.fetch_add(1, Ordering::Relaxed);
+    std::ptr::null_mut()
+}
+
+#[test]
+fn child_fallback() {
+    if is_child() {
+        unsafe {
+            let register = konigsberg::<unsafe extern "C" fn(*const c_char, *const c_void) -> bool>(
+                "konigsberg_register_fallback",
+            );
+            let name = cstr("SteamAPI_SteamApps_v009");
+            assert!(register(name.as_ptr(), fallback as *const c_void));
+        }
+        call_v009_twice();
+        assert_eq!(FALLBACK_CALLS.load(Ordering::Relaxed), 2);
+    }
+}
+
+#[test]
+fn missing_symbol_calls_fallback() {
+    let output = run_child("child_fallback", "on_missing = fallback\n", None);
+    assert!(output.status.success(), "{output:?}");
+    let lines = diagnostics(&output, "SteamAPI_SteamApps_v009");
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(
+        lines[0].contains("calling the registered fallback"),
+        "{lines:?}"
+    );
+}
+
+#[test]
+fn child_abort() {
+    if is_child() {
+        call_v009_twice();
+    }
+}
+
+#[test]
+fn missing_symbol_aborts() {
+    let output = run_child("child_abort", "on_missing = abort\n", None);
+    assert!(!output.status.success(), "{output:?}");
+    let lines = diagnostics(&output, "SteamAPI_SteamApps_v009");
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].contains("aborting"), "{lines:?}");
+}
+
+#[test]
+fn child_no_library() {
+    if is_child() {
+        let lib = std::env::var("KONIGSBERG_CHILD_LIB").unwrap();
+        std::env::set_var("KONIGSBERG_LIB", &lib);
+        let konigsberg = unsafe { libloading::Library::new(&build().konigsberg).unwrap() };
+        unsafe {
+            let init = konigsberg
+                .get::<unsafe extern "C" fn() -> bool>(b"SteamAPI_Init")
+                .unwrap();
+            assert!(!init());
+            let user = konigsberg
+                .get::<unsafe extern "C" fn() -> *mut c_void>(b"SteamAPI_SteamUser_v021")
+                .unwrap();
+            assert!(user().is_null());
+            #[cfg(all(unix, target_arch = "x86_64"))]
+            {
+                let init_anonymous = konigsberg
+                    .get::<unsafe extern "C" fn() -> bool>(b"SteamAPI_InitAnonymousUser")
+                    .unwrap();
+                assert!(!init_anonymous());
+            }
+        }
+    }
+}
+
+#[test]
+fn missing_library_returns_default() {
+    let lib = build().dir.join("missing").join("nonexistent");
+    let lib = lib.to_str().unwrap();
+    let output = run_child("child_no_library", "on_missing = default\n", Some(lib));
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(diagnostics(&output, "failed to load").len(), 1);
+    let mut symbols = vec!["SteamAPI_Init", "SteamAPI_SteamUser_v021"];
+    if cfg!(all(unix, target_arch = "x86_64")) {
+        symbols.push("SteamAPI_InitAnonymousUser");
+    }
+    for symbol in symbols {
+        let lines = diagnostics(&output, &format!("{symbol}:"));
+        assert_eq!(lines.len(), 1, "{lines:?}");
+        assert!(lines[0].contains(lib), "{lines:?}");
+    }
+}
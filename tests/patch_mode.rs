@@ -0,0 +1,101 @@
+//! `patch_mode = copy`: hooks go into a copy of each vtable, and the mock
+//! library's own vtables are left alone.
+use std::ffi::{c_char, c_void};
+use steamworks_sys::{AppId_t, CSteamID, EUserHasLicenseForAppResult, HSteamPipe, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type AppFn = unsafe extern "C" fn(*mut c_void, AppId_t) -> bool;
+type LicenseFn =
+    unsafe extern "C" fn(*mut c_void, CSteamID, AppId_t) -> EUserHasLicenseForAppResult;
+type GetInterfaceFn =
+    unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void;
+
+/// Switch to copy mode before konigsberg is first called in this process.
+fn init() {
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    ONCE.call_once(|| {
+        std::env::set_var("KONIGSBERG_PATCH_MODE", "copy");
+        libs();
+    });
+}
+
+fn find_or_create(ver: &str) -> *mut c_void {
+    init();
+    unsafe {
+        konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr(ver).as_ptr(),
+        )
+    }
+}
+
+/// The mock's object for `ver`, without konigsberg touching it.
+fn mock_object(ver: &str) -> *mut c_void {
+    init();
+    unsafe {
+        mock::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(1, cstr(ver).as_ptr())
+    }
+}
+
+unsafe fn vtable(object: *mut c_void) -> *const usize {
+    *(object as *const *const usize)
+}
+
+#[test]
+fn hooks_go_into_a_copy() {
+    // a version no other test asks for, so nothing has swapped its vtable yet
+    let original = unsafe { vtable(mock_object("STEAMAPPS_INTERFACE_VERSION007")) };
+    let original_slots = unsafe { std::slice::from_raw_parts(original, 8).to_vec() };
+
+    let apps = find_or_create("STEAMAPPS_INTERFACE_VERSION007");
+    let copy = unsafe { vtable(apps) };
+    assert_ne!(copy, original);
+    for index in [6, 7] {
+        assert!(unsafe { slot::<AppFn>(apps, index)(apps, 1234) });
+    }
+    // untouched slots still lead to the mock, and its vtable is unchanged
+    assert_eq!(unsafe { *copy }, original_slots[0]);
+    assert_eq!(
+        unsafe { std::slice::from_raw_parts(original, 8) },
+        &original_slots[..]
+    );
+
+    // the same object keeps its copy, it isn't copied again
+    assert_eq!(find_or_create("STEAMAPPS_INTERFACE_VERSION007"), apps);
+    assert_eq!(unsafe { vtable(apps) }, copy);
+}
+
+#[test]
+fn copy_covers_the_whole_vtable() {
+    let user = find_or_create("SteamUser021");
+    assert_eq!(
+        unsafe { slot::<LicenseFn>(user, 17)(user, std::mem::zeroed(), 480) },
+        EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense
+    );
+    // the mock's vtables are 64 entries long, all pointing to code
+    let last = unsafe { slot_addr(user, 63) };
+    assert_ne!(last, 0);
+    assert_eq!(last, unsafe { slot_addr(user, 1) });
+}
+
+#[test]
+fn client_getters_call_through_the_copy() {
+    let original = unsafe { vtable(mock_object("SteamClient020")) };
+    let client = find_or_create("SteamClient020");
+    assert_ne!(unsafe { vtable(client) }, original);
+    for _ in 0..2 {
+        let apps = unsafe {
+            slot::<GetInterfaceFn>(client, 15)(
+                client,
+                1,
+                1,
+                cstr("STEAMAPPS_INTERFACE_VERSION008").as_ptr(),
+            )
+        };
+        assert!(!apps.is_null());
+        assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+    }
+}
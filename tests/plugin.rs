@@ -0,0 +1,112 @@
+//! Plugins loaded from `KONIGSBERG_PLUGINS`, using the example plugin.
+use once_cell::sync::OnceCell;
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, c_void, CStr},
+    path::PathBuf,
+};
+use steamworks_sys::{AppId_t, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type LanguageFn = unsafe extern "C" fn(*mut c_void) -> *const c_char;
+type AppFn = unsafe extern "C" fn(*mut c_void, AppId_t) -> bool;
+
+/// Fill a plugin directory and point konigsberg at it before it's first
+/// called in this process. Returns the log file.
+fn init() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let build = build();
+        let dir = build.dir.join("plugin");
+        let plugins = dir.join(format!("{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&plugins);
+        std::fs::create_dir_all(&plugins).unwrap();
+        std::fs::copy(
+            &build.plugin,
+            plugins.join(format!("{DLL_PREFIX}example{DLL_SUFFIX}")),
+        )
+        .unwrap();
+        std::fs::copy(
+            &build.mock,
+            plugins.join(format!("{DLL_PREFIX}not_a_plugin{DLL_SUFFIX}")),
+        )
+        .unwrap();
+        std::fs::write(plugins.join("README.txt"), "not a library").unwrap();
+        let log = dir.join(format!("{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&log);
+        std::env::set_var("KONIGSBERG_PLUGINS", &plugins);
+        std::env::set_var("KONIGSBERG_LOG", &log);
+        libs();
+        log
+    })
+}
+
+fn log_lines(needle: &str) -> Vec<String> {
+    std::fs::read_to_string(init())
+        .unwrap_or_default()
+        .lines()
+        .filter(|x| x.contains(needle))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn find_or_create(ver: &str) -> *mut c_void {
+    init();
+    unsafe {
+        konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr(ver).as_ptr(),
+        )
+    }
+}
+
+#[test]
+fn export_hooks_call_the_original() {
+    init();
+    assert!(unsafe { konigsberg::<unsafe extern "C" fn() -> bool>("SteamAPI_IsSteamRunning")() });
+    assert_eq!(last_call().as_deref(), Some("SteamAPI_IsSteamRunning"));
+    assert!(!unsafe { mock::<unsafe extern "C" fn() -> bool>("SteamAPI_IsSteamRunning")() });
+    assert!(!log_lines("example plugin: Steam isn't running").is_empty());
+}
+
+#[test]
+fn vtable_hooks_sit_alongside_konigsbergs() {
+    for ver in [
+        "STEAMAPPS_INTERFACE_VERSION001",
+        "STEAMAPPS_INTERFACE_VERSION008",
+    ] {
+        let apps = find_or_create(ver);
+        let language = unsafe { CStr::from_ptr(slot::<LanguageFn>(apps, 4)(apps)) };
+        assert_eq!(language.to_str(), Ok("english"));
+    }
+    let apps = find_or_create("STEAMAPPS_INTERFACE_VERSION008");
+    assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+}
+
+#[test]
+fn missing_original_exports_are_null() {
+    init();
+    // loads the plugins, which would abort under the default `on_missing`
+    // policy if that applied to them
+    unsafe { konigsberg::<unsafe extern "C" fn() -> bool>("SteamAPI_IsSteamRunning")() };
+    assert_eq!(
+        log_lines("example plugin: no GetAuthTicketForWebApi in this library").len(),
+        1
+    );
+    // and nothing logged as missing
+    assert!(log_lines("SteamAPI_ISteamUser_GetAuthTicketForWebApi").is_empty());
+}
+
+#[test]
+fn only_plugins_are_loaded() {
+    init();
+    unsafe { konigsberg::<unsafe extern "C" fn() -> bool>("SteamAPI_IsSteamRunning")() };
+    assert_eq!(log_lines("loaded plugin").len(), 1);
+    let lines = log_lines("isn't a plugin, it has no konigsberg_plugin_init");
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].contains("not_a_plugin"), "{lines:?}");
+    assert!(log_lines("README.txt").is_empty());
+}
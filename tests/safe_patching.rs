@@ -0,0 +1,234 @@
+//! Objects konigsberg refuses to hook, other hookers it chains to or finds
+//! on top of it, and what patching leaves behind.
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_void},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use steamworks_sys::{AppId_t, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type AppFn = unsafe extern "C" fn(*mut c_void, AppId_t) -> bool;
+type SetObjectFn = unsafe extern "C" fn(*const c_char, *mut c_void) -> bool;
+
+/// Send diagnostics to a file before konigsberg is first called in this
+/// process.
+fn log_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("safe_patching");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_LOG", &path);
+        libs();
+        path
+    })
+}
+
+fn refusals(ver: &str) -> Vec<String> {
+    let needle = format!("konigsberg: not hooking {ver} ");
+    std::fs::read_to_string(log_file())
+        .unwrap_or_default()
+        .lines()
+        .filter(|x| x.starts_with(&needle))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn find_or_create(ver: &str) -> *mut c_void {
+    log_file();
+    unsafe {
+        konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr(ver).as_ptr(),
+        )
+    }
+}
+
+fn set_object(ver: &str, object: *mut c_void) {
+    log_file();
+    assert!(unsafe { mock::<SetObjectFn>("mock_set_object")(cstr(ver).as_ptr(), object) });
+}
+
+unsafe fn vtable(object: *mut c_void) -> *mut usize {
+    *(object as *const *mut usize)
+}
+
+/// Write `value` into `slot` of `object`'s vtable, like another hooker.
+unsafe fn overwrite(object: *mut c_void, slot: usize, value: usize) {
+    let slot = vtable(object).add(slot);
+    let _handle = region::protect_with_handle(
+        slot,
+        std::mem::size_of::<usize>(),
+        region::Protection::READ_WRITE,
+    )
+    .unwrap();
+    *slot = value;
+}
+
+fn log_lines(needle: &str) -> Vec<String> {
+    std::fs::read_to_string(log_file())
+        .unwrap_or_default()
+        .lines()
+        .filter(|x| x.contains(needle))
+        .map(str::to_owned)
+        .collect()
+}
+
+static FOREIGN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn foreign(_this: *mut c_void, _app_id: AppId_t) -> bool {
+    FOREIGN_CALLS.fetch_add(1, Ordering::SeqCst);
+    false
+}
+
+#[test]
+fn refuses_slots_that_arent_code() {
+    static DATA: usize = 0;
+    let ver = "STEAMAPPS_INTERFACE_VERSION006";
+    let apps = unsafe {
+        log_file();
+        mock::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(1, cstr(ver).as_ptr())
+    };
+    unsafe { overwrite(apps, 6, &DATA as *const usize as usize) };
+    for _ in 0..2 {
+        assert_eq!(find_or_create(ver), apps);
+    }
+    let lines = refusals(ver);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(
+        lines[0].contains("ISteamApps::BIsSubscribedApp (slot 6) points to"),
+        "{lines:?}"
+    );
+    // slot 7 was fine, but it's all or nothing
+    assert_eq!(
+        unsafe { slot_addr(apps, 6) },
+        &DATA as *const usize as usize
+    );
+    assert!(!unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+}
+
+#[test]
+fn chains_to_hooks_installed_before() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION007";
+    let apps = unsafe {
+        log_file();
+        mock::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(1, cstr(ver).as_ptr())
+    };
+    // someone else got to slot 7 first
+    unsafe { overwrite(apps, 7, foreign as *const () as usize) };
+    assert_eq!(find_or_create(ver), apps);
+    assert!(refusals(ver).is_empty());
+    assert_eq!(
+        log_lines("ISteamApps::BIsDlcInstalled (slot 7) in the vtable at").len(),
+        1
+    );
+
+    let before = FOREIGN_CALLS.load(Ordering::SeqCst);
+    assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+    assert_eq!(FOREIGN_CALLS.load(Ordering::SeqCst), before + 1);
+    // and the slot konigsberg was first in still reaches the mock
+    assert!(unsafe { slot::<AppFn>(apps, 6)(apps, 1234) });
+    assert_eq!(last_call().as_deref(), Some("ISteamApps::BIsSubscribedApp"));
+}
+
+#[test]
+fn detects_slots_replaced_after_hooking() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION008";
+    let apps = find_or_create(ver);
+    assert!(unsafe { slot::<AppFn>(apps, 6)(apps, 1234) });
+    let check =
+        || unsafe { konigsberg::<unsafe extern "C" fn() -> usize>("konigsberg_check_hooks")() };
+    assert_eq!(check(), 0);
+
+    // someone else hooks on top of konigsberg
+    unsafe { overwrite(apps, 6, foreign as *const () as usize) };
+    for _ in 0..2 {
+        assert_eq!(check(), 1);
+        assert_eq!(find_or_create(ver), apps);
+    }
+    let lines = log_lines("ISteamApps::BIsSubscribedApp in the vtable at");
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(
+        lines[0].ends_with("after konigsberg hooked it"),
+        "{lines:?}"
+    );
+}
+
+#[test]
+fn refuses_unreadable_objects() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION004";
+    let bogus = 0x10 as *mut c_void;
+    set_object(ver, bogus);
+    for _ in 0..2 {
+        assert_eq!(find_or_create(ver), bogus);
+    }
+    let lines = refusals(ver);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].contains("the object isn't readable"), "{lines:?}");
+}
+
+#[test]
+fn refuses_unreadable_vtables() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION003";
+    let object = Box::into_raw(Box::new(0x18usize)) as *mut c_void;
+    set_object(ver, object);
+    assert_eq!(find_or_create(ver), object);
+    let lines = refusals(ver);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].contains("isn't readable"), "{lines:?}");
+}
+
+#[test]
+fn hooks_vtables_outside_any_module() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION002";
+    let real = unsafe {
+        log_file();
+        mock::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(1, cstr(ver).as_ptr())
+    };
+    // a copy of a vtable on the heap, like another hooker might make
+    let copy = Box::leak(Box::new(unsafe {
+        std::ptr::read(vtable(real) as *const [usize; 64])
+    }));
+    let object = Box::into_raw(Box::new(copy.as_mut_ptr())) as *mut c_void;
+    set_object(ver, object);
+    assert_eq!(find_or_create(ver), object);
+    assert!(refusals(ver).is_empty());
+    assert_eq!(
+        log_lines(&format!(
+            "the vtable at {:p} isn't in a loaded module",
+            copy.as_ptr()
+        ))
+        .len(),
+        1
+    );
+    assert!(unsafe { slot::<AppFn>(object, 6)(object, 1234) });
+    assert_eq!(last_call().as_deref(), Some("ISteamApps::BIsSubscribedApp"));
+}
+
+#[test]
+fn follows_objects_pointed_at_another_vtable() {
+    let ver = "STEAMAPPS_INTERFACE_VERSION009";
+    let apps = find_or_create(ver);
+    // another hooker points the hooked object at its own copy of the vtable
+    let copy = Box::leak(Box::new(unsafe {
+        std::ptr::read(vtable(apps) as *const [usize; 64])
+    }));
+    unsafe { *(apps as *mut *mut usize) = copy.as_mut_ptr() };
+    assert!(unsafe { slot::<AppFn>(apps, 6)(apps, 1234) });
+    assert_eq!(last_call().as_deref(), Some("ISteamApps::BIsSubscribedApp"));
+}
+
+#[test]
+fn patched_vtables_stay_read_only() {
+    let apps = find_or_create("STEAMAPPS_INTERFACE_VERSION005");
+    assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+    let region = region::query(unsafe { vtable(apps) }).unwrap();
+    assert_eq!(region.protection(), region::Protection::READ);
+    assert!(refusals("STEAMAPPS_INTERFACE_VERSION005").is_empty());
+}
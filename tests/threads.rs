@@ -0,0 +1,161 @@
+//! The thread report breaks calls down by thread, and flags calls into an
+//! interface while another thread is running callbacks, whether they're
+//! made through flat exports or hooked interface pointers.
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_int, c_void},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+use steamworks_sys::{AppId_t, CCallbackBase, CSteamID, EUserHasLicenseForAppResult, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type LicenseFn =
+    unsafe extern "C" fn(*mut c_void, CSteamID, AppId_t) -> EUserHasLicenseForAppResult;
+type RegisterFn = unsafe extern "C" fn(*mut CCallbackBase, c_int);
+type UnregisterFn = unsafe extern "C" fn(*mut CCallbackBase);
+type PostFn = unsafe extern "C" fn(c_int, *const c_void, c_int);
+type InterfaceFn = unsafe extern "C" fn(*mut c_void) -> u32;
+type WriteFn = unsafe extern "C" fn(*const c_char) -> bool;
+
+/// The mock delivers its whole queue to whichever thread asks, so tests
+/// take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Turn the report on before konigsberg is first called in this process.
+fn report_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let dir = build().dir.join("threads");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_THREADS", &path);
+        libs();
+        path
+    })
+}
+
+fn report() -> String {
+    let path = report_file();
+    let path_c = cstr(path.to_str().unwrap());
+    assert!(unsafe { konigsberg::<WriteFn>("konigsberg_write_threads")(path_c.as_ptr()) });
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn lines_starting<'a>(report: &'a str, start: &str) -> Vec<&'a str> {
+    report.lines().filter(|x| x.starts_with(start)).collect()
+}
+
+/// The hooked `SteamUser023` the callback handler calls into.
+static USER: AtomicUsize = AtomicUsize::new(0);
+
+/// Calls into `ISteamUGC` and `ISteamUser` from another thread while the
+/// calling thread is inside `SteamAPI_RunCallbacks`.
+unsafe extern "C" fn run(_this: *mut CCallbackBase, _param: *mut c_void) {
+    std::thread::spawn(|| unsafe {
+        konigsberg::<InterfaceFn>("SteamAPI_ISteamUGC_GetNumSubscribedItems")(std::ptr::null_mut());
+        let user = USER.load(Ordering::Relaxed) as *mut c_void;
+        slot::<LicenseFn>(user, 17)(user, std::mem::zeroed(), 480);
+    })
+    .join()
+    .unwrap();
+}
+
+unsafe extern "C" fn run_call_result(
+    _this: *mut CCallbackBase,
+    _param: *mut c_void,
+    _failed: bool,
+    _call: u64,
+) {
+}
+
+unsafe extern "C" fn size(_this: *mut CCallbackBase) -> c_int {
+    4
+}
+
+#[test]
+fn flags_calls_while_another_thread_runs_callbacks() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let vtable: &'static [usize; 3] = Box::leak(Box::new([
+        run as *const () as usize,
+        run_call_result as *const () as usize,
+        size as *const () as usize,
+    ]));
+    let mut object = CCallbackBase {
+        vtable_: vtable.as_ptr() as *const _,
+        m_nCallbackFlags: 0,
+        m_iCallback: 0,
+    };
+    unsafe {
+        let user = konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr("SteamUser023").as_ptr(),
+        );
+        USER.store(user as usize, Ordering::Relaxed);
+        konigsberg::<RegisterFn>("SteamAPI_RegisterCallback")(&mut object, 9301);
+        mock::<PostFn>("mock_post_callback")(9301, [0u8; 4].as_ptr() as *const c_void, 4);
+        konigsberg::<unsafe extern "C" fn()>("SteamAPI_RunCallbacks")();
+        konigsberg::<UnregisterFn>("SteamAPI_UnregisterCallback")(&mut object);
+    }
+    let report = report();
+
+    for (interface, function) in [
+        ("ISteamUGC", "SteamAPI_ISteamUGC_GetNumSubscribedItems"),
+        ("ISteamUser", "ISteamUser::UserHasLicenseForApp"),
+    ] {
+        let lines = lines_starting(&report, &format!("{{\"concurrent\":\"{interface}\","));
+        assert_eq!(lines.len(), 1, "{report}");
+        let line = lines[0];
+        assert!(
+            line.contains(",\"fn\":\"SteamAPI_RunCallbacks\"},\"entering\":"),
+            "{line}"
+        );
+        assert!(
+            line.ends_with(&format!(",\"fn\":\"{function}\"}}}}")),
+            "{line}"
+        );
+    }
+}
+
+#[test]
+fn breaks_calls_down_by_thread() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    report_file();
+    let call = |name: &str| unsafe { konigsberg::<InterfaceFn>(name)(std::ptr::null_mut()) };
+    // one after the other, so not concurrent
+    call("SteamAPI_ISteamApps_GetAppBuildId");
+    std::thread::spawn(move || {
+        for _ in 0..3 {
+            call("SteamAPI_ISteamApps_GetAppBuildId");
+        }
+        call("SteamAPI_ISteamApps_BIsLowViolence");
+    })
+    .join()
+    .unwrap();
+    let report = report();
+
+    let lines = lines_starting(&report, "{\"thread\":")
+        .into_iter()
+        .filter(|line| line.contains("\"SteamAPI_ISteamApps_BIsLowViolence\""))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1, "{report}");
+    assert!(
+        lines[0].ends_with(concat!(
+            ",\"calls\":4,\"interfaces\":{\"ISteamApps\":4},\"functions\":",
+            "{\"SteamAPI_ISteamApps_BIsLowViolence\":1,\"SteamAPI_ISteamApps_GetAppBuildId\":3}}",
+        )),
+        "{report}"
+    );
+    assert!(
+        lines_starting(&report, "{\"concurrent\":\"ISteamApps\",").is_empty(),
+        "{report}"
+    );
+}
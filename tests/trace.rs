@@ -0,0 +1,176 @@
+use once_cell::sync::OnceCell;
+use std::{
+    ffi::{c_char, c_void},
+    path::PathBuf,
+};
+use steamworks_sys::{CSteamID, EUniverse};
+
+mod common;
+use common::*;
+
+/// Turn tracing on before konigsberg is first called in this process.
+fn trace_file() -> &'static PathBuf {
+    static CELL: OnceCell<PathBuf> = OnceCell::new();
+    CELL.get_or_init(|| {
+        let path =
+            std::env::temp_dir().join(format!("konigsberg-trace-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("KONIGSBERG_TRACE", &path);
+        libs();
+        path
+    })
+}
+
+fn lines_for(name: &str) -> Vec<String> {
+    let needle = format!("{{\"fn\":\"{name}\",");
+    std::fs::read_to_string(trace_file())
+        .unwrap()
+        .lines()
+        .filter(|x| x.starts_with(&needle))
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn scalar_arguments_and_return_are_logged() {
+    trace_file();
+    let ret = unsafe {
+        konigsberg::<unsafe extern "C" fn(u32) -> bool>("SteamAPI_RestartAppIfNecessary")(480)
+    };
+    assert!(!ret);
+    let lines = lines_for("SteamAPI_RestartAppIfNecessary");
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert!(
+        line.contains("\"args\":{\"unOwnAppID\":480},\"ret\":false,\"thread\":"),
+        "{line}"
+    );
+    assert!(line.contains(",\"dur_ns\":"), "{line}");
+    assert!(line.ends_with('}'), "{line}");
+}
+
+#[test]
+fn enums_and_pointers_are_decoded() {
+    trace_file();
+    let id = cstr("[U:1:2]");
+    unsafe {
+        konigsberg::<unsafe extern "C" fn(*mut CSteamID, *const c_char, EUniverse)>(
+            "_ZN8CSteamID13SetFromStringEPKc9EUniverse",
+        )(
+            std::ptr::null_mut(),
+            id.as_ptr(),
+            EUniverse::k_EUniversePublic,
+        )
+    };
+    let lines = lines_for("CSteamID_SetFromString");
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert!(line.contains("\"this\":\"0x0\""), "{line}");
+    assert!(line.contains("\"pchSteamID\":\"[U:1:2]\""), "{line}");
+    assert!(
+        line.contains("\"eDefaultUniverse\":\"k_EUniversePublic\""),
+        "{line}"
+    );
+    assert!(line.contains("\"ret\":null"), "{line}");
+}
+
+#[test]
+fn steam_ids_and_ips_are_readable() {
+    trace_file();
+    unsafe {
+        konigsberg::<unsafe extern "C" fn(*mut c_void, u64, u32, u16)>(
+            "SteamAPI_ISteamUser_AdvertiseGame",
+        )(std::ptr::null_mut(), 76561197960265730, 0x0a000007, 27015)
+    };
+    let lines = lines_for("SteamAPI_ISteamUser_AdvertiseGame");
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert!(
+        line.contains(concat!(
+            "\"steamIDGameServer\":\"[U:1:2]\",",
+            "\"unIPServer\":\"10.0.0.7\",\"usPortServer\":27015",
+        )),
+        "{line}"
+    );
+}
+
+#[test]
+fn out_parameters_are_read_after_the_call() {
+    trace_file();
+    let mut ticket = [0u8; 8];
+    let mut len = 0u32;
+    let handle = unsafe {
+        konigsberg::<unsafe extern "C" fn(*mut c_void, *mut c_void, i32, *mut u32) -> u32>(
+            "SteamAPI_ISteamUser_GetAuthSessionTicket",
+        )(
+            std::ptr::null_mut(),
+            ticket.as_mut_ptr() as *mut c_void,
+            ticket.len() as i32,
+            &mut len,
+        )
+    };
+    assert_eq!(len, 4);
+    let line = lines_for("SteamAPI_ISteamUser_GetAuthSessionTicket")
+        .into_iter()
+        .find(|x| x.contains(&format!("\"ret\":{handle},")))
+        .unwrap();
+    assert!(
+        line.contains(concat!(
+            "\"pTicket\":\"deadbeef00000000\",\"cbMaxTicket\":8,",
+            "\"pcbTicket\":4}",
+        )),
+        "{line}"
+    );
+}
+
+#[test]
+fn call_results_are_read_after_the_call() {
+    trace_file();
+    let mut data = [0u8; 4];
+    let mut failed = true;
+    let ret = unsafe {
+        mock::<unsafe extern "C" fn(u64, i32, *const c_void, i32, bool)>("mock_complete_call")(
+            0x7ace,
+            9104,
+            [0xcau8, 0xfe, 0xf0, 0x0d].as_ptr() as *const c_void,
+            4,
+            false,
+        );
+        konigsberg::<unsafe extern "C" fn(i32, u64, *mut c_void, i32, i32, *mut bool) -> bool>(
+            "SteamAPI_ManualDispatch_GetAPICallResult",
+        )(
+            1,
+            0x7ace,
+            data.as_mut_ptr() as *mut c_void,
+            data.len() as i32,
+            9104,
+            &mut failed,
+        )
+    };
+    assert!(ret);
+    assert_eq!(data, [0xca, 0xfe, 0xf0, 0x0d]);
+    let lines = lines_for("SteamAPI_ManualDispatch_GetAPICallResult");
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert!(
+        line.contains(concat!(
+            "\"pCallback\":\"cafef00d\",\"cubCallback\":4,",
+            "\"iCallbackExpected\":9104,\"pbFailed\":false}",
+        )),
+        "{line}"
+    );
+}
+
+#[test]
+fn intercepted_imports_are_logged() {
+    trace_file();
+    let apps = unsafe {
+        konigsberg::<unsafe extern "C" fn(i32, *const c_char) -> *mut c_void>(
+            "SteamInternal_FindOrCreateUserInterface",
+        )(1, cstr("STEAMAPPS_INTERFACE_VERSION008").as_ptr())
+    };
+    let line = lines_for("SteamInternal_FindOrCreateUserInterface")
+        .into_iter()
+        .find(|x| x.contains(&format!("\"ret\":\"{:#x}\"", apps as usize)));
+    assert!(line.is_some());
+}
@@ -0,0 +1,152 @@
+//! Hooks come off on `SteamAPI_Shutdown`, on `SteamGameServer_Shutdown`
+//! without a client running, and when konigsberg is unloaded.
+//! Each test loads its own copy of konigsberg, so it has its own config and
+//! hooks, in front of the one shared mock library.
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, c_void},
+    sync::Mutex,
+};
+use steamworks_sys::{AppId_t, EServerMode, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type AppFn = unsafe extern "C" fn(*mut c_void, AppId_t) -> bool;
+type GameServerInitFn =
+    unsafe extern "C" fn(u32, u16, u16, u16, EServerMode, *const c_char) -> bool;
+
+/// Held while a copy is loaded, since the patch mode comes from the
+/// environment.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn load_copy(name: &str, patch_mode: &str) -> libloading::Library {
+    let build = build();
+    libs();
+    let dir = build.dir.join("unhook");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{DLL_PREFIX}{name}{DLL_SUFFIX}"));
+    std::fs::copy(&build.konigsberg, &path).unwrap();
+    std::env::set_var("KONIGSBERG_PATCH_MODE", patch_mode);
+    unsafe { libloading::Library::new(&path).unwrap() }
+}
+
+fn find_or_create(konigsberg: &libloading::Library, ver: &str) -> *mut c_void {
+    unsafe {
+        konigsberg
+            .get::<FindOrCreateFn>(b"SteamInternal_FindOrCreateUserInterface")
+            .unwrap()(1, cstr(ver).as_ptr())
+    }
+}
+
+fn shutdown(konigsberg: &libloading::Library) {
+    call(konigsberg, "SteamAPI_Shutdown");
+}
+
+fn call(konigsberg: &libloading::Library, name: &str) {
+    unsafe {
+        konigsberg
+            .get::<unsafe extern "C" fn()>(name.as_bytes())
+            .unwrap()()
+    };
+    assert_eq!(last_call().as_deref(), Some(name));
+}
+
+fn game_server_init(konigsberg: &libloading::Library) {
+    assert!(unsafe {
+        konigsberg
+            .get::<GameServerInitFn>(b"SteamInternal_GameServer_Init")
+            .unwrap()(
+            0,
+            0,
+            27015,
+            27016,
+            EServerMode::eServerModeNoAuthentication,
+            cstr("1.0").as_ptr(),
+        )
+    });
+}
+
+fn hooked(apps: *mut c_void) -> bool {
+    unsafe { slot::<AppFn>(apps, 7)(apps, 1234) }
+}
+
+unsafe fn vtable(object: *mut c_void) -> usize {
+    *(object as *const usize)
+}
+
+#[test]
+fn shutdown_restores_slots_and_init_hooks_again() {
+    let _lock = LOCK.lock().unwrap();
+    let konigsberg = load_copy("unhook_in_place", "in_place");
+    let apps = find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION008");
+    let vtable = unsafe { vtable(apps) };
+    assert!(hooked(apps));
+    shutdown(&konigsberg);
+    assert!(!hooked(apps));
+    assert_eq!(
+        find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION008"),
+        apps
+    );
+    assert!(hooked(apps));
+    assert_eq!(unsafe { self::vtable(apps) }, vtable);
+    shutdown(&konigsberg);
+}
+
+#[test]
+fn shutdown_points_objects_back_at_their_vtables() {
+    let _lock = LOCK.lock().unwrap();
+    let konigsberg = load_copy("unhook_copy", "copy");
+    let ver = cstr("STEAMAPPS_INTERFACE_VERSION006");
+    let original = unsafe {
+        vtable(mock::<FindOrCreateFn>(
+            "SteamInternal_FindOrCreateUserInterface",
+        )(1, ver.as_ptr()))
+    };
+    let apps = find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION006");
+    assert!(hooked(apps));
+    assert_ne!(unsafe { vtable(apps) }, original);
+    shutdown(&konigsberg);
+    assert!(!hooked(apps));
+    assert_eq!(unsafe { vtable(apps) }, original);
+    find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION006");
+    assert!(hooked(apps));
+    shutdown(&konigsberg);
+    assert_eq!(unsafe { vtable(apps) }, original);
+}
+
+#[test]
+fn game_server_shutdown_restores_slots_without_a_client() {
+    let _lock = LOCK.lock().unwrap();
+    let konigsberg = load_copy("unhook_game_server", "in_place");
+    game_server_init(&konigsberg);
+    let apps = find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION008");
+    assert!(hooked(apps));
+    call(&konigsberg, "SteamGameServer_Shutdown");
+    assert!(!hooked(apps));
+
+    // the client may still be using its interfaces
+    assert!(unsafe {
+        konigsberg
+            .get::<unsafe extern "C" fn() -> bool>(b"SteamAPI_Init")
+            .unwrap()()
+    });
+    game_server_init(&konigsberg);
+    find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION008");
+    assert!(hooked(apps));
+    call(&konigsberg, "SteamGameServer_Shutdown");
+    assert!(hooked(apps));
+    shutdown(&konigsberg);
+    assert!(!hooked(apps));
+}
+
+#[test]
+fn unloading_restores_slots() {
+    let _lock = LOCK.lock().unwrap();
+    let konigsberg = load_copy("unhook_unload", "in_place");
+    let apps = find_or_create(&konigsberg, "STEAMAPPS_INTERFACE_VERSION007");
+    assert!(hooked(apps));
+    drop(konigsberg);
+    assert!(!hooked(apps));
+}
@@ -0,0 +1,207 @@
+use std::ffi::{c_char, c_void};
+use steamworks_sys::{AppId_t, CSteamID, EUserHasLicenseForAppResult, HSteamPipe, HSteamUser};
+
+mod common;
+use common::*;
+
+type FindOrCreateFn = unsafe extern "C" fn(HSteamUser, *const c_char) -> *mut c_void;
+type AppFn = unsafe extern "C" fn(*mut c_void, AppId_t) -> bool;
+type BoolFn = unsafe extern "C" fn(*mut c_void) -> bool;
+type LicenseFn =
+    unsafe extern "C" fn(*mut c_void, CSteamID, AppId_t) -> EUserHasLicenseForAppResult;
+type GetInterfaceFn =
+    unsafe extern "C" fn(*mut c_void, HSteamUser, HSteamPipe, *const c_char) -> *mut c_void;
+
+fn find_or_create(ver: &str) -> *mut c_void {
+    unsafe {
+        let ret = konigsberg::<FindOrCreateFn>("SteamInternal_FindOrCreateUserInterface")(
+            1,
+            cstr(ver).as_ptr(),
+        );
+        assert_eq!(
+            last_call().as_deref(),
+            Some("SteamInternal_FindOrCreateUserInterface")
+        );
+        ret
+    }
+}
+
+fn steam_id() -> CSteamID {
+    unsafe { std::mem::zeroed() }
+}
+
+fn has_license(user: *mut c_void, slot_index: usize) -> EUserHasLicenseForAppResult {
+    unsafe { slot::<LicenseFn>(user, slot_index)(user, steam_id(), 480) }
+}
+
+#[test]
+fn apps_slots_are_patched() {
+    for (ver, patched) in [
+        ("STEAMAPPS_INTERFACE_VERSION002", &[6][..]),
+        ("STEAMAPPS_INTERFACE_VERSION003", &[6, 7]),
+        ("STEAMAPPS_INTERFACE_VERSION008", &[6, 7]),
+    ] {
+        let apps = find_or_create(ver);
+        for index in [6, 7] {
+            let ret = unsafe { slot::<AppFn>(apps, index)(apps, 1234) };
+            if patched.contains(&index) {
+                assert!(ret, "{ver} slot {index} isn't patched");
+            }
+        }
+        assert!(unsafe { slot::<BoolFn>(apps, 0)(apps) });
+    }
+}
+
+#[test]
+fn old_apps_interface_is_left_alone() {
+    let apps = find_or_create("STEAMAPPS_INTERFACE_VERSION001");
+    let orig = unsafe { slot_addr(apps, 6) };
+    let unknown = unsafe { slot_addr(apps, 1) };
+    assert_eq!(orig, unknown);
+}
+
+#[test]
+fn user_slots_are_patched() {
+    for (ver, index) in [
+        ("SteamUser012", 15),
+        ("SteamUser013", 16),
+        ("SteamUser014", 16),
+        ("SteamUser015", 17),
+        ("SteamUser021", 17),
+        ("SteamUser023", 17),
+    ] {
+        let user = find_or_create(ver);
+        assert_eq!(
+            has_license(user, index),
+            EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense,
+            "{ver}"
+        );
+    }
+}
+
+#[test]
+fn old_user_interface_is_left_alone() {
+    let user = find_or_create("SteamUser011");
+    let (orig, unknown) = unsafe { (slot_addr(user, 15), slot_addr(user, 1)) };
+    assert_eq!(orig, unknown);
+}
+
+#[test]
+fn steam_apps_accessor_is_patched() {
+    let apps =
+        unsafe { konigsberg::<unsafe extern "C" fn() -> *mut c_void>("SteamAPI_SteamApps_v008")() };
+    assert_eq!(last_call().as_deref(), Some("SteamAPI_SteamApps_v008"));
+    assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+}
+
+#[test]
+fn client_getters_patch_returned_interfaces() {
+    // (version, user slot, apps slot, generic slot)
+    for (ver, user_slot, apps_slot, generic_slot) in [
+        ("SteamClient006", 6, 16, None),
+        ("SteamClient007", 5, 18, Some(14)),
+        ("SteamClient008", 5, 15, Some(13)),
+        ("SteamClient011", 5, 16, Some(13)),
+        ("SteamClient017", 5, 15, Some(12)),
+        ("SteamClient020", 5, 15, Some(12)),
+    ] {
+        let client = find_or_create(ver);
+        // fetching twice makes sure the originals survive the client being patched again
+        for _ in 0..2 {
+            let apps = unsafe {
+                slot::<GetInterfaceFn>(client, apps_slot)(
+                    client,
+                    1,
+                    1,
+                    cstr("STEAMAPPS_INTERFACE_VERSION004").as_ptr(),
+                )
+            };
+            assert!(unsafe { slot::<AppFn>(apps, 7)(apps, 1234) }, "{ver}");
+
+            let user = unsafe {
+                slot::<GetInterfaceFn>(client, user_slot)(
+                    client,
+                    1,
+                    1,
+                    cstr("SteamUser016").as_ptr(),
+                )
+            };
+            assert_eq!(
+                has_license(user, 17),
+                EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense,
+                "{ver}"
+            );
+
+            if let Some(generic_slot) = generic_slot {
+                let user = unsafe {
+                    slot::<GetInterfaceFn>(client, generic_slot)(
+                        client,
+                        1,
+                        1,
+                        cstr("SteamUser018").as_ptr(),
+                    )
+                };
+                assert_eq!(
+                    has_license(user, 17),
+                    EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense,
+                    "{ver}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn old_client_interface_is_left_alone() {
+    let client = find_or_create("SteamClient005");
+    let apps = unsafe {
+        slot::<GetInterfaceFn>(client, 16)(
+            client,
+            1,
+            1,
+            cstr("STEAMAPPS_INTERFACE_VERSION005").as_ptr(),
+        )
+    };
+    assert!(!unsafe { slot::<AppFn>(apps, 7)(apps, 1234) });
+}
+
+#[test]
+fn unknown_interfaces_pass_through() {
+    assert!(find_or_create("SteamFriends017").is_null());
+}
+
+#[test]
+fn generated_slots_match_the_headers() {
+    let slots = include_str!("../src/vtable_slots.rs");
+    let mut checked = 0;
+    for module in slots.split("pub(crate) mod ").skip(1) {
+        let Some(ver) = module.lines().find_map(|line| {
+            line.trim()
+                .strip_prefix("pub(crate) const INTERFACE_VERSION: &str = \"")
+        }) else {
+            continue;
+        };
+        let ver = cstr(ver.trim_end_matches("\";"));
+        for line in module.lines() {
+            let Some((method, slot)) = line
+                .trim()
+                .strip_prefix("pub(crate) const ")
+                .and_then(|rest| rest.strip_suffix(';'))
+                .and_then(|rest| rest.split_once(": usize = "))
+            else {
+                continue;
+            };
+            let declared = unsafe {
+                mock::<unsafe extern "C" fn(*const c_char, *const c_char) -> i32>(
+                    "mock_declared_slot",
+                )(ver.as_ptr(), cstr(method).as_ptr())
+            };
+            if declared < 0 {
+                continue;
+            }
+            assert_eq!(slot, declared.to_string(), "{ver:?} {method}");
+            checked += 1;
+        }
+    }
+    assert!(checked > 0);
+}